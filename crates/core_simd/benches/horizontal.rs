@@ -0,0 +1,54 @@
+//! Benchmarks for the horizontal reductions, compared against summing the lanes in order.
+#![feature(test)]
+
+extern crate test;
+
+use core_simd::{SimdF32, SimdF64, SimdI32};
+use test::{black_box, Bencher};
+
+/// The number of vectors reduced per iteration.
+const VECTORS: usize = 256;
+
+macro_rules! bench_horizontal {
+    { $name:ident, $vector:ident, $scalar:ty, $lanes:literal } => {
+        mod $name {
+            use super::*;
+
+            fn input() -> Vec<$vector<$lanes>> {
+                (0..VECTORS)
+                    .map(|i| $vector::<$lanes>::splat(i as $scalar))
+                    .collect()
+            }
+
+            #[bench]
+            fn horizontal_sum(b: &mut Bencher) {
+                let input = input();
+                b.iter(|| {
+                    for v in black_box(&input) {
+                        black_box(v.horizontal_sum());
+                    }
+                });
+            }
+
+            #[bench]
+            fn ordered_sum(b: &mut Bencher) {
+                let input = input();
+                b.iter(|| {
+                    for v in black_box(&input) {
+                        black_box(v.as_slice().iter().fold(0 as $scalar, |acc, x| acc + x));
+                    }
+                });
+            }
+        }
+    }
+}
+
+bench_horizontal! { f32x8, SimdF32, f32, 8 }
+bench_horizontal! { f32x16, SimdF32, f32, 16 }
+bench_horizontal! { f32x32, SimdF32, f32, 32 }
+bench_horizontal! { f64x8, SimdF64, f64, 8 }
+bench_horizontal! { f64x16, SimdF64, f64, 16 }
+bench_horizontal! { f64x32, SimdF64, f64, 32 }
+bench_horizontal! { i32x8, SimdI32, i32, 8 }
+bench_horizontal! { i32x16, SimdI32, i32, 16 }
+bench_horizontal! { i32x32, SimdI32, i32, 32 }
@@ -0,0 +1,55 @@
+//! Benchmarks for `simd_sort`, compared against `sort_unstable`.
+#![feature(test)]
+
+extern crate test;
+
+use core_simd::simd_sort;
+use test::{black_box, Bencher};
+
+/// A simple linear congruential generator, so that the inputs are reproducible.
+fn random(len: usize) -> Vec<u32> {
+    let mut state = 1u32;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            state
+        })
+        .collect()
+}
+
+macro_rules! bench_sort {
+    { $name:ident, $ty:ty, $len:literal, $map:expr } => {
+        mod $name {
+            use super::*;
+
+            fn input() -> Vec<$ty> {
+                random($len).into_iter().map($map).collect()
+            }
+
+            #[bench]
+            fn simd_sort(b: &mut Bencher) {
+                let input = input();
+                b.iter(|| {
+                    let mut v = black_box(&input).clone();
+                    super::simd_sort(&mut v);
+                    v
+                });
+            }
+
+            #[bench]
+            fn sort_unstable(b: &mut Bencher) {
+                let input = input();
+                b.iter(|| {
+                    let mut v = black_box(&input).clone();
+                    v.sort_unstable();
+                    v
+                });
+            }
+        }
+    }
+}
+
+bench_sort! { u32_1k, u32, 1000, |x| x }
+bench_sort! { u32_100k, u32, 100_000, |x| x }
+bench_sort! { i32_100k, i32, 100_000, |x| x as i32 }
+bench_sort! { i32_100k_few_distinct, i32, 100_000, |x| (x >> 28) as i32 }
@@ -0,0 +1,256 @@
+//! An owned buffer of elements that is always aligned for loading whole vectors.
+
+use crate::{LaneCount, Simd, SimdElement, SupportedLaneCount};
+use alloc::vec::Vec;
+
+/// A growable buffer of `T`, stored as a sequence of `Simd<T, LANES>` vectors.
+///
+/// The buffer is always aligned to [`Simd::ALIGN`], so it can be viewed both as a slice of
+/// elements and as a slice of vectors, and loaded without checking alignment.  The elements are
+/// padded to a whole number of vectors with `T::default()`.  The padding is included in
+/// [`as_vectors`](Self::as_vectors), but not in [`as_slice`](Self::as_slice).
+///
+/// The buffer dereferences to a slice of elements, like `Vec<T>`.
+///
+/// # Examples
+/// ```
+/// # use core_simd::*;
+/// let mut buf = AlignedBuf::<f32, 4>::from_slice(&[1., 2., 3., 4., 5.]);
+/// buf.push(6.);
+/// assert_eq!(buf.len(), 6);
+/// assert_eq!(buf.as_vectors(), [f32x4::from_array([1., 2., 3., 4.]), f32x4::from_array([5., 6., 0., 0.])]);
+/// let sum = buf.as_vectors().iter().fold(f32x4::splat(0.), |sum, &v| sum + v);
+/// assert_eq!(sum.horizontal_sum(), 21.);
+/// ```
+#[derive(Clone, Debug)]
+pub struct AlignedBuf<T, const LANES: usize>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    vectors: Vec<Simd<T, LANES>>,
+    len: usize,
+}
+
+impl<T, const LANES: usize> AlignedBuf<T, LANES>
+where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Creates an empty buffer.  This doesn't allocate.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            vectors: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Creates an empty buffer with space for at least `capacity` elements.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            vectors: Vec::with_capacity(Self::vector_count(capacity)),
+            len: 0,
+        }
+    }
+
+    /// Creates a buffer of `len` copies of `value`.
+    #[inline]
+    pub fn splat(value: T, len: usize) -> Self {
+        let mut buf = Self {
+            vectors: alloc::vec![Simd::splat(value); Self::vector_count(len)],
+            len,
+        };
+        buf.clear_padding();
+        buf
+    }
+
+    /// Creates a buffer containing a copy of `slice`.
+    #[inline]
+    pub fn from_slice(slice: &[T]) -> Self {
+        let mut buf = Self::with_capacity(slice.len());
+        buf.extend_from_slice(slice);
+        buf
+    }
+
+    /// Returns the number of elements in the buffer, not including padding.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the buffer contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the elements of the buffer.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &Simd::flatten_slice(&self.vectors)[..self.len]
+    }
+
+    /// Returns the elements of the buffer, mutably.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut Simd::flatten_slice_mut(&mut self.vectors)[..self.len]
+    }
+
+    /// Returns the vectors of the buffer, including the padding in the last vector.
+    #[inline]
+    pub fn as_vectors(&self) -> &[Simd<T, LANES>] {
+        &self.vectors
+    }
+
+    /// Returns the vectors of the buffer mutably, including the padding in the last vector.
+    ///
+    /// Changes to the padding are discarded: the padding is reset to `T::default()` whenever the
+    /// length of the buffer changes.
+    #[inline]
+    pub fn as_mut_vectors(&mut self) -> &mut [Simd<T, LANES>] {
+        &mut self.vectors
+    }
+
+    /// Appends an element to the end of the buffer.
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        if self.len == self.vectors.len() * LANES {
+            self.vectors.push(Simd::splat(T::default()));
+        }
+        let len = self.len;
+        Simd::flatten_slice_mut(&mut self.vectors)[len] = value;
+        self.len += 1;
+        self.clear_padding();
+    }
+
+    /// Appends a copy of each element of `slice` to the end of the buffer.
+    #[inline]
+    pub fn extend_from_slice(&mut self, slice: &[T]) {
+        let len = self.len + slice.len();
+        self.vectors
+            .resize(Self::vector_count(len), Simd::splat(T::default()));
+        Simd::flatten_slice_mut(&mut self.vectors)[self.len..len].copy_from_slice(slice);
+        self.len = len;
+        self.clear_padding();
+    }
+
+    /// Shortens the buffer to `len` elements.  Does nothing if the buffer is already shorter.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            self.vectors.truncate(Self::vector_count(len));
+            self.len = len;
+            self.clear_padding();
+        }
+    }
+
+    /// Removes all elements from the buffer, keeping its allocation.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Returns the number of vectors needed to hold `len` elements.
+    fn vector_count(len: usize) -> usize {
+        let count = len / LANES;
+        if count * LANES < len {
+            count + 1
+        } else {
+            count
+        }
+    }
+
+    /// Resets the lanes after the last element to `T::default()`.
+    fn clear_padding(&mut self) {
+        let len = self.len;
+        for lane in &mut Simd::flatten_slice_mut(&mut self.vectors)[len..] {
+            *lane = T::default();
+        }
+    }
+}
+
+impl<T, const LANES: usize> Default for AlignedBuf<T, LANES>
+where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Compares the elements of the buffers, ignoring the padding.
+impl<T, const LANES: usize> PartialEq for AlignedBuf<T, LANES>
+where
+    T: SimdElement + Default + PartialEq,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T, const LANES: usize> core::ops::Deref for AlignedBuf<T, LANES>
+where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const LANES: usize> core::ops::DerefMut for AlignedBuf<T, LANES>
+where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const LANES: usize> From<&[T]> for AlignedBuf<T, LANES>
+where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn from(slice: &[T]) -> Self {
+        Self::from_slice(slice)
+    }
+}
+
+impl<T, const LANES: usize> Extend<T> for AlignedBuf<T, LANES>
+where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T, const LANES: usize> core::iter::FromIterator<T> for AlignedBuf<T, LANES>
+where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut buf = Self::new();
+        buf.extend(iter);
+        buf
+    }
+}
@@ -0,0 +1,72 @@
+//! `arbitrary` support, for fuzzing code that takes vectors and masks as input.
+
+use crate::{LaneCount, Mask, MaskElement, Simd, SimdElement, SupportedLaneCount};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Each lane is generated independently, so the size hint is that of `LANES` lanes.
+fn lanes_size_hint<'a, T: Arbitrary<'a>>(lanes: usize, depth: usize) -> (usize, Option<usize>) {
+    let (lower, upper) = T::size_hint(depth);
+    (
+        lower.saturating_mul(lanes),
+        upper.and_then(|upper| upper.checked_mul(lanes)),
+    )
+}
+
+impl<'a, T, const LANES: usize> Arbitrary<'a> for Simd<T, LANES>
+where
+    T: SimdElement + Default + Arbitrary<'a>,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut value = Self::default();
+        for lane in value.as_mut_slice() {
+            *lane = T::arbitrary(u)?;
+        }
+        Ok(value)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        lanes_size_hint::<T>(LANES, depth)
+    }
+}
+
+impl<'a, T, const LANES: usize> Arbitrary<'a> for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut value = Self::splat(false);
+        for lane in 0..LANES {
+            value.set(lane, bool::arbitrary(u)?);
+        }
+        Ok(value)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        lanes_size_hint::<bool>(LANES, depth)
+    }
+}
+
+/// Half-precision vectors are generated from arbitrary bit patterns.
+macro_rules! impl_half_arbitrary {
+    { $($feature:literal => $type:ident),* } => {
+        $(
+        #[cfg(feature = $feature)]
+        impl<'a, const LANES: usize> Arbitrary<'a> for crate::$type<LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                crate::SimdU16::arbitrary(u).map(Self::from_bits)
+            }
+
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                crate::SimdU16::<LANES>::size_hint(depth)
+            }
+        }
+        )*
+    }
+}
+
+impl_half_arbitrary! { "f16" => SimdF16, "bf16" => SimdBf16 }
@@ -0,0 +1,87 @@
+//! ASCII classification and case conversion on byte vectors.
+
+use crate::{LaneCount, Mask, Simd, SupportedLaneCount};
+
+impl<const LANES: usize> Simd<u8, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Returns true for each lane if its value is within the ASCII range.
+    ///
+    /// Use [`Mask::all`] on the result to check an entire vector, as with
+    /// [`slice::is_ascii`].
+    ///
+    /// ```
+    /// # use core_simd::SimdU8;
+    /// let a = SimdU8::from_array([b'a', 0x7f, 0x80, 0xff]);
+    /// assert_eq!(a.is_ascii().to_array(), [true, true, false, false]);
+    /// ```
+    #[inline]
+    pub fn is_ascii(self) -> Mask<i8, LANES> {
+        self.lanes_lt(Self::splat(0x80))
+    }
+
+    /// Returns true for each lane if it is an ASCII lowercase letter.
+    #[inline]
+    fn is_ascii_lowercase_lanes(self) -> Mask<i8, LANES> {
+        // Wrapping subtraction moves every lane below `b'a'` above the range as well.
+        self.wrapping_sub(Self::splat(b'a'))
+            .lanes_lt(Self::splat(26))
+    }
+
+    /// Returns true for each lane if it is an ASCII uppercase letter.
+    #[inline]
+    fn is_ascii_uppercase_lanes(self) -> Mask<i8, LANES> {
+        self.wrapping_sub(Self::splat(b'A'))
+            .lanes_lt(Self::splat(26))
+    }
+
+    /// Converts each lane to its ASCII uppercase equivalent.
+    ///
+    /// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z', and all other values are unchanged,
+    /// as with [`u8::to_ascii_uppercase`].
+    ///
+    /// ```
+    /// # use core_simd::SimdU8;
+    /// let a = SimdU8::from_array(*b"aZ{`");
+    /// assert_eq!(&a.to_ascii_uppercase().to_array(), b"AZ{`");
+    /// ```
+    #[inline]
+    pub fn to_ascii_uppercase(self) -> Self {
+        self ^ self
+            .is_ascii_lowercase_lanes()
+            .select(Self::splat(0x20), Self::splat(0))
+    }
+
+    /// Converts each lane to its ASCII lowercase equivalent.
+    ///
+    /// ASCII letters 'A' to 'Z' are mapped to 'a' to 'z', and all other values are unchanged,
+    /// as with [`u8::to_ascii_lowercase`].
+    ///
+    /// ```
+    /// # use core_simd::SimdU8;
+    /// let a = SimdU8::from_array(*b"aZ[@");
+    /// assert_eq!(&a.to_ascii_lowercase().to_array(), b"az[@");
+    /// ```
+    #[inline]
+    pub fn to_ascii_lowercase(self) -> Self {
+        self ^ self
+            .is_ascii_uppercase_lanes()
+            .select(Self::splat(0x20), Self::splat(0))
+    }
+
+    /// Returns true for each lane if it is equal to the corresponding lane in `other`, ignoring
+    /// ASCII case, as with [`u8::eq_ignore_ascii_case`].
+    ///
+    /// ```
+    /// # use core_simd::SimdU8;
+    /// let a = SimdU8::from_array(*b"Rust");
+    /// let b = SimdU8::from_array(*b"rUsH");
+    /// assert_eq!(a.eq_ignore_ascii_case(b).to_array(), [true, true, true, false]);
+    /// ```
+    #[inline]
+    pub fn eq_ignore_ascii_case(self, other: Self) -> Mask<i8, LANES> {
+        self.to_ascii_lowercase()
+            .lanes_eq(other.to_ascii_lowercase())
+    }
+}
@@ -0,0 +1,59 @@
+//! `bytemuck` support, for casting vectors to and from bytes.
+
+use crate::{LaneCount, Mask, MaskElement, Simd, SimdElement, SupportedLaneCount};
+use bytemuck::{Pod, Zeroable};
+
+// Safety: a vector has the same size as its lane array, and every supported lane count is a power
+// of two, so there is no padding.  Every bit pattern is valid if it is valid for the lanes.
+unsafe impl<T, const LANES: usize> Zeroable for Simd<T, LANES>
+where
+    T: SimdElement + Zeroable,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
+
+unsafe impl<T, const LANES: usize> Pod for Simd<T, LANES>
+where
+    T: SimdElement + Pod,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
+
+// Safety: both mask representations use zero for `false`.  Masks are not `Pod`, since their
+// layout is unspecified and not every bit pattern is a valid mask.
+unsafe impl<T, const LANES: usize> Zeroable for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
+
+/// Half-precision vectors are transparent wrappers around their bit patterns.
+macro_rules! impl_half_bytemuck {
+    { $($feature:literal => $type:ident),* } => {
+        $(
+        #[cfg(feature = $feature)]
+        unsafe impl<const LANES: usize> Zeroable for crate::$type<LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+        }
+
+        #[cfg(feature = $feature)]
+        unsafe impl<const LANES: usize> Pod for crate::$type<LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+        }
+
+        #[cfg(feature = $feature)]
+        unsafe impl<const LANES: usize> bytemuck::TransparentWrapper<crate::SimdU16<LANES>> for crate::$type<LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+        }
+        )*
+    }
+}
+
+impl_half_bytemuck! { "f16" => SimdF16, "bf16" => SimdBf16 }
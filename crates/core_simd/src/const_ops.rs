@@ -0,0 +1,104 @@
+//! Lanewise arithmetic as `const fn`, for computing vectors in constants.
+//!
+//! The operator traits can't be implemented as `const`, so these are separate methods.  They
+//! operate on the lanes one at a time, and are slower than the operators outside of constants.
+
+use crate::{LaneCount, Simd, SupportedLaneCount};
+
+/// Implements a binary `const fn` that applies `$op` to each pair of lanes.
+macro_rules! const_lanewise {
+    { $(#[$attr:meta])* fn $name:ident(|$a:ident, $b:ident| $op:expr) } => {
+        $(#[$attr])*
+        #[inline]
+        pub const fn $name(self, rhs: Self) -> Self {
+            let mut lanes = self.to_array();
+            let rhs = rhs.to_array();
+            let mut i = 0;
+            while i < LANES {
+                let ($a, $b) = (lanes[i], rhs[i]);
+                lanes[i] = $op;
+                i += 1;
+            }
+            Self::from_array(lanes)
+        }
+    }
+}
+
+macro_rules! impl_const_integer_ops {
+    { $($type:ty),* } => {
+        $(
+        impl<const LANES: usize> Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            const_lanewise! {
+                /// Lanewise wrapping addition, usable in constants.  Equivalent to
+                /// [`wrapping_add`](Self::wrapping_add).
+                fn const_add(|a, b| a.wrapping_add(b))
+            }
+
+            const_lanewise! {
+                /// Lanewise wrapping subtraction, usable in constants.  Equivalent to
+                /// [`wrapping_sub`](Self::wrapping_sub).
+                fn const_sub(|a, b| a.wrapping_sub(b))
+            }
+
+            const_lanewise! {
+                /// Lanewise wrapping multiplication, usable in constants.  Equivalent to
+                /// [`wrapping_mul`](Self::wrapping_mul).
+                fn const_mul(|a, b| a.wrapping_mul(b))
+            }
+
+            const_lanewise! {
+                /// Lanewise bitwise "and", usable in constants.  Equivalent to `self & rhs`.
+                fn const_and(|a, b| a & b)
+            }
+
+            const_lanewise! {
+                /// Lanewise bitwise "or", usable in constants.  Equivalent to `self | rhs`.
+                fn const_or(|a, b| a | b)
+            }
+
+            const_lanewise! {
+                /// Lanewise bitwise "xor", usable in constants.  Equivalent to `self ^ rhs`.
+                fn const_xor(|a, b| a ^ b)
+            }
+        }
+        )*
+    }
+}
+
+impl_const_integer_ops! { u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize }
+
+macro_rules! impl_const_float_ops {
+    { $($type:ty),* } => {
+        $(
+        impl<const LANES: usize> Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            const_lanewise! {
+                /// Lanewise addition, usable in constants.  Equivalent to `self + rhs`.
+                fn const_add(|a, b| a + b)
+            }
+
+            const_lanewise! {
+                /// Lanewise subtraction, usable in constants.  Equivalent to `self - rhs`.
+                fn const_sub(|a, b| a - b)
+            }
+
+            const_lanewise! {
+                /// Lanewise multiplication, usable in constants.  Equivalent to `self * rhs`.
+                fn const_mul(|a, b| a * b)
+            }
+
+            const_lanewise! {
+                /// Lanewise division, usable in constants.  Equivalent to `self / rhs`.
+                fn const_div(|a, b| a / b)
+            }
+        }
+        )*
+    }
+}
+
+impl_const_float_ops! { f32, f64 }
@@ -0,0 +1,108 @@
+//! Comparison and selection for cryptographic code, where the running time must not depend on
+//! the values being compared.
+//!
+//! These are implemented with arithmetic and bitwise intrinsics only.  Comparisons may be lowered
+//! to branches on targets without vector comparison instructions, and the shift operators branch
+//! on their shift amounts.
+
+use crate::intrinsics;
+use crate::{LaneCount, Mask, Simd, SupportedLaneCount};
+
+macro_rules! impl_constant_time {
+    { $($type:ty => $mask:ty),* } => {
+        $(
+        impl<const LANES: usize> Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            /// Returns all ones in each lane that differs between `self` and `other`, and zero
+            /// in each lane that is equal.
+            #[inline]
+            fn ct_ne_int(self, other: Self) -> Simd<$mask, LANES> {
+                const SIGN_SHIFT: $mask = (core::mem::size_of::<$mask>() * 8 - 1) as $mask;
+                unsafe {
+                    let diff: Simd<$mask, LANES> = intrinsics::simd_cast(intrinsics::simd_xor(self, other));
+                    // The sign bit of `diff | -diff` is set if and only if `diff` is nonzero,
+                    // and the arithmetic shift copies it to every bit.
+                    let neg = intrinsics::simd_sub(Simd::splat(0), diff);
+                    intrinsics::simd_shr(intrinsics::simd_or(diff, neg), Simd::splat(SIGN_SHIFT))
+                }
+            }
+
+            /// Tests each lane for equality, in constant time.
+            ///
+            /// The running time doesn't depend on the values of `self` or `other`: there are no
+            /// data-dependent branches or memory accesses.  This is a property of the generated
+            /// code rather than something the compiler guarantees, so it should be checked for
+            /// targets where it matters.
+            ///
+            /// To compare entire vectors, such as message authentication codes, use
+            /// [`Mask::all`] on the result, which doesn't leak which lanes differ.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = Simd::<", stringify!($type), ", 4>::from_array([1, 2, 3, 4]);")]
+            #[doc = concat!("let b = Simd::<", stringify!($type), ", 4>::from_array([1, 0, 3, 0]);")]
+            /// assert_eq!(a.ct_eq(b).to_array(), [true, false, true, false]);
+            /// assert!(a.ct_eq(a).all());
+            /// ```
+            #[inline]
+            pub fn ct_eq(self, other: Self) -> Mask<$mask, LANES> {
+                // Safety: `ct_ne_int` returns all ones or zero in each lane.
+                unsafe { Mask::from_int_unchecked(intrinsics::simd_xor(self.ct_ne_int(other), Simd::splat(-1))) }
+            }
+
+            /// Returns a bitmask with the bit for each lane set if the lanes of `self` and
+            /// `other` differ, in constant time.
+            ///
+            /// The bits are ordered as in [`Mask::to_bitmask`].  Like [`ct_eq`](Self::ct_eq),
+            /// this has no data-dependent branches or memory accesses.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = Simd::<", stringify!($type), ", 4>::from_array([1, 2, 3, 4]);")]
+            #[doc = concat!("let b = Simd::<", stringify!($type), ", 4>::from_array([1, 0, 3, 0]);")]
+            /// assert_eq!(a.ct_ne_bitmask(b), [0b1010]);
+            /// ```
+            #[inline]
+            pub fn ct_ne_bitmask(self, other: Self) -> <LaneCount<LANES> as SupportedLaneCount>::BitMask {
+                // Safety: `ct_ne_int` returns all ones or zero in each lane.
+                unsafe { Mask::from_int_unchecked(self.ct_ne_int(other)).to_bitmask() }
+            }
+
+            /// Chooses each lane from `true_values` if the lane of `mask` is set, and from
+            /// `false_values` otherwise, in constant time.
+            ///
+            /// This is equivalent to [`Mask::select`], but is implemented with bitwise
+            /// operations so that there are no data-dependent branches or memory accesses.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = Simd::<", stringify!($type), ", 4>::from_array([1, 2, 3, 4]);")]
+            #[doc = concat!("let b = Simd::<", stringify!($type), ", 4>::from_array([5, 6, 7, 8]);")]
+            #[doc = concat!("let mask = Mask::<", stringify!($mask), ", 4>::from_array([true, false, false, true]);")]
+            #[doc = concat!("assert_eq!(Simd::<", stringify!($type), ", 4>::ct_select(mask, a, b).to_array(), [1, 6, 7, 4]);")]
+            /// ```
+            #[inline]
+            pub fn ct_select(mask: Mask<$mask, LANES>, true_values: Self, false_values: Self) -> Self {
+                unsafe {
+                    let mask: Self = intrinsics::simd_cast(mask.to_int());
+                    let not_mask = intrinsics::simd_xor(mask, Self::splat(!0));
+                    intrinsics::simd_or(
+                        intrinsics::simd_and(true_values, mask),
+                        intrinsics::simd_and(false_values, not_mask),
+                    )
+                }
+            }
+        }
+        )*
+    }
+}
+
+impl_constant_time! {
+    u8 => i8, u16 => i16, u32 => i32, u64 => i64, u128 => i128, usize => isize,
+    i8 => i8, i16 => i16, i32 => i32, i64 => i64, i128 => i128, isize => isize
+}
@@ -0,0 +1,78 @@
+//! Runtime selection between versions of a function compiled for different target features.
+
+/// Defines a function that is compiled for several target feature levels, and calls the best
+/// one the CPU supports.
+///
+/// Vector code only uses the instructions enabled at compile time, so a program built for the
+/// baseline `x86_64` target uses 128-bit SSE2 instructions even on a CPU with AVX-512.  This
+/// macro compiles the body once per level, and checks which level the CPU supports on each call.
+/// The standard library caches the result of detection, so the check is cheap.
+///
+/// The levels are:
+/// * `x86` and `x86_64`: `avx512f`, `avx2` with `fma`, and the baseline target features.
+/// * All other targets: the baseline target features only.  NEON is always enabled on
+///   `aarch64`, so the baseline version already uses it.
+///
+/// The body is an ordinary function body, and calls to inlined functions (such as the vector
+/// operations) are compiled with the selected features.  Arguments must be plain identifiers,
+/// and the function can't be generic.
+///
+/// Detection requires the standard library in the calling crate.  On toolchains where AVX-512
+/// target features are unstable, the calling crate must enable `avx512_target_feature`.
+///
+/// # Example
+/// ```
+/// # #![cfg_attr(any(target_arch = "x86", target_arch = "x86_64"), feature(avx512_target_feature))]
+/// # use core_simd::*;
+/// core_simd::dispatch! {
+///     /// Adds each vector of `b` to the corresponding vector of `a`.
+///     pub fn add_assign(a: &mut [f32x16], b: &[f32x16]) {
+///         for (a, b) in a.iter_mut().zip(b) {
+///             *a += *b;
+///         }
+///     }
+/// }
+///
+/// let mut a = [f32x16::splat(1.); 4];
+/// add_assign(&mut a, &[f32x16::splat(2.); 4]);
+/// assert_eq!(a, [f32x16::splat(3.); 4]);
+/// ```
+#[macro_export]
+macro_rules! dispatch {
+    {
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)? $body:block
+    } => {
+        $(#[$attr])*
+        $vis fn $name($($arg: $ty),*) $(-> $ret)? {
+            #[inline(always)]
+            fn kernel($($arg: $ty),*) $(-> $ret)? $body
+
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            {
+                #[target_feature(enable = "avx512f")]
+                unsafe fn avx512f($($arg: $ty),*) $(-> $ret)? {
+                    kernel($($arg),*)
+                }
+
+                #[target_feature(enable = "avx2,fma")]
+                unsafe fn avx2($($arg: $ty),*) $(-> $ret)? {
+                    kernel($($arg),*)
+                }
+
+                if ::std::is_x86_feature_detected!("avx512f") {
+                    // Safety: the CPU supports the target features of `avx512f`.
+                    return unsafe { avx512f($($arg),*) };
+                }
+                if ::std::is_x86_feature_detected!("avx2")
+                    && ::std::is_x86_feature_detected!("fma")
+                {
+                    // Safety: the CPU supports the target features of `avx2`.
+                    return unsafe { avx2($($arg),*) };
+                }
+            }
+
+            kernel($($arg),*)
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Float operations that trade reproducibility for speed.
+
+use crate::{LaneCount, Simd, SupportedLaneCount};
+
+mod sealed {
+    pub trait Sealed {}
+}
+use sealed::Sealed;
+
+impl<const LANES: usize> Sealed for Simd<f32, LANES> where LaneCount<LANES>: SupportedLaneCount {}
+impl<const LANES: usize> Sealed for Simd<f64, LANES> where LaneCount<LANES>: SupportedLaneCount {}
+
+/// Float methods whose results may differ between targets, compiler versions, or optimization
+/// levels, in exchange for speed.
+///
+/// The inherent float methods give the same result everywhere: reductions add lanes in a fixed
+/// order, and no operations are fused unless requested.  These methods relax that, in the
+/// manner of "fast math" compiler flags, but only where they are called.  Results are still
+/// close to the exact value for finite inputs, and the operations never cause undefined
+/// behavior.
+///
+/// This trait is sealed and implemented for every float vector.
+pub trait FastFloat: Sealed + Sized {
+    /// The type of each lane.
+    type Scalar;
+
+    /// Returns the sum of the lanes, added in any order.
+    ///
+    /// Unlike the inherent `horizontal_sum`, the lanes may be added in whatever order is fastest
+    /// on the target.  Only the additions within the reduction are reordered: they aren't
+    /// reassociated with surrounding code, so summing a slice is still faster with a vector
+    /// accumulator reduced once at the end.
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    fn fast_sum(self) -> Self::Scalar;
+
+    /// Returns the product of the lanes, multiplied in any order.
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    fn fast_product(self) -> Self::Scalar;
+
+    /// Returns the dot product of `self` and `other`, the sum of the products of corresponding
+    /// lanes.
+    ///
+    /// Unlike the inherent `dot`, on targets with fused multiply-add instructions the products
+    /// of the lower half of the lanes are fused into the first step of the sum, so they are not
+    /// rounded on their own.  Elsewhere the products are summed in any order.
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    fn fast_dot(self, other: Self) -> Self::Scalar;
+
+    /// Computes `(self * a) + b`, fused into one operation if the target has fused multiply-add
+    /// instructions, and as a separate multiplication and addition otherwise.
+    ///
+    /// Unlike `StdFloat::mul_add`, this never calls the math library, but the rounding depends
+    /// on the target.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn fast_mul_add(self, a: Self, b: Self) -> Self;
+
+    /// Divides each lane by the corresponding lane of `rhs`, by multiplying with its
+    /// reciprocal.
+    ///
+    /// Multiplication is much faster than division, and the reciprocal of a divisor used
+    /// repeatedly, such as in a loop, can be computed once.  When the reciprocal of `rhs` and
+    /// the quotient are both normal, the result is within 2 ULP of `self / rhs`.  This holds
+    /// for `rhs` of magnitude from `MIN_POSITIVE` up to `2^126` for `f32`, or `2^1022` for
+    /// `f64`.  Larger divisors have a subnormal reciprocal, which has fewer bits of precision,
+    /// so the error may be a few ULP more.  Divisors smaller than `2^-128` for `f32`, or
+    /// `2^-1024` for `f64`, have an infinite reciprocal, so the result is infinite or `NaN`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn fast_div(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_fast_float {
+    { $($type:ty),* } => {
+        $(
+        impl<const LANES: usize> FastFloat for Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Scalar = $type;
+
+            #[inline]
+            fn fast_sum(self) -> $type {
+                unsafe { crate::intrinsics::simd_reduce_add_unordered(self) }
+            }
+
+            #[inline]
+            fn fast_product(self) -> $type {
+                unsafe { crate::intrinsics::simd_reduce_mul_unordered(self) }
+            }
+
+            #[inline]
+            fn fast_dot(self, other: Self) -> $type {
+                #[cfg(target_feature = "fma")]
+                {
+                    if LANES == 1 {
+                        return self[0] * other[0];
+                    }
+                    let upper = crate::permute::rotate_half(self) * crate::permute::rotate_half(other);
+                    let pairs = unsafe { crate::intrinsics::simd_fma(self, other, upper) };
+                    crate::permute::reduce_lanes(pairs, LANES / 2, |a, b| a + b)
+                }
+                #[cfg(not(target_feature = "fma"))]
+                {
+                    (self * other).fast_sum()
+                }
+            }
+
+            #[inline]
+            fn fast_mul_add(self, a: Self, b: Self) -> Self {
+                #[cfg(target_feature = "fma")]
+                {
+                    unsafe { crate::intrinsics::simd_fma(self, a, b) }
+                }
+                #[cfg(not(target_feature = "fma"))]
+                {
+                    self * a + b
+                }
+            }
+
+            #[inline]
+            fn fast_div(self, rhs: Self) -> Self {
+                self * (Self::splat(1.0) / rhs)
+            }
+        }
+        )*
+    }
+}
+
+impl_fast_float! { f32, f64 }
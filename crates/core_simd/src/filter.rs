@@ -0,0 +1,149 @@
+//! Filter kernels, which evaluate a vector predicate over a slice and report the matches.
+
+use crate::{LaneCount, Mask, Simd, SimdElement, SupportedLaneCount};
+use core::convert::TryInto;
+
+/// Evaluates `predicate` on each vector of `slice`, calling `f` with the index of the first
+/// element of the vector and the mask of matches.
+///
+/// The elements at the end of the slice are padded with `T::default()` to fill a vector, and the
+/// padding lanes are cleared in the mask.
+#[inline]
+fn for_each_match_mask<T, const LANES: usize>(
+    slice: &[T],
+    mut predicate: impl FnMut(Simd<T, LANES>) -> Mask<T::Mask, LANES>,
+    mut f: impl FnMut(usize, Mask<T::Mask, LANES>),
+) where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mut chunks = slice.chunks_exact(LANES);
+    for (i, chunk) in chunks.by_ref().enumerate() {
+        f(
+            i * LANES,
+            predicate(Simd::from_array(chunk.try_into().unwrap())),
+        );
+    }
+    let remainder = chunks.remainder();
+    if !remainder.is_empty() {
+        let mut padded = [T::default(); LANES];
+        padded[..remainder.len()].copy_from_slice(remainder);
+        let mut in_bounds = [false; LANES];
+        for lane in &mut in_bounds[..remainder.len()] {
+            *lane = true;
+        }
+        let mask = predicate(Simd::from_array(padded)) & Mask::from_array(in_bounds);
+        f(slice.len() - remainder.len(), mask);
+    }
+}
+
+/// Evaluates `predicate` on `slice`, `LANES` elements at a time, and sets the bits of `bitmap`
+/// for the elements that match.  Returns the number of matches.
+///
+/// Bit `i` of the bitmap is bit `i % 8` of byte `i / 8`, which is the layout of Apache Arrow
+/// validity bitmaps.  The bits after the end of the slice in the last byte are cleared.  The
+/// predicate is also evaluated on the padding after the last elements, but its result for those
+/// lanes is ignored.
+///
+/// # Panics
+/// Panics if `bitmap` has fewer than `ceil(slice.len() / 8)` bytes.
+///
+/// # Examples
+/// ```
+/// # use core_simd::*;
+/// let values = [5, -3, 8, 0, 12, -7, 1, 9, 4, 10];
+/// let mut bitmap = [0; 2];
+/// let count = filter_bitmap(&values, &mut bitmap, |v: i32x4| v.lanes_gt(i32x4::splat(4)));
+/// assert_eq!(count, 5);
+/// assert_eq!(bitmap, [0b1001_0101, 0b10]);
+/// ```
+pub fn filter_bitmap<T, const LANES: usize>(
+    slice: &[T],
+    bitmap: &mut [u8],
+    predicate: impl FnMut(Simd<T, LANES>) -> Mask<T::Mask, LANES>,
+) -> usize
+where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mut bytes = slice.len() / 8;
+    if bytes * 8 < slice.len() {
+        bytes += 1;
+    }
+    assert!(bitmap.len() >= bytes, "bitmap is too short for the slice");
+    let bitmap = &mut bitmap[..bytes];
+    for byte in bitmap.iter_mut() {
+        *byte = 0;
+    }
+    let mut count = 0;
+    for_each_match_mask(slice, predicate, |start, mask| {
+        // Vectors narrower than a byte start at a multiple of their width within the byte, and
+        // wider vectors start at a byte boundary.
+        for (i, bits) in mask.to_bitmask().as_ref().iter().enumerate() {
+            let bit = start + 8 * i;
+            if let Some(byte) = bitmap.get_mut(bit / 8) {
+                *byte |= bits << (bit % 8);
+            }
+            count += bits.count_ones() as usize;
+        }
+    });
+    count
+}
+
+/// Evaluates `predicate` on `slice`, `LANES` elements at a time, and writes the indices of the
+/// elements that match to the start of `indices`, in increasing order.  Returns the number of
+/// matches.
+///
+/// The matches of each vector are packed with [`Simd::compress`].  This produces the selection
+/// vectors used by vectorized query engines, which list the rows that passed a filter.  The
+/// predicate is also evaluated on the padding after the last elements, but its result for those
+/// lanes is ignored.
+///
+/// # Panics
+/// Panics if `indices` is shorter than `slice`, or if `slice` has more than `u32::MAX`
+/// elements.
+///
+/// # Examples
+/// ```
+/// # use core_simd::*;
+/// let values = [5, -3, 8, 0, 12, -7, 1, 9, 4, 10];
+/// let mut indices = [0; 10];
+/// let count = filter_indices(&values, &mut indices, |v: i32x4| v.lanes_gt(i32x4::splat(4)));
+/// assert_eq!(indices[..count], [0, 2, 4, 7, 9]);
+/// ```
+pub fn filter_indices<T, const LANES: usize>(
+    slice: &[T],
+    indices: &mut [u32],
+    predicate: impl FnMut(Simd<T, LANES>) -> Mask<T::Mask, LANES>,
+) -> usize
+where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    assert!(
+        indices.len() >= slice.len(),
+        "indices is too short for the slice"
+    );
+    assert!(
+        slice.len() <= u32::MAX as usize,
+        "slice is too long for u32 indices"
+    );
+    let mut lane_index = [0; LANES];
+    for (i, index) in lane_index.iter_mut().enumerate() {
+        *index = i as u32;
+    }
+    let lane_index = Simd::from_array(lane_index);
+    let mut count = 0;
+    for_each_match_mask(slice, predicate, |start, mask| {
+        // Safety: casting a mask between lane widths sign-extends or truncates each lane, which
+        // keeps it 0 or -1.
+        let mask: Mask<i32, LANES> =
+            unsafe { Mask::from_int_unchecked(crate::intrinsics::simd_cast(mask.to_int())) };
+        let (packed, matches) = Simd::splat(start as u32)
+            .wrapping_add(lane_index)
+            .compress(mask);
+        indices[count..count + matches].copy_from_slice(&packed.as_array()[..matches]);
+        count += matches;
+    });
+    count
+}
@@ -0,0 +1,128 @@
+//! Lane-wise elementary functions on float vectors.
+//!
+//! These are implemented with range reduction and polynomial approximations built from vector
+//! operations, rather than calling the math library for each lane, so they are available without
+//! the standard library.
+
+mod exp_log;
+mod exponent;
+mod hyperbolic;
+mod inverse_trig;
+mod roots;
+mod trig;
+
+use crate::{LaneCount, Simd, SupportedLaneCount};
+
+/// Implements helpers shared by the elementary functions for a vector of float `$type`, which uses
+/// `$bits_ty` as its binary representation.
+macro_rules! impl_float_math_helpers {
+    {
+        $type:ty,
+        $bits_ty:ty,
+        $mantissa_bits:literal,
+        $exponent_bias:literal,
+        sqrt_iterations: $sqrt_iterations:literal
+    } => {
+        impl<const LANES: usize> Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            /// Adding and then subtracting this value rounds to the nearest integer, ties to even,
+            /// for any value less than `2^($mantissa_bits - 1)` in magnitude.
+            const ROUNDING_MAGIC: $type = (3u64 << ($mantissa_bits - 1)) as $type;
+
+            /// Evaluates the polynomial with the given coefficients, lowest degree first, at each
+            /// lane using Horner's method.
+            #[inline]
+            pub(crate) fn polynomial(self, coefficients: &[$type]) -> Self {
+                let mut coefficients = coefficients.iter().rev();
+                let mut result = Self::splat(*coefficients.next().unwrap());
+                for c in coefficients {
+                    result = result * self + Self::splat(*c);
+                }
+                result
+            }
+
+            /// Rounds each lane to the nearest integer, ties to even.
+            ///
+            /// Only valid for lanes less than `2^($mantissa_bits - 1)` in magnitude.
+            #[inline]
+            pub(crate) fn round_small(self) -> Self {
+                let magic = Self::splat(Self::ROUNDING_MAGIC);
+                (self + magic) - magic
+            }
+
+            /// Computes `2^self`, where each lane is an integer in the normal exponent range.
+            #[inline]
+            pub(crate) fn exp2_int(self) -> Self {
+                // Place the biased exponent in the low bits of the mantissa, then shift it into
+                // the exponent field.
+                let magic = Self::splat(Self::ROUNDING_MAGIC);
+                let biased = self + Self::splat($exponent_bias as $type) + magic;
+                Self::from_bits(biased.to_bits().wrapping_sub(magic.to_bits()) << $mantissa_bits)
+            }
+
+            /// Evaluates `x + x^3 * R(x^2)` at each lane, where `R` is the rational function with
+            /// the given numerator and denominator coefficients, lowest degree first.
+            #[inline]
+            pub(crate) fn odd_rational(self, numerator: &[$type], denominator: &[$type]) -> Self {
+                let z = self * self;
+                self + self * z * (z.polynomial(numerator) / z.polynomial(denominator))
+            }
+
+            /// Returns `self` with the sign of `sign` in each lane.
+            #[inline]
+            pub(crate) fn with_sign_of(self, sign: Self) -> Self {
+                let sign_bit = Simd::splat(!(<$bits_ty>::MAX >> 1));
+                Self::from_bits((self.to_bits() & !sign_bit) | (sign.to_bits() & sign_bit))
+            }
+
+            /// Computes the square root of each lane, which must be positive and normal, using
+            /// Newton's method rather than the math library.
+            #[inline]
+            pub(crate) fn sqrt_newton(self) -> Self {
+                // Halving the exponent gives an estimate within 6%, and each iteration roughly
+                // doubles the number of correct bits.
+                let one = Self::splat(1.0).to_bits();
+                let mut y = Self::from_bits((self.to_bits() >> 1) + (one >> 1));
+                for _ in 0..$sqrt_iterations {
+                    y = Self::splat(0.5) * (y + self / y);
+                }
+                y
+            }
+
+            /// Splits each lane of a positive, finite `self` into an exponent `e` and a
+            /// significand `m` in `[sqrt(2)/2, sqrt(2))` such that `self = m * 2^e`.
+            #[inline]
+            pub(crate) fn split_exponent(self) -> (Self, Self) {
+                // Scale subnormals into the normal range.
+                let subnormal = self.lanes_lt(Self::splat(<$type>::MIN_POSITIVE));
+                let scale = Self::splat((1u64 << $mantissa_bits) as $type);
+                let x = subnormal.select(self * scale, self);
+                let bits = x.to_bits();
+
+                // Read the exponent field as a float, the same way `exp2_int` writes it.
+                let mantissa_mask: $bits_ty = (1 << $mantissa_bits) - 1;
+                let magic = Self::splat(Self::ROUNDING_MAGIC);
+                let exponent_field = (bits & Simd::splat(<$bits_ty>::MAX >> 1)) >> $mantissa_bits;
+                let exponent = Self::from_bits(exponent_field + magic.to_bits())
+                    - magic
+                    - Self::splat($exponent_bias as $type)
+                    - subnormal.select(Self::splat($mantissa_bits as $type), Self::splat(0.0));
+
+                // Give the significand the exponent of 1.0, then halve it if it's large.
+                let significand = Self::from_bits(
+                    (bits & Simd::splat(mantissa_mask)) | Self::splat(1.0).to_bits(),
+                );
+                let large = significand.lanes_gt(Self::splat(core::f64::consts::SQRT_2 as $type));
+                (
+                    exponent + large.select(Self::splat(1.0), Self::splat(0.0)),
+                    large.select(significand * Self::splat(0.5), significand),
+                )
+            }
+        }
+    }
+}
+
+impl_float_math_helpers! { f32, u32, 23, 127, sqrt_iterations: 4 }
+impl_float_math_helpers! { f64, u64, 52, 1023, sqrt_iterations: 5 }
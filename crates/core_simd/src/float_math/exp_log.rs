@@ -0,0 +1,363 @@
+use crate::{LaneCount, Simd, SupportedLaneCount};
+
+/// Implements exponential and logarithm functions for a vector of float `$type`.
+///
+/// Constants given as pairs are split into a high part with few significant bits, so that
+/// multiplying it by an exponent is exact, and a low part holding the remainder.
+///
+/// `$exp_coefficients` approximate `e^r` for `|r| <= ln(2)/2`, and `$log_coefficients`
+/// approximate `(ln(1 + s) - ln(1 - s) - 2s) / s^3` as a polynomial in `s^2` for
+/// `|s| <= 3 - 2 * sqrt(2)`.
+macro_rules! impl_exp_log {
+    {
+        $type:ident,
+        max_ulps: $max_ulps:literal,
+        exp_range: ($exp_min:literal, $exp_max:literal),
+        ln_2: ($ln_2_hi:literal, $ln_2_lo:literal),
+        log2_e: ($log2_e_hi:literal, $log2_e_lo:literal),
+        log10_2: ($log10_2_hi:literal, $log10_2_lo:literal),
+        log10_e: ($log10_e_hi:literal, $log10_e_lo:literal),
+        two_thirds_lo: $two_thirds_lo:literal,
+        hi_mask: $hi_mask:literal,
+        exp_coefficients: $exp_coefficients:expr,
+        log_coefficients: $log_coefficients:expr,
+    } => {
+        impl<const LANES: usize> Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            /// Returns `e^(self)` for each lane.
+            ///
+            #[doc = concat!("The result is within ", stringify!($max_ulps), " ULP of the equivalent scalar method.")]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn exp(self) -> Self {
+                self.exp_extended(Self::splat(0.0))
+            }
+
+            /// Computes `e^(self + lo)`, where `lo` is much smaller than `self`.
+            #[inline]
+            fn exp_extended(self, lo: Self) -> Self {
+                // Clamp to a range where the result saturates to zero or infinity, which also
+                // replaces NaNs so they don't produce garbage exponents.
+                let x = self.lanes_lt(Self::splat($exp_max)).select(self, Self::splat($exp_max));
+                let x = x.lanes_gt(Self::splat($exp_min)).select(x, Self::splat($exp_min));
+                let lo = x.lanes_eq(self).select(lo, Self::splat(0.0));
+
+                // e^x = 2^n * e^r, where |r| <= ln(2)/2
+                let n = (x * Self::splat(core::$type::consts::LOG2_E)).round_small();
+                let r = (x - n * Self::splat($ln_2_hi)) - n * Self::splat($ln_2_lo) + lo;
+                let exp_r = r.polynomial(&$exp_coefficients);
+
+                // Scale in two steps, so that each power of two has a normal exponent.
+                let n_1 = (n * Self::splat(0.5)).round_small();
+                let n_2 = n - n_1;
+                let result = exp_r * n_1.exp2_int() * n_2.exp2_int();
+                self.is_nan().select(self, result)
+            }
+
+            /// Returns `e^(self) - 1` for each lane, which is accurate even when the lane is
+            /// close to zero.
+            ///
+            #[doc = concat!("The result is within ", stringify!($max_ulps), " ULP of the equivalent scalar method.")]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn exp_m1(self) -> Self {
+                let x = self.lanes_lt(Self::splat($exp_max)).select(self, Self::splat($exp_max));
+                let x = x.lanes_gt(Self::splat($exp_min)).select(x, Self::splat($exp_min));
+
+                // e^x - 1 = 2^n * (e^r - 1) + (2^n - 1), where |r| <= ln(2)/2
+                let n = (x * Self::splat(core::$type::consts::LOG2_E)).round_small();
+                let r = (x - n * Self::splat($ln_2_hi)) - n * Self::splat($ln_2_lo);
+                let exp_m1_r = r + r * r * r.polynomial(&$exp_coefficients[2..]);
+
+                // When 2^n - 1 isn't exact, or 2^n isn't a normal float, scale e^r instead.
+                let n_1 = (n * Self::splat(0.5)).round_small();
+                let n_2 = n - n_1;
+                let scale_1 = n_1.exp2_int();
+                let scale_2 = n_2.exp2_int();
+                let one = Self::splat(1.0);
+                let small = n.abs().lanes_le(Self::splat(<$type>::MANTISSA_DIGITS as $type - 1.0));
+                let scale = scale_1 * scale_2;
+                let result = small.select(
+                    exp_m1_r * scale + (scale - one),
+                    (exp_m1_r + one) * scale_1 * scale_2 - one,
+                );
+
+                // Preserve the sign of zero.
+                (self.is_nan() | self.lanes_eq(Self::splat(0.0))).select(self, result)
+            }
+
+            /// Computes `ln(1 + f)` as the sum of a high part with its low bits cleared and a
+            /// low part, for `f` in `[sqrt(2)/2 - 1, sqrt(2) - 1)`.
+            #[inline]
+            fn ln_1p_reduced(f: Self) -> (Self, Self) {
+                // ln(1 + f) = ln(1 + s) - ln(1 - s), where s = f / (2 + f)
+                //           = f - f^2/2 + s * (f^2/2 + R(s^2))
+                let s = f / (Self::splat(2.0) + f);
+                let z = s * s;
+                let r = z * z.polynomial(&$log_coefficients);
+                let half_f_squared = Self::splat(0.5) * f * f;
+                let hi = Self::from_bits((f - half_f_squared).to_bits() & Simd::splat($hi_mask));
+                let lo = (f - hi) - half_f_squared + s * (half_f_squared + r);
+                (hi, lo)
+            }
+
+            /// Computes a logarithm from the exponent and the high and low parts of the
+            /// logarithm of the significand, handling the lanes outside the domain of
+            /// `split_exponent`.
+            #[inline]
+            fn log_with(self, f: impl FnOnce(Self, Self, Self) -> Self) -> Self {
+                let (exponent, significand) = self.split_exponent();
+                let (hi, lo) = Self::ln_1p_reduced(significand - Self::splat(1.0));
+                let result = f(exponent, hi, lo);
+                let result = self.lanes_eq(Self::splat(0.0)).select(Self::splat(<$type>::NEG_INFINITY), result);
+                let result = self.lanes_lt(Self::splat(0.0)).select(Self::splat(<$type>::NAN), result);
+                let result = self.lanes_eq(Self::splat(<$type>::INFINITY)).select(self, result);
+                self.is_nan().select(self, result)
+            }
+
+            /// Returns the natural logarithm of each lane.
+            ///
+            #[doc = concat!("The result is within ", stringify!($max_ulps), " ULP of the equivalent scalar method.")]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn ln(self) -> Self {
+                self.log_with(|exponent, hi, lo| {
+                    exponent * Self::splat($ln_2_hi) + (hi + (lo + exponent * Self::splat($ln_2_lo)))
+                })
+            }
+
+            /// Returns `ln(1 + self)` for each lane, which is accurate even when the lane is close
+            /// to zero.
+            ///
+            #[doc = concat!("The result is within ", stringify!($max_ulps), " ULP of the equivalent scalar method.")]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn ln_1p(self) -> Self {
+                // ln(1 + x) = ln(u + c) ~= ln(u) + c/u, where c is the rounding error of u = 1 + x
+                let one = Self::splat(1.0);
+                let u = one + self;
+                let c = u.lanes_ge(Self::splat(2.0)).select(one - (u - self), self - (u - one));
+                let correction = c / u;
+                let result = u.log_with(|exponent, hi, lo| {
+                    exponent * Self::splat($ln_2_hi)
+                        + (hi + (lo + (exponent * Self::splat($ln_2_lo) + correction)))
+                });
+
+                // Preserve the sign of zero.
+                self.lanes_eq(Self::splat(0.0)).select(self, result)
+            }
+
+            /// Clears the low bits of each lane, so that the product of two such values is exact.
+            #[inline]
+            fn high_bits(self) -> Self {
+                Self::from_bits(self.to_bits() & Simd::splat($hi_mask))
+            }
+
+            /// Computes the product of each lane as the sum of a high and low part.
+            #[inline]
+            fn mul_exact(self, other: Self) -> (Self, Self) {
+                let product = self * other;
+                let (a_hi, a_lo) = (self.high_bits(), self - self.high_bits());
+                let (b_hi, b_lo) = (other.high_bits(), other - other.high_bits());
+                let error = (((a_hi * b_hi - product) + a_hi * b_lo) + a_lo * b_hi) + a_lo * b_lo;
+                (product, error)
+            }
+
+            /// Raises each lane of `self` to the power of the corresponding lane of `n`.
+            ///
+            #[doc = concat!("The result is within ", stringify!($max_ulps), " ULP of the equivalent scalar method.")]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn powf(self, n: Self) -> Self {
+                let one = Self::splat(1.0);
+                let zero = Self::splat(0.0);
+                let infinity = Self::splat(<$type>::INFINITY);
+                let x = self.abs();
+
+                // x^n = e^(n * ln(x)), where both the logarithm and the product are computed as
+                // the sum of a high and low part.
+                let (exponent, significand) = x.split_exponent();
+
+                // ln(m) = ln(1 + s) - ln(1 - s)
+                //       = 2s + 2/3 * s^3 + s^5 * R(s^2), where s = (m - 1) / (m + 1)
+                // The leading terms are computed to extra precision as the sum of a high and low
+                // part.
+                let f = significand - one;
+                let d = significand + one;
+                let d_lo = significand - (d - one);
+                let s = f / d;
+                let (s_d, s_d_lo) = s.mul_exact(d);
+                let s_lo = (((f - s_d) - s_d_lo) - s * d_lo) / d;
+                let (z, z_lo) = s.mul_exact(s);
+                let z_lo = z_lo + Self::splat(2.0) * s * s_lo;
+                let (s_3, s_3_lo) = s.mul_exact(z);
+                let s_3_lo = s_3_lo + (s * z_lo + s_lo * z);
+                let two_thirds = Self::splat(2.0 / 3.0);
+                let (cubic, cubic_lo) = s_3.mul_exact(two_thirds);
+                let cubic_lo =
+                    cubic_lo + (two_thirds * s_3_lo + Self::splat($two_thirds_lo) * s_3);
+                let tail = s_3 * z * z.polynomial(&$log_coefficients[1..]);
+
+                let two_s = s + s;
+                let exponent_hi = exponent * Self::splat($ln_2_hi);
+                let ln_hi = exponent_hi + two_s;
+                let ln_lo = (exponent_hi - ln_hi) + two_s;
+                let sum = ln_hi + cubic;
+                let ln_lo = ln_lo
+                    + ((ln_hi - sum) + cubic)
+                    + (cubic_lo + tail + (s_lo + s_lo) + exponent * Self::splat($ln_2_lo));
+                let ln_hi = sum;
+                let sum = ln_hi + ln_lo;
+                let ln_lo = ln_lo - (sum - ln_hi);
+                let ln_hi = sum;
+
+                let product = n * ln_hi;
+                let (n_hi, ln_hi_hi) = (n.high_bits(), ln_hi.high_bits());
+                let (n_lo, ln_hi_lo) = (n - n_hi, ln_hi - ln_hi_hi);
+                let product_lo = (((n_hi * ln_hi_hi - product) + n_hi * ln_hi_lo)
+                    + n_lo * ln_hi_hi)
+                    + n_lo * ln_hi_lo
+                    + n * ln_lo;
+                let product_lo = product.is_finite().select(product_lo, zero);
+                let result = product.exp_extended(product_lo);
+
+                // Zero and infinite bases and exponents.
+                let positive = n.lanes_gt(zero);
+                let result = x.lanes_eq(zero).select(positive.select(zero, infinity), result);
+                let result = x.lanes_eq(infinity).select(positive.select(infinity, zero), result);
+                let result = n.abs().lanes_eq(infinity).select(
+                    x.lanes_eq(one).select(
+                        one,
+                        (x.lanes_lt(one) ^ positive).select(infinity, zero),
+                    ),
+                    result,
+                );
+
+                // Negative bases are only defined for integer exponents.  Every float at least
+                // 2^(MANTISSA_DIGITS - 1) in magnitude is an integer, and every float at least
+                // twice that is even.
+                let limit = Self::splat((1u64 << (<$type>::MANTISSA_DIGITS - 1)) as $type);
+                let n_abs = n.abs();
+                let half = n_abs * Self::splat(0.5);
+                let integer = n_abs.lanes_ge(limit) | ((n_abs + limit) - limit).lanes_eq(n_abs);
+                let odd = integer
+                    & n_abs.lanes_lt(limit * Self::splat(2.0))
+                    & ((half + limit) - limit).lanes_ne(half);
+                let result = (odd & self.is_sign_negative()).select(-result, result);
+                let undefined = self.lanes_lt(zero) & self.is_finite() & !integer;
+                let result = undefined.select(Self::splat(<$type>::NAN), result);
+
+                // x^0 and 1^n are 1, even when the other operand is NaN.
+                let result = (self.is_nan() | n.is_nan()).select(self + n, result);
+                (n.lanes_eq(zero) | self.lanes_eq(one)).select(one, result)
+            }
+
+            /// Returns the base 2 logarithm of each lane.
+            ///
+            #[doc = concat!("The result is within ", stringify!($max_ulps), " ULP of the equivalent scalar method.")]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn log2(self) -> Self {
+                self.log_with(|exponent, hi, lo| {
+                    let hi_result = hi * Self::splat($log2_e_hi);
+                    let lo_result = (lo + hi) * Self::splat($log2_e_lo) + lo * Self::splat($log2_e_hi);
+                    let sum = exponent + hi_result;
+                    let lo_result = lo_result + ((exponent - sum) + hi_result);
+                    lo_result + sum
+                })
+            }
+
+            /// Returns the base 10 logarithm of each lane.
+            ///
+            #[doc = concat!("The result is within ", stringify!($max_ulps), " ULP of the equivalent scalar method.")]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn log10(self) -> Self {
+                self.log_with(|exponent, hi, lo| {
+                    let exponent_hi = exponent * Self::splat($log10_2_hi);
+                    let hi_result = hi * Self::splat($log10_e_hi);
+                    let lo_result = exponent * Self::splat($log10_2_lo)
+                        + (lo + hi) * Self::splat($log10_e_lo)
+                        + lo * Self::splat($log10_e_hi);
+                    let sum = exponent_hi + hi_result;
+                    let lo_result = lo_result + ((exponent_hi - sum) + hi_result);
+                    lo_result + sum
+                })
+            }
+        }
+    }
+}
+
+impl_exp_log! {
+    f32,
+    max_ulps: 2,
+    exp_range: (-104.0, 89.0),
+    ln_2: (6.933_594e-1, -2.121_944_4e-4),
+    log2_e: (1.442_871_1, -1.760_528_5e-4),
+    log10_2: (3.010_292e-1, 7.903_415e-7),
+    log10_e: (4.343_261_7e-1, -3.168_997e-5),
+    two_thirds_lo: -1.986_821_5e-8,
+    hi_mask: 0xffff_f000,
+    exp_coefficients: [
+        1.0,
+        1.0,
+        1.0 / 2.0,
+        1.0 / 6.0,
+        1.0 / 24.0,
+        1.0 / 120.0,
+        1.0 / 720.0,
+        1.0 / 5040.0,
+    ],
+    log_coefficients: [
+        2.0 / 3.0,
+        2.0 / 5.0,
+        2.0 / 7.0,
+        2.0 / 9.0,
+        2.0 / 11.0,
+        2.0 / 13.0,
+    ],
+}
+
+impl_exp_log! {
+    f64,
+    max_ulps: 2,
+    exp_range: (-746.0, 710.0),
+    ln_2: (6.931_471_803_691_238e-1, 1.908_214_929_270_587_7e-10),
+    log2_e: (1.442_695_040_721_446_3, 1.675_171_316_488_651_2e-10),
+    log10_2: (3.010_299_956_636_117_7e-1, 3.694_239_077_158_931e-13),
+    log10_e: (4.342_944_818_781_689e-1, 2.508_294_671_164_527_5e-11),
+    two_thirds_lo: 3.700_743_415_417_188e-17,
+    hi_mask: 0xffff_ffff_0000_0000,
+    exp_coefficients: [
+        1.0,
+        1.0,
+        1.0 / 2.0,
+        1.0 / 6.0,
+        1.0 / 24.0,
+        1.0 / 120.0,
+        1.0 / 720.0,
+        1.0 / 5040.0,
+        1.0 / 40320.0,
+        1.0 / 362880.0,
+        1.0 / 3628800.0,
+        1.0 / 39916800.0,
+        1.0 / 479001600.0,
+        1.0 / 6227020800.0,
+    ],
+    log_coefficients: [
+        2.0 / 3.0,
+        2.0 / 5.0,
+        2.0 / 7.0,
+        2.0 / 9.0,
+        2.0 / 11.0,
+        2.0 / 13.0,
+        2.0 / 15.0,
+        2.0 / 17.0,
+        2.0 / 19.0,
+        2.0 / 21.0,
+        2.0 / 23.0,
+        2.0 / 25.0,
+    ],
+}
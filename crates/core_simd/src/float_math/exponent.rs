@@ -0,0 +1,104 @@
+use crate::{LaneCount, Simd, SupportedLaneCount};
+
+/// Implements exponent manipulation for a vector of float `$type`, which uses `$bits_ty` as its
+/// binary representation and `$int_ty`, the integer of the same width, for exponent arithmetic.
+macro_rules! impl_exponent {
+    { $type:ident, $bits_ty:ty, $int_ty:ty, mantissa_bits: $mantissa_bits:literal, exponent_bias: $exponent_bias:literal } => {
+        impl<const LANES: usize> Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            /// Returns `2^n` for each lane of `n`, which must be in the normal exponent range.
+            #[inline]
+            fn exp2_normal(n: Simd<$int_ty, LANES>) -> Self {
+                let biased: Simd<$bits_ty, LANES> =
+                    unsafe { crate::intrinsics::simd_cast(n + Simd::splat($exponent_bias)) };
+                Self::from_bits(biased << $mantissa_bits)
+            }
+
+            /// Multiplies each lane by 2 raised to the power of the corresponding lane of `exp`.
+            ///
+            /// The result is rounded once, so it is exact unless it overflows to infinity or
+            /// underflows into the subnormal range.  Zero, infinite, and `NaN` lanes are
+            /// unchanged.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = Simd::<", stringify!($type), ", 4>::from_array([1.5, -3.0, 1.0, 1.0]);")]
+            #[doc = concat!("let exp = Simd::from_array([2, -1, ", stringify!($exponent_bias), " + 1, i32::MIN]);")]
+            #[doc = concat!("assert_eq!(x.ldexp(exp).to_array(), [6.0, -1.5, ", stringify!($type), "::INFINITY, 0.0]);")]
+            /// ```
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn ldexp(self, exp: Simd<i32, LANES>) -> Self {
+                const MAX: $int_ty = $exponent_bias;
+                const MIN: $int_ty = 1 - $exponent_bias;
+                // Scaling down by `2^DOWN` leaves a margin of `2^($mantissa_bits + 1)` above the
+                // subnormal range, so a lane is only rounded at this step if the final result
+                // rounds to zero anyway.
+                const DOWN: $int_ty = MIN + $mantissa_bits + 1;
+
+                let mut n: Simd<$int_ty, LANES> = unsafe { crate::intrinsics::simd_cast(exp) };
+                let mut y = self;
+                for _ in 0..2 {
+                    let large = n.lanes_gt(Simd::splat(MAX));
+                    y *= large.select(Self::exp2_normal(Simd::splat(MAX)), Self::splat(1.0));
+                    n = large.select(n.wrapping_sub(Simd::splat(MAX)), n);
+                }
+                for _ in 0..2 {
+                    let small = n.lanes_lt(Simd::splat(MIN));
+                    y *= small.select(Self::exp2_normal(Simd::splat(DOWN)), Self::splat(1.0));
+                    n = small.select(n.wrapping_sub(Simd::splat(DOWN)), n);
+                }
+                let n = n.lanes_gt(Simd::splat(MAX)).select(Simd::splat(MAX), n);
+                let n = n.lanes_lt(Simd::splat(MIN)).select(Simd::splat(MIN), n);
+                y * Self::exp2_normal(n)
+            }
+
+            /// Splits each lane into a significand and an exponent, such that
+            /// `self = significand * 2^exponent`.
+            ///
+            /// The magnitude of the significand is in `[0.5, 1)`, and it has the sign of `self`.
+            /// Zero, infinite, and `NaN` lanes are returned as the significand, with an exponent
+            /// of zero.  This is the inverse of [`ldexp`](Self::ldexp).
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = Simd::<", stringify!($type), ", 4>::from_array([6.0, -0.75, 0.0, ", stringify!($type), "::INFINITY]);")]
+            /// let (significand, exponent) = x.frexp();
+            #[doc = concat!("assert_eq!(significand.to_array(), [0.75, -0.75, 0.0, ", stringify!($type), "::INFINITY]);")]
+            /// assert_eq!(exponent.to_array(), [3, 0, 0, 0]);
+            /// ```
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn frexp(self) -> (Self, Simd<i32, LANES>) {
+                const SUBNORMAL_SCALE: $int_ty = $mantissa_bits + 2;
+                let exponent_mask: $bits_ty = ((1 << $mantissa_bits) - 1) ^ (<$bits_ty>::MAX >> 1);
+
+                // Scale subnormals into the normal range.
+                let subnormal = self.is_subnormal();
+                let x = subnormal.select(self * Self::exp2_normal(Simd::splat(SUBNORMAL_SCALE)), self);
+                let bits = x.to_bits();
+
+                let field: Simd<$int_ty, LANES> =
+                    unsafe { crate::intrinsics::simd_cast((bits & Simd::splat(exponent_mask)) >> $mantissa_bits) };
+                let exponent = field
+                    - Simd::splat($exponent_bias - 1)
+                    - subnormal.select(Simd::splat(SUBNORMAL_SCALE), Simd::splat(0));
+                let significand = Self::from_bits(
+                    (bits & !Simd::splat(exponent_mask)) | Simd::splat(($exponent_bias - 1) << $mantissa_bits),
+                );
+
+                let special = self.lanes_eq(Self::splat(0.0)) | !self.is_finite();
+                let exponent = special.select(Simd::splat(0), exponent);
+                (
+                    special.select(self, significand),
+                    unsafe { crate::intrinsics::simd_cast(exponent) },
+                )
+            }
+        }
+    }
+}
+
+impl_exponent! { f32, u32, i32, mantissa_bits: 23, exponent_bias: 127 }
+impl_exponent! { f64, u64, i64, mantissa_bits: 52, exponent_bias: 1023 }
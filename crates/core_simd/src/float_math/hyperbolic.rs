@@ -0,0 +1,130 @@
+use crate::{LaneCount, Simd, SupportedLaneCount};
+
+/// Implements hyperbolic functions for a vector of float `$type`.
+///
+/// `$exp_limit` is the largest lane for which `exp` doesn't overflow.
+///
+/// `$sinh_coefficients` approximate `(sinh(x) - x) / x^3` as a polynomial in `x^2` for
+/// `|x| <= 1`, and `$tanh_coefficients` approximate `(tanh(x) - x) / x^3` as a rational function
+/// of `x^2` for `|x| <= 0.625`, given as the coefficients of the numerator and denominator.  All
+/// coefficients are lowest degree first.
+macro_rules! impl_hyperbolic {
+    {
+        $type:ident,
+        max_ulps: (sinh: $sinh_ulps:literal, cosh: $cosh_ulps:literal, tanh: $tanh_ulps:literal),
+        exp_limit: $exp_limit:literal,
+        sinh_coefficients: $sinh_coefficients:expr,
+        tanh_coefficients: ($tanh_numerator:expr, $tanh_denominator:expr),
+    } => {
+        impl<const LANES: usize> Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            /// Computes `e^x / 2` for each lane, which must not be negative, along with `e^-x / 2`.
+            #[inline]
+            fn half_exp_positive(self) -> (Self, Self) {
+                // Past the overflow limit, square e^(x/2) rather than computing e^x directly.
+                let large = self.lanes_gt(Self::splat($exp_limit));
+                let half = Self::splat(0.5);
+                let e = large.select(self * half, self).exp();
+                let half_e = half * e;
+                (large.select(half_e * e, half_e), half / e)
+            }
+
+            /// Computes the hyperbolic sine of each lane.
+            ///
+            #[doc = concat!("The result is within ", stringify!($sinh_ulps), " ULP of the equivalent scalar method.")]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn sinh(self) -> Self {
+                let x = self.abs();
+                let small = x.lanes_lt(Self::splat(1.0));
+                let (half_e, half_e_recip) = x.half_exp_positive();
+                let result = small.select(
+                    x.odd_rational(&$sinh_coefficients, &[1.0]),
+                    half_e - half_e_recip,
+                );
+                self.is_nan().select(self, result.with_sign_of(self))
+            }
+
+            /// Computes the hyperbolic cosine of each lane.
+            ///
+            #[doc = concat!("The result is within ", stringify!($cosh_ulps), " ULP of the equivalent scalar method.")]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn cosh(self) -> Self {
+                let (half_e, half_e_recip) = self.abs().half_exp_positive();
+                half_e + half_e_recip
+            }
+
+            /// Computes the hyperbolic tangent of each lane.
+            ///
+            #[doc = concat!("The result is within ", stringify!($tanh_ulps), " ULP of the equivalent scalar method.")]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn tanh(self) -> Self {
+                // tanh(x) = 1 - 2 / (e^2x + 1), which saturates to 1 when e^2x overflows.
+                let x = self.abs();
+                let small = x.lanes_lt(Self::splat(0.625));
+                let one = Self::splat(1.0);
+                let large = one - Self::splat(2.0) / ((x + x).exp() + one);
+                small
+                    .select(x.odd_rational(&$tanh_numerator, &$tanh_denominator), large)
+                    .with_sign_of(self)
+            }
+        }
+    }
+}
+
+impl_hyperbolic! {
+    f32,
+    max_ulps: (sinh: 3, cosh: 3, tanh: 2),
+    exp_limit: 88.72283,
+    sinh_coefficients: [
+        1.0 / 6.0,
+        1.0 / 120.0,
+        1.0 / 5040.0,
+        1.0 / 362880.0,
+        1.0 / 39916800.0,
+    ],
+    tanh_coefficients: (
+        [
+            -3.333_328e-1,
+            1.333_144_2e-1,
+            -5.373_971_6e-2,
+            2.063_909e-2,
+            -5.704_988_7e-3,
+        ],
+        [1.0]
+    ),
+}
+
+impl_hyperbolic! {
+    f64,
+    max_ulps: (sinh: 3, cosh: 3, tanh: 2),
+    exp_limit: 709.782_712_893_384,
+    sinh_coefficients: [
+        1.0 / 6.0,
+        1.0 / 120.0,
+        1.0 / 5040.0,
+        1.0 / 362880.0,
+        1.0 / 39916800.0,
+        1.0 / 6227020800.0,
+        1.0 / 1307674368000.0,
+        1.0 / 355687428096000.0,
+        1.0 / 121645100408832000.0,
+    ],
+    tanh_coefficients: (
+        [
+            -1.614_687_684_417_084_5e3,
+            -9.928_772_310_019_186e1,
+            -9.643_991_794_250_523e-1,
+        ],
+        [
+            4.844_063_053_251_255e3,
+            2.235_488_390_601_004_6e3,
+            1.128_116_784_916_329_3e2,
+            1.0,
+        ]
+    ),
+}
@@ -0,0 +1,201 @@
+use crate::{LaneCount, Simd, SupportedLaneCount};
+
+/// Implements inverse trigonometric functions for a vector of float `$type`.
+///
+/// Constants given as pairs are split into the nearest float and the remainder.
+///
+/// `$atan_coefficients` approximate `(atan(t) - t) / t^3` as a rational function of `t^2`, for
+/// `|t| <= $atan_threshold`, and `$asin_coefficients` approximate `(asin(s) - s) / s^3` as a
+/// rational function of `s^2`, for `|s| <= 1/2`.  Each is given as the coefficients of the
+/// numerator and denominator, lowest degree first.
+macro_rules! impl_inverse_trig {
+    {
+        $type:ident,
+        max_ulps: (asin: $asin_ulps:literal, acos: $acos_ulps:literal, atan: $atan_ulps:literal, atan2: $atan2_ulps:literal),
+        pi: ($pi_hi:expr, $pi_lo:literal),
+        atan_threshold: $atan_threshold:literal,
+        atan_coefficients: ($atan_numerator:expr, $atan_denominator:expr),
+        asin_coefficients: ($asin_numerator:expr, $asin_denominator:expr),
+    } => {
+        impl<const LANES: usize> Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            /// Computes the arctangent of each lane, which must not be negative.
+            #[inline]
+            fn atan_positive(self) -> Self {
+                // atan(x) = pi/2 + atan(-1/x)
+                //         = pi/4 + atan((x - 1) / (x + 1))
+                let large = self.lanes_gt(Self::splat(core::$type::consts::SQRT_2 + 1.0));
+                let medium = !large & self.lanes_gt(Self::splat($atan_threshold));
+                let one = Self::splat(1.0);
+                let t = large.select(-one / self, medium.select((self - one) / (self + one), self));
+                let zero = Self::splat(0.0);
+                let base_hi = large.select(
+                    Self::splat($pi_hi * 0.5),
+                    medium.select(Self::splat($pi_hi * 0.25), zero),
+                );
+                let base_lo = large.select(
+                    Self::splat($pi_lo * 0.5),
+                    medium.select(Self::splat($pi_lo * 0.25), zero),
+                );
+                base_hi + (t.odd_rational(&$atan_numerator, &$atan_denominator) + base_lo)
+            }
+
+            /// Computes the arcsine of each lane, in radians in the range `[-pi/2, pi/2]`.
+            ///
+            /// Lanes outside `[-1, 1]` produce `NaN`.
+            #[doc = concat!("The result is within ", stringify!($asin_ulps), " ULP of the equivalent scalar method.")]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn asin(self) -> Self {
+                // asin(x) = pi/2 - 2 * asin(sqrt((1 - x) / 2))
+                let x = self.abs();
+                let large = x.lanes_gt(Self::splat(0.5));
+                let z = Self::splat(0.5) * (Self::splat(1.0) - x);
+                let s = large.select(z.sqrt_newton(), x);
+                let s = z.lanes_eq(Self::splat(0.0)).select(z, s);
+                let k = s.odd_rational(&$asin_numerator, &$asin_denominator);
+                let result = large.select(
+                    Self::splat($pi_hi * 0.5) - (Self::splat(2.0) * k - Self::splat($pi_lo * 0.5)),
+                    k,
+                );
+                let result = x.lanes_gt(Self::splat(1.0)).select(Self::splat(<$type>::NAN), result);
+                result.with_sign_of(self)
+            }
+
+            /// Computes the arccosine of each lane, in radians in the range `[0, pi]`.
+            ///
+            /// Lanes outside `[-1, 1]` produce `NaN`.
+            #[doc = concat!("The result is within ", stringify!($acos_ulps), " ULP of the equivalent scalar method.")]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn acos(self) -> Self {
+                // acos(x) = pi/2 - asin(x)
+                //         = 2 * asin(sqrt((1 - x) / 2))
+                //         = pi - 2 * asin(sqrt((1 + x) / 2))
+                let x = self.abs();
+                let large = x.lanes_gt(Self::splat(0.5));
+                let z = Self::splat(0.5) * (Self::splat(1.0) - x);
+                let s = large.select(z.sqrt_newton(), self);
+                let s = z.lanes_eq(Self::splat(0.0)).select(z, s);
+                let k = s.odd_rational(&$asin_numerator, &$asin_denominator);
+                let k_2 = Self::splat(2.0) * k;
+                let result = large.select(
+                    self.is_sign_negative().select(
+                        Self::splat($pi_hi) - (k_2 - Self::splat($pi_lo)),
+                        k_2,
+                    ),
+                    Self::splat($pi_hi * 0.5) - (k - Self::splat($pi_lo * 0.5)),
+                );
+                x.lanes_gt(Self::splat(1.0)).select(Self::splat(<$type>::NAN), result)
+            }
+
+            /// Computes the arctangent of each lane, in radians in the range `[-pi/2, pi/2]`.
+            #[doc = concat!("The result is within ", stringify!($atan_ulps), " ULP of the equivalent scalar method.")]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn atan(self) -> Self {
+                self.abs().atan_positive().with_sign_of(self)
+            }
+
+            /// Computes the four quadrant arctangent of `self` (`y`) and `other` (`x`) in each
+            /// lane, in radians in the range `[-pi, pi]`.
+            ///
+            /// Signed zeros and infinities are handled the same way as the equivalent scalar
+            /// method.
+            #[doc = concat!("The result is within ", stringify!($atan2_ulps), " ULP of the equivalent scalar method.")]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn atan2(self, other: Self) -> Self {
+                let y = self.abs();
+                let x = other.abs();
+
+                // The ratio is undefined when both are zero or both are infinite.
+                let zero = Self::splat(0.0);
+                let infinity = Self::splat(<$type>::INFINITY);
+                let result = (y / x).atan_positive();
+                let result = (y.lanes_eq(zero) & x.lanes_eq(zero)).select(zero, result);
+                let result = (y.lanes_eq(infinity) & x.lanes_eq(infinity))
+                    .select(Self::splat($pi_hi * 0.25), result);
+
+                // Reflect into the left half-plane.  A result of `pi/2` is its own reflection,
+                // which is kept exact.
+                let reflect = other.is_sign_negative() & result.lanes_ne(Self::splat($pi_hi * 0.5));
+                let result = reflect.select(
+                    Self::splat($pi_hi) - (result - Self::splat($pi_lo)),
+                    result,
+                );
+                let result = (self.is_nan() | other.is_nan()).select(self + other, result);
+                result.with_sign_of(self)
+            }
+        }
+    }
+}
+
+impl_inverse_trig! {
+    f32,
+    max_ulps: (asin: 3, acos: 2, atan: 2, atan2: 2),
+    pi: (core::f32::consts::PI, -8.742_278e-8),
+    atan_threshold: 0.414_213_57,
+    atan_coefficients: (
+        [
+            -3.333_295e-1,
+            1.997_771_1e-1,
+            -1.387_768_6e-1,
+            8.053_744_5e-2,
+        ],
+        [1.0]
+    ),
+    asin_coefficients: (
+        [
+            1.666_675_2e-1,
+            7.495_300_3e-2,
+            4.547_002_6e-2,
+            2.418_131e-2,
+            4.216_32e-2,
+        ],
+        [1.0]
+    ),
+}
+
+impl_inverse_trig! {
+    f64,
+    max_ulps: (asin: 2, acos: 2, atan: 2, atan2: 2),
+    pi: (core::f64::consts::PI, 1.224_646_799_147_353_2e-16),
+    atan_threshold: 0.66,
+    atan_coefficients: (
+        [
+            -6.485_021_904_942_025e1 / 1.945_506_571_482_614e2,
+            -1.228_866_684_490_136_2e2 / 1.945_506_571_482_614e2,
+            -7.500_855_792_314_705e1 / 1.945_506_571_482_614e2,
+            -1.615_753_718_733_365e1 / 1.945_506_571_482_614e2,
+            -8.750_608_600_031_904e-1 / 1.945_506_571_482_614e2,
+        ],
+        [
+            1.0,
+            4.853_903_996_359_137e2 / 1.945_506_571_482_614e2,
+            4.328_810_604_912_903e2 / 1.945_506_571_482_614e2,
+            1.650_270_098_316_988e2 / 1.945_506_571_482_614e2,
+            2.485_846_490_142_306e1 / 1.945_506_571_482_614e2,
+            1.0 / 1.945_506_571_482_614e2,
+        ]
+    ),
+    asin_coefficients: (
+        [
+            1.666_666_666_666_666_6e-1,
+            -3.255_658_186_224_009e-1,
+            2.012_125_321_348_629_3e-1,
+            -4.005_553_450_067_941e-2,
+            7.915_349_942_898_145e-4,
+            3.479_331_075_960_212e-5,
+        ],
+        [
+            1.0,
+            -2.403_394_911_734_414,
+            2.020_945_760_233_505_7,
+            -6.882_839_716_054_533e-1,
+            7.703_815_055_590_194e-2,
+        ]
+    ),
+}
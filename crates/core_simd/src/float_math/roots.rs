@@ -0,0 +1,200 @@
+use crate::{LaneCount, Simd, SupportedLaneCount};
+
+/// Implements root functions for a vector of float `$type`.
+///
+/// The cube root is estimated by dividing the binary representation by three and adding
+/// `$cbrt_magic`, which corrects the exponent bias, and then refined with Newton's method.
+/// Subnormal lanes are first scaled up by `2^(3 * $cbrt_scale)`.
+///
+/// Lanes larger than `2^$scale_threshold` are scaled down by `2^-$scale`, and lanes smaller than
+/// `2^-$scale_threshold` are scaled up by `2^$scale`, so that their squares are normal.
+macro_rules! impl_roots {
+    {
+        $type:ident,
+        $bits_ty:ty,
+        max_ulps: (hypot: $hypot_ulps:literal, cbrt: $cbrt_ulps:literal),
+        scale_threshold: $scale_threshold:literal,
+        scale: $scale:literal,
+        cbrt_magic: $cbrt_magic:literal,
+        cbrt_scale: $cbrt_scale:literal,
+        cbrt_iterations: $cbrt_iterations:literal,
+    } => {
+        impl<const LANES: usize> Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            /// Computes the cube root of each lane.
+            ///
+            #[doc = concat!("The result is within ", stringify!($cbrt_ulps), " ULP of the equivalent scalar method.")]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn cbrt(self) -> Self {
+                let x = self.abs();
+                let subnormal = x.lanes_lt(Self::splat(<$type>::MIN_POSITIVE));
+                let x = subnormal.select(x * Self::splat((3 * $cbrt_scale) as $type).exp2_int(), x);
+
+                let mut y = Self::from_bits(x.to_bits() / Simd::splat(3) + Simd::splat($cbrt_magic));
+                for _ in 0..$cbrt_iterations {
+                    y = y + (x / (y * y) - y) * Self::splat(1.0 / 3.0);
+                }
+
+                let y = subnormal.select(y * Self::splat(-$cbrt_scale as $type).exp2_int(), y);
+                let special = self.lanes_eq(Self::splat(0.0)) | !self.is_finite();
+                special.select(self, y.with_sign_of(self))
+            }
+
+            /// Computes the length of the hypotenuse of a right triangle with legs `self` and
+            /// `other` in each lane, without overflowing or underflowing in intermediate results.
+            ///
+            #[doc = concat!("The result is within ", stringify!($hypot_ulps), " ULP of the equivalent scalar method.")]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn hypot(self, other: Self) -> Self {
+                let x = self.abs();
+                let y = other.abs();
+                let max = x.lanes_ge(y).select(x, y);
+
+                let large = max.lanes_gt(Self::splat($scale_threshold as $type).exp2_int());
+                let small = max.lanes_lt(Self::splat(-$scale_threshold as $type).exp2_int());
+                let scale = large.select(
+                    Self::splat(-$scale as $type).exp2_int(),
+                    small.select(Self::splat($scale as $type).exp2_int(), Self::splat(1.0)),
+                );
+                let x = x * scale;
+                let y = y * scale;
+                let sum = x * x + y * y;
+                let zero = Self::splat(0.0);
+                let result = sum.lanes_eq(zero).select(zero, sum.sqrt_newton()) / scale;
+
+                // An infinite lane takes precedence over a NaN.
+                let infinity = Self::splat(<$type>::INFINITY);
+                let result = (self.is_nan() | other.is_nan()).select(self + other, result);
+                (x.lanes_eq(infinity) | y.lanes_eq(infinity)).select(infinity, result)
+            }
+        }
+    }
+}
+
+impl_roots! {
+    f32,
+    u32,
+    max_ulps: (hypot: 2, cbrt: 1),
+    scale_threshold: 60,
+    scale: 90,
+    cbrt_magic: 709_958_130,
+    cbrt_scale: 8,
+    cbrt_iterations: 4,
+}
+
+impl_roots! {
+    f64,
+    u64,
+    max_ulps: (hypot: 2, cbrt: 1),
+    scale_threshold: 500,
+    scale: 600,
+    cbrt_magic: 0x2a9f_7893_0000_0000,
+    cbrt_scale: 18,
+    cbrt_iterations: 5,
+}
+
+/// Implements reciprocal square root estimates for a vector of float `$type`.
+///
+/// The portable estimate subtracts half the binary representation from `$rsqrt_magic`, which
+/// approximates `x^(-1/2)` within 3.5%, and takes one step of Newton's method.
+macro_rules! impl_rsqrt {
+    { $type:ident, $bits_ty:ty, rsqrt_magic: $rsqrt_magic:literal } => {
+        impl<const LANES: usize> Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            /// Takes one step of Newton's method toward `self^(-1/2)` from the estimate `y`.
+            #[inline]
+            fn rsqrt_step(self, y: Self) -> Self {
+                y * (Self::splat(1.5) - Self::splat(0.5) * self * y * y)
+            }
+
+            /// Estimates `self^(-1/2)` without target-specific instructions.
+            #[inline]
+            #[allow(dead_code)]
+            fn rsqrt_portable(self) -> Self {
+                let y = Self::from_bits(Simd::<$bits_ty, LANES>::splat($rsqrt_magic).wrapping_sub(self.to_bits() >> 1));
+                self.rsqrt_step(y)
+            }
+
+            /// Estimates the reciprocal of the square root of each lane.
+            ///
+            /// The relative error is less than `2^-9`, about 0.2%, for lanes that are positive
+            /// and normal.  The result for other lanes is unspecified.  On x86 this uses the
+            /// `rsqrtps` instruction for `f32` vectors of at least 4 lanes, which is
+            /// more accurate; otherwise it uses an integer estimate and one step of Newton's
+            /// method.  The result may differ between targets.
+            ///
+            /// See [`rsqrt_refined`](Self::rsqrt_refined) for a more accurate result.
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn rsqrt_approx(self) -> Self {
+                self.rsqrt_estimate()
+            }
+
+            /// Estimates the reciprocal of the square root of each lane, refined with one step of
+            /// Newton's method.
+            ///
+            /// The relative error is less than `2^-17` for lanes that are positive and normal.  The
+            /// result for other lanes is unspecified.  This is still much less accurate than
+            /// dividing by the square root, but is faster on most targets.
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn rsqrt_refined(self) -> Self {
+                self.rsqrt_step(self.rsqrt_estimate())
+            }
+        }
+    }
+}
+
+impl_rsqrt! { f32, u32, rsqrt_magic: 0x5f37_59df }
+impl_rsqrt! { f64, u64, rsqrt_magic: 0x5fe6_eb50_c7b5_37a9 }
+
+impl<const LANES: usize> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Estimates `self^(-1/2)`, using `rsqrtps` where it is available.
+    #[inline]
+    fn rsqrt_estimate(self) -> Self {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse"
+        ))]
+        // Lane counts are powers of two, so these vectors are whole `__m128`s.
+        if LANES >= 4 {
+            #[cfg(target_arch = "x86")]
+            use core::arch::x86::{__m128, _mm_rsqrt_ps};
+            #[cfg(target_arch = "x86_64")]
+            use core::arch::x86_64::{__m128, _mm_rsqrt_ps};
+            let lanes = self.to_array();
+            let mut result = [0.; LANES];
+            for (result, lanes) in result.chunks_exact_mut(4).zip(lanes.chunks_exact(4)) {
+                let mut chunk = [0.; 4];
+                chunk.copy_from_slice(lanes);
+                // Safety: the target feature is enabled.
+                let chunk =
+                    unsafe { _mm_rsqrt_ps(__m128::from(Simd::<f32, 4>::from_array(chunk))) };
+                result.copy_from_slice(&Simd::<f32, 4>::from(chunk).to_array());
+            }
+            return Self::from_array(result);
+        }
+
+        self.rsqrt_portable()
+    }
+}
+
+impl<const LANES: usize> Simd<f64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Estimates `self^(-1/2)`.
+    #[inline]
+    fn rsqrt_estimate(self) -> Self {
+        self.rsqrt_portable()
+    }
+}
@@ -0,0 +1,284 @@
+use crate::{LaneCount, Simd, SupportedLaneCount};
+
+/// Bits of `2/pi`, most significant first, used for Payne-Hanek range reduction.
+const TWO_OVER_PI: [u64; 20] = [
+    0xa2f9836e4e441529,
+    0xfc2757d1f534ddc0,
+    0xdb6295993c439041,
+    0xfe5163abdebbc561,
+    0xb7246e3a424dd2e0,
+    0x06492eea09d1921c,
+    0xfe1deb1cb129a73e,
+    0xe88235f52ebb4484,
+    0xe99c7026b45f7e41,
+    0x3991d639835339f4,
+    0x9c845f8bbdf9283b,
+    0x1ff897ffde05980f,
+    0xef2f118b5a0a6d1f,
+    0x6d367ecf27cb09b7,
+    0x4f463f669e5fea2d,
+    0x7527bac7ebe5f17b,
+    0x3d0739f78a5292ea,
+    0x6bfb5fb11f8d5d08,
+    0x56033046fc7b6bab,
+    0xf0cfbc209af4361d,
+];
+
+/// Returns the 64 bits of `2/pi` starting with the bit weighted `2^-start`.
+fn two_over_pi_bits(start: i32) -> u64 {
+    if start < 1 {
+        // The integer bits of 2/pi are zero.
+        let shift = 1 - start;
+        return if shift < 64 {
+            two_over_pi_bits(1) >> shift
+        } else {
+            0
+        };
+    }
+    let index = (start - 1) as usize;
+    let (word, shift) = (index / 64, index % 64);
+    let lo = if shift == 0 {
+        0
+    } else {
+        TWO_OVER_PI[word + 1] >> (64 - shift)
+    };
+    (TWO_OVER_PI[word] << shift) | lo
+}
+
+/// Returns `2^exponent`, for an exponent in the normal range.
+fn exp2_int(exponent: i32) -> f64 {
+    f64::from_bits(((1023 + exponent) as u64) << 52)
+}
+
+/// Reduces a large, finite `x` to `r` in `[-pi/4, pi/4]` and the quadrant `q` such that
+/// `x = r + q * pi/2 (mod 2pi)`, using Payne-Hanek reduction.
+fn rem_pi_2_large(x: f64) -> (f64, u32) {
+    const PI_2_HI: f64 = core::f64::consts::FRAC_PI_2;
+    const PI_2_LO: f64 = 6.123_233_995_736_766e-17;
+
+    // x = m * 2^e
+    let bits = x.to_bits();
+    let m = (bits & ((1 << 52) - 1)) | (1 << 52);
+    let e = ((bits >> 52) & 0x7ff) as i32 - 1075;
+
+    // Bits of 2/pi weighted 2^(2 - e) or more contribute multiples of 4 to x * 2/pi, so they
+    // don't affect the quadrant.  Keep the next 192 bits, so that the product modulo 2^192
+    // holds x * 2/pi modulo 4, with the binary point after the top two bits.
+    let t = [
+        two_over_pi_bits(e - 1),
+        two_over_pi_bits(e + 63),
+        two_over_pi_bits(e + 127),
+    ];
+    let p2 = m as u128 * t[2] as u128;
+    let p1 = m as u128 * t[1] as u128 + (p2 >> 64);
+    let p0 = m as u128 * t[0] as u128 + (p1 >> 64);
+    let mut product = [p0 as u64 & (u64::MAX >> 2), p1 as u64, p2 as u64];
+    let mut quadrant = (p0 as u64 >> 62) as u32;
+
+    // Round to the nearest quadrant, negating the fraction if it rounded up.
+    let round_up = product[0] >> 61 != 0;
+    if round_up {
+        quadrant += 1;
+        let (lo, carry) = (!product[2]).overflowing_add(1);
+        let (mid, carry) = (!product[1]).overflowing_add(carry as u64);
+        let hi = (!product[0]).wrapping_add(carry as u64) & (u64::MAX >> 2);
+        product = [hi, mid, lo];
+    }
+
+    // Normalize the fraction, which is the product times 2^-190, and take its top 128 bits.
+    let leading_zeros = if product[0] != 0 {
+        product[0].leading_zeros()
+    } else if product[1] != 0 {
+        64 + product[1].leading_zeros()
+    } else {
+        128 + product[2].leading_zeros()
+    };
+    let word_at = |bit: u32| -> u64 {
+        let (word, shift) = ((bit / 64) as usize, bit % 64);
+        let hi = product.get(word).map_or(0, |w| w << shift);
+        let lo = match product.get(word + 1) {
+            Some(w) if shift != 0 => w >> (64 - shift),
+            _ => 0,
+        };
+        hi | lo
+    };
+    let fraction = (word_at(leading_zeros) as u128) << 64 | word_at(leading_zeros + 64) as u128;
+
+    // Split the fraction into two doubles and multiply by pi/2.
+    let scale = -126 - leading_zeros as i32;
+    let hi = (fraction >> 75) as f64 * exp2_int(scale + 75);
+    let lo = (fraction & ((1 << 75) - 1)) as f64 * exp2_int(scale);
+    let r = hi * PI_2_HI + (hi * PI_2_LO + lo * PI_2_HI);
+    let r = if round_up { -r } else { r };
+
+    if x.is_sign_negative() {
+        (-r, quadrant.wrapping_neg() & 3)
+    } else {
+        (r, quadrant & 3)
+    }
+}
+
+/// Implements trigonometric functions for a vector of float `$type`, which uses `$bits_ty` as its
+/// binary representation.
+///
+/// `$pi_2` splits `pi/2` into parts with few significant bits, except the last, so that
+/// multiplying by the quadrant is exact for lanes up to `$reduction_limit` in magnitude.  Larger
+/// lanes fall back to a slower, exact reduction.
+///
+/// `$sin_coefficients` approximate `(sin(r) - r) / r^3` and `$cos_coefficients` approximate
+/// `(cos(r) - 1) / r^2` as polynomials in `r^2`, for `|r| <= pi/4`.
+macro_rules! impl_trig {
+    {
+        $type:ident,
+        $bits_ty:ident,
+        max_ulps: (sin: $sin_ulps:literal, cos: $cos_ulps:literal, tan: $tan_ulps:literal),
+        reduction_limit: $reduction_limit:literal,
+        pi_2: $pi_2:expr,
+        sin_coefficients: $sin_coefficients:expr,
+        cos_coefficients: $cos_coefficients:expr,
+    } => {
+        impl<const LANES: usize> Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            /// Reduces each lane to `r` in `[-pi/4, pi/4]` and the quadrant `q` such that
+            /// `self = r + q * pi/2 (mod 2pi)`.
+            #[inline]
+            fn reduce_pi_2(self) -> (Self, Simd<$bits_ty, LANES>) {
+                // Cody-Waite reduction
+                let n = (self * Self::splat(core::$type::consts::FRAC_2_PI)).round_small();
+                let mut r = self;
+                for part in $pi_2.iter() {
+                    r -= n * Self::splat(*part);
+                }
+                // Subtracting zero may lose the sign of a zero lane.
+                let mut r = n.lanes_eq(Self::splat(0.0)).select(self, r);
+                let magic = Self::splat(Self::ROUNDING_MAGIC);
+                let mut quadrant = (n + magic).to_bits() & Simd::splat(3);
+
+                let large = self.abs().lanes_gt(Self::splat($reduction_limit)) & self.is_finite();
+                if large.any() {
+                    for lane in 0..LANES {
+                        if large.test(lane) {
+                            let (lane_r, lane_quadrant) = rem_pi_2_large(self[lane] as f64);
+                            r[lane] = lane_r as $type;
+                            quadrant[lane] = lane_quadrant as $bits_ty;
+                        }
+                    }
+                }
+                (r, quadrant)
+            }
+
+            /// Computes the sine and cosine of each lane, for lanes in `[-pi/4, pi/4]`.
+            #[inline]
+            fn sin_cos_reduced(self) -> (Self, Self) {
+                let z = self * self;
+                let sin = self + self * z * z.polynomial(&$sin_coefficients);
+                // Adding the correction term to a zero lane may lose its sign.
+                let sin = self.lanes_eq(Self::splat(0.0)).select(self, sin);
+                let cos = Self::splat(1.0) + z * z.polynomial(&$cos_coefficients);
+                (sin, cos)
+            }
+
+            /// Selects the sine or cosine of the reduced argument, and its sign, by quadrant.
+            #[inline]
+            fn sin_by_quadrant(sin: Self, cos: Self, quadrant: Simd<$bits_ty, LANES>) -> Self {
+                let zero = Simd::splat(0);
+                let result = (quadrant & Simd::splat(1)).lanes_ne(zero).select(cos, sin);
+                (quadrant & Simd::splat(2)).lanes_ne(zero).select(-result, result)
+            }
+
+            /// Computes the sine of each lane, in radians.
+            #[doc = concat!("The result is within ", stringify!($sin_ulps), " ULP of the equivalent scalar method.")]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn sin(self) -> Self {
+                let (r, quadrant) = self.reduce_pi_2();
+                let (sin, cos) = r.sin_cos_reduced();
+                Self::sin_by_quadrant(sin, cos, quadrant)
+            }
+
+            /// Computes the cosine of each lane, in radians.
+            #[doc = concat!("The result is within ", stringify!($cos_ulps), " ULP of the equivalent scalar method.")]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn cos(self) -> Self {
+                // cos(x) = sin(x + pi/2)
+                let (r, quadrant) = self.reduce_pi_2();
+                let (sin, cos) = r.sin_cos_reduced();
+                Self::sin_by_quadrant(sin, cos, quadrant + Simd::splat(1))
+            }
+
+            /// Computes the tangent of each lane, in radians.
+            #[doc = concat!("The result is within ", stringify!($tan_ulps), " ULP of the equivalent scalar method.")]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn tan(self) -> Self {
+                // tan(r + pi/2) = -cos(r) / sin(r)
+                let (r, quadrant) = self.reduce_pi_2();
+                let (sin, cos) = r.sin_cos_reduced();
+                let odd = (quadrant & Simd::splat(1)).lanes_ne(Simd::splat(0));
+                odd.select(-cos / sin, sin / cos)
+            }
+        }
+    }
+}
+
+impl_trig! {
+    f32,
+    u32,
+    max_ulps: (sin: 2, cos: 2, tan: 3),
+    reduction_limit: 4096.0,
+    pi_2: [
+        1.570_800_8,
+        -4.453_584_6e-6,
+        -8.706_138e-10,
+        6.223_372e-14,
+    ],
+    sin_coefficients: [
+        -1.0 / 6.0,
+        1.0 / 120.0,
+        -1.0 / 5040.0,
+        1.0 / 362880.0,
+    ],
+    cos_coefficients: [
+        -1.0 / 2.0,
+        1.0 / 24.0,
+        -1.0 / 720.0,
+        1.0 / 40320.0,
+        -1.0 / 3628800.0,
+    ],
+}
+
+impl_trig! {
+    f64,
+    u64,
+    max_ulps: (sin: 2, cos: 2, tan: 4),
+    reduction_limit: 1048576.0,
+    pi_2: [
+        1.570_796_326_734_125_6,
+        6.077_100_506_303_966e-11,
+        2.022_266_248_795_950_6e-21,
+    ],
+    sin_coefficients: [
+        -1.0 / 6.0,
+        1.0 / 120.0,
+        -1.0 / 5040.0,
+        1.0 / 362880.0,
+        -1.0 / 39916800.0,
+        1.0 / 6227020800.0,
+        -1.0 / 1307674368000.0,
+        1.0 / 355687428096000.0,
+    ],
+    cos_coefficients: [
+        -1.0 / 2.0,
+        1.0 / 24.0,
+        -1.0 / 720.0,
+        1.0 / 40320.0,
+        -1.0 / 3628800.0,
+        1.0 / 479001600.0,
+        -1.0 / 87178291200.0,
+        1.0 / 20922789888000.0,
+        -1.0 / 6402373705728000.0,
+    ],
+}
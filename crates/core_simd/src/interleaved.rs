@@ -0,0 +1,208 @@
+//! Loading and storing vectors from memory holding several interleaved streams, such as the
+//! channels of RGB pixels or stereo audio samples, or with a stride between lanes.
+
+use crate::{LaneCount, Simd, SimdElement, SupportedLaneCount};
+
+/// A number of interleaved streams known at compile time.
+struct StreamCount<const N: usize>;
+
+impl<const N: usize> StreamCount<N> {
+    /// Fails to evaluate, and so to compile, if there are no streams.  Loading them would
+    /// otherwise need an element to fill the empty array with.
+    const NONZERO: usize = N - 1;
+}
+
+impl<T, const LANES: usize> Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Loads `N` interleaved streams from the start of `slice`, one vector per stream.
+    ///
+    /// Element `i` of stream `s` is `slice[i * N + s]`, so for a slice of RGB pixels and
+    /// `N = 3`, the result contains a vector of red, green, and blue values.  This reads
+    /// `N * LANES` elements.  For up to four streams, the elements are read as `N` contiguous
+    /// vectors and separated with shuffles, which targets with structure loads, such as `vld3`
+    /// on ARM, may combine into a single instruction.  More streams are gathered one element at
+    /// a time.
+    ///
+    /// # Panics
+    /// Panics if `slice` is shorter than `N * LANES` elements.
+    ///
+    /// ```
+    /// # use core_simd::Simd;
+    /// let rgb = [10, 20, 30, 11, 21, 31, 12, 22, 32, 13, 23, 33];
+    /// let [r, g, b] = Simd::<u8, 4>::load_interleaved::<3>(&rgb);
+    /// assert_eq!(r.to_array(), [10, 11, 12, 13]);
+    /// assert_eq!(g.to_array(), [20, 21, 22, 23]);
+    /// assert_eq!(b.to_array(), [30, 31, 32, 33]);
+    /// ```
+    ///
+    /// There must be at least one stream, so `N = 0` fails to compile:
+    /// ```compile_fail
+    /// # use core_simd::Simd;
+    /// let [] = Simd::<u8, 4>::load_interleaved::<0>(&[]);
+    /// ```
+    #[inline]
+    pub fn load_interleaved<const N: usize>(slice: &[T]) -> [Self; N] {
+        let _ = StreamCount::<N>::NONZERO;
+        assert!(
+            slice.len() >= N * LANES,
+            "slice must contain at least N * LANES elements"
+        );
+        if N <= 4 {
+            let mut vectors = [Self::splat(slice[0]); N];
+            for (vector, chunk) in vectors.iter_mut().zip(slice.chunks_exact(LANES)) {
+                vector.as_mut_array().copy_from_slice(chunk);
+            }
+            if let Some(streams) = crate::permute::deinterleave_streams(vectors) {
+                return streams;
+            }
+        }
+        let mut streams = [[slice[0]; LANES]; N];
+        for (s, stream) in streams.iter_mut().enumerate() {
+            for (i, lane) in stream.iter_mut().enumerate() {
+                *lane = slice[i * N + s];
+            }
+        }
+        streams.map(Self::from_array)
+    }
+
+    /// Stores `N` vectors to the start of `slice`, interleaving their lanes.
+    ///
+    /// This is the inverse of [`load_interleaved`](Self::load_interleaved): lane `i` of
+    /// `streams[s]` is written to `slice[i * N + s]`, and `N * LANES` elements are written.  As
+    /// with loading, up to four streams are interleaved with shuffles and written as contiguous
+    /// vectors.
+    ///
+    /// # Panics
+    /// Panics if `slice` is shorter than `N * LANES` elements.
+    ///
+    /// ```
+    /// # use core_simd::f32x2;
+    /// let left = f32x2::from_array([0.5, 0.25]);
+    /// let right = f32x2::from_array([-0.5, -0.25]);
+    /// let mut stereo = [0.; 4];
+    /// f32x2::store_interleaved([left, right], &mut stereo);
+    /// assert_eq!(stereo, [0.5, -0.5, 0.25, -0.25]);
+    /// ```
+    #[inline]
+    pub fn store_interleaved<const N: usize>(streams: [Self; N], slice: &mut [T]) {
+        assert!(
+            slice.len() >= N * LANES,
+            "slice must contain at least N * LANES elements"
+        );
+        if let Some(vectors) = crate::permute::interleave_streams(streams) {
+            for (vector, chunk) in vectors.iter().zip(slice.chunks_exact_mut(LANES)) {
+                chunk.copy_from_slice(vector.as_slice());
+            }
+            return;
+        }
+        for (s, stream) in streams.iter().enumerate() {
+            for (i, lane) in stream.to_array().iter().enumerate() {
+                slice[i * N + s] = *lane;
+            }
+        }
+    }
+
+    /// Returns true if `slice` contains the element for every lane when the lanes are `stride`
+    /// elements apart.
+    #[inline]
+    fn stride_in_bounds(len: usize, stride: usize) -> bool {
+        matches!((LANES - 1).checked_mul(stride), Some(last) if last < len)
+    }
+
+    /// Returns the index of each lane when the lanes are `stride` elements apart.
+    #[inline]
+    fn stride_indices(stride: usize) -> Simd<usize, LANES> {
+        let mut idxs = [0; LANES];
+        for (i, idx) in idxs.iter_mut().enumerate() {
+            *idx = i;
+        }
+        Simd::from_array(idxs).wrapping_mul(Simd::splat(stride))
+    }
+
+    /// Loads every `stride`-th element from the start of `slice`, so lane `i` is
+    /// `slice[i * stride]`.
+    ///
+    /// This walks a column of a row-major matrix whose rows are `stride` elements long.  A
+    /// stride of 1 is a contiguous load.  Strides of 2 to 4 load the first stream of
+    /// [`load_interleaved`](Self::load_interleaved) when `slice` holds `stride * LANES`
+    /// elements, and other strides are gathered with
+    /// [`gather_or`](Self::gather_or).
+    ///
+    /// # Panics
+    /// Panics if `slice` doesn't contain the element for the last lane, at
+    /// `(LANES - 1) * stride`.
+    ///
+    /// ```
+    /// # use core_simd::Simd;
+    /// let matrix = [0, 1, 2, 10, 11, 12, 20, 21, 22, 30, 31, 32];
+    /// let column = Simd::<i32, 4>::gather_stride(&matrix[1..], 3);
+    /// assert_eq!(column.to_array(), [1, 11, 21, 31]);
+    /// ```
+    #[inline]
+    pub fn gather_stride(slice: &[T], stride: usize) -> Self {
+        assert!(
+            Self::stride_in_bounds(slice.len(), stride),
+            "slice must contain the element for every lane"
+        );
+        let whole = slice.len() >= stride * LANES;
+        match stride {
+            1 => {
+                let mut v = Self::splat(slice[0]);
+                v.as_mut_array().copy_from_slice(&slice[..LANES]);
+                v
+            }
+            2 if whole => Self::load_interleaved::<2>(slice)[0],
+            3 if whole => Self::load_interleaved::<3>(slice)[0],
+            4 if whole => Self::load_interleaved::<4>(slice)[0],
+            // Every index is in bounds, so no lane is taken from the fallback.
+            _ => Self::gather_or(slice, Self::stride_indices(stride), Self::splat(slice[0])),
+        }
+    }
+
+    /// Stores the lanes to every `stride`-th element from the start of `slice`, so lane `i` is
+    /// written to `slice[i * stride]`.
+    ///
+    /// The elements between the lanes are unchanged.  If `stride` is zero, the last lane is
+    /// written.  As with [`gather_stride`](Self::gather_stride), strides of 2 to 4 use
+    /// shuffles when `slice` holds `stride * LANES` elements: the elements are loaded
+    /// interleaved, the first stream is replaced, and they are stored back.  Other strides are
+    /// written with [`scatter`](Self::scatter).
+    ///
+    /// # Panics
+    /// Panics if `slice` doesn't contain the element for the last lane, at
+    /// `(LANES - 1) * stride`.
+    ///
+    /// ```
+    /// # use core_simd::Simd;
+    /// let mut matrix = [0; 6];
+    /// Simd::<i32, 2>::from_array([1, 2]).scatter_stride(&mut matrix[2..], 3);
+    /// assert_eq!(matrix, [0, 0, 1, 0, 0, 2]);
+    /// ```
+    #[inline]
+    pub fn scatter_stride(self, slice: &mut [T], stride: usize) {
+        assert!(
+            Self::stride_in_bounds(slice.len(), stride),
+            "slice must contain the element for every lane"
+        );
+        let whole = slice.len() >= stride * LANES;
+        match stride {
+            1 => slice[..LANES].copy_from_slice(self.as_slice()),
+            2 if whole => {
+                let [_, b] = Self::load_interleaved::<2>(slice);
+                Self::store_interleaved([self, b], slice);
+            }
+            3 if whole => {
+                let [_, b, c] = Self::load_interleaved::<3>(slice);
+                Self::store_interleaved([self, b, c], slice);
+            }
+            4 if whole => {
+                let [_, b, c, d] = Self::load_interleaved::<4>(slice);
+                Self::store_interleaved([self, b, c, d], slice);
+            }
+            _ => self.scatter(slice, Self::stride_indices(stride)),
+        }
+    }
+}
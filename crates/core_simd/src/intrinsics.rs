@@ -59,6 +59,11 @@ extern "platform-intrinsic" {
     pub(crate) fn simd_shuffle8<T, U>(x: T, y: T, idx: [u32; 8]) -> U;
     pub(crate) fn simd_shuffle16<T, U>(x: T, y: T, idx: [u32; 16]) -> U;
     pub(crate) fn simd_shuffle32<T, U>(x: T, y: T, idx: [u32; 32]) -> U;
+    pub(crate) fn simd_shuffle64<T, U>(x: T, y: T, idx: [u32; 64]) -> U;
+    pub(crate) fn simd_shuffle128<T, U>(x: T, y: T, idx: [u32; 128]) -> U;
+
+    // fma, which calls the math library unless the target has FMA instructions
+    pub(crate) fn simd_fma<T>(x: T, y: T, z: T) -> T;
 
     // {s,u}add.sat
     pub(crate) fn simd_saturating_add<T>(x: T, y: T) -> T;
@@ -67,8 +72,8 @@ extern "platform-intrinsic" {
     pub(crate) fn simd_saturating_sub<T>(x: T, y: T) -> T;
 
     // reductions
-    pub(crate) fn simd_reduce_add_ordered<T, U>(x: T, y: U) -> U;
-    pub(crate) fn simd_reduce_mul_ordered<T, U>(x: T, y: U) -> U;
+    pub(crate) fn simd_reduce_add_unordered<T, U>(x: T) -> U;
+    pub(crate) fn simd_reduce_mul_unordered<T, U>(x: T) -> U;
     pub(crate) fn simd_reduce_all<T>(x: T) -> bool;
     pub(crate) fn simd_reduce_any<T>(x: T) -> bool;
     pub(crate) fn simd_reduce_max<T, U>(x: T) -> U;
@@ -80,6 +85,10 @@ extern "platform-intrinsic" {
     // truncate integer vector to bitmask
     pub(crate) fn simd_bitmask<T, U>(x: T) -> U;
 
+    // gather and scatter, with a vector of pointers and an integer mask
+    pub(crate) fn simd_gather<T, U, V>(val: T, ptr: U, mask: V) -> T;
+    pub(crate) fn simd_scatter<T, U, V>(val: T, ptr: U, mask: V);
+
     // select
     pub(crate) fn simd_select<T, U>(m: T, a: U, b: U) -> U;
     #[allow(unused)]
@@ -100,6 +109,33 @@ mod std {
 
         // trunc
         pub(crate) fn simd_trunc<T>(x: T) -> T;
+
+        // sqrt
+        pub(crate) fn simd_fsqrt<T>(x: T) -> T;
+
+        // sin
+        pub(crate) fn simd_fsin<T>(x: T) -> T;
+
+        // cos
+        pub(crate) fn simd_fcos<T>(x: T) -> T;
+
+        // pow
+        pub(crate) fn simd_fpow<T>(x: T, y: T) -> T;
+
+        // exp
+        pub(crate) fn simd_fexp<T>(x: T) -> T;
+
+        // exp2
+        pub(crate) fn simd_fexp2<T>(x: T) -> T;
+
+        // log
+        pub(crate) fn simd_flog<T>(x: T) -> T;
+
+        // log2
+        pub(crate) fn simd_flog2<T>(x: T) -> T;
+
+        // log10
+        pub(crate) fn simd_flog10<T>(x: T) -> T;
     }
 }
 
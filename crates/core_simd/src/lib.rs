@@ -1,13 +1,27 @@
 #![no_std]
 #![allow(incomplete_features)]
 #![feature(repr_simd, platform_intrinsics, simd_ffi, const_generics)]
+#![feature(const_fn_trait_bound, const_fn_floating_point_arithmetic)]
 #![feature(extended_key_value_attributes)]
+#![cfg_attr(
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "powerpc",
+        target_arch = "powerpc64"
+    ),
+    feature(stdsimd)
+)]
 #![warn(missing_docs)]
 //! Portable SIMD module.
 
-#[macro_use]
-mod first;
-#[macro_use]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 mod permute;
 #[macro_use]
 mod transmute;
@@ -17,16 +31,78 @@ mod reduction;
 mod select;
 pub use select::Select;
 
+#[cfg(feature = "alloc")]
+mod aligned_buf;
+#[cfg(feature = "alloc")]
+pub use aligned_buf::AlignedBuf;
+mod ascii;
 mod comparisons;
+mod const_ops;
+mod constant_time;
+mod fast;
+pub use fast::FastFloat;
+mod filter;
+pub use filter::{filter_bitmap, filter_indices};
 mod fmt;
+mod interleaved;
 mod intrinsics;
+mod lookup;
+mod masked;
+pub use masked::MaskedSimd;
+mod mat4;
+pub use mat4::Mat4;
+mod morton;
+mod nontemporal;
+mod num;
+pub use num::{SimdFloat, SimdInt, SimdInteger, SimdNum, SimdUint};
 mod ops;
+mod pack;
+mod prng;
+pub use prng::SimdRng;
+mod quantize;
 mod round;
+mod search;
+pub use search::find_byte;
+pub mod slice_ops;
+mod soa;
+mod sort;
+pub use sort::{simd_sort, SortElement};
+mod swizzle;
+pub use swizzle::{
+    BroadcastLane, DuplicateEven, DuplicateOdd, InterleaveHi, InterleaveLo, Reverse,
+    RotateLanesLeft, RotateLanesRight, Swizzle,
+};
 
 mod math;
 
-mod lanes_at_most_32;
-pub use lanes_at_most_32::LanesAtMost32;
+mod float_math;
+
+#[cfg(feature = "std")]
+mod dispatch;
+#[cfg(feature = "std")]
+mod std_float;
+#[cfg(feature = "std")]
+pub use std_float::StdFloat;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rand")]
+pub use crate::rand::UniformSimd;
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "rayon")]
+pub use crate::rayon::{ParSimdChunks, ParSimdChunksMut, ParSimdSlice};
+#[cfg(feature = "serde")]
+mod serde;
+
+mod lane_count;
+pub use lane_count::{LaneCount, SupportedLaneCount};
 
 mod masks;
 pub use masks::*;
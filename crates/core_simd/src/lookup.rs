@@ -0,0 +1,61 @@
+//! Byte table lookups, as done by `pshufb` on x86 and `tbl` on AArch64.
+
+use crate::{LaneCount, Simd, SupportedLaneCount};
+
+impl<const LANES: usize> Simd<u8, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Looks up each lane in a table of 16 bytes.
+    ///
+    /// Each lane of `self` is an index into `table`, and is replaced by the table entry at that
+    /// index.  Lanes with an index of 16 or more become zero.  This is the basis of vectorized
+    /// base64 and hex codecs, and of classifying bytes by their high and low nibbles.
+    ///
+    /// The `pshufb` instruction is used on x86 when the `ssse3` target feature is enabled and
+    /// the vector has at least 16 lanes.  Otherwise each table entry is compared and
+    /// selected in turn.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let hex = u8x16::from_array(*b"0123456789abcdef");
+    /// let nibbles = u8x8::from_array([0, 1, 9, 10, 15, 16, 0x80, 0xff]);
+    /// assert_eq!(&nibbles.lookup16(hex).to_array(), b"019af\0\0\0");
+    /// ```
+    #[inline]
+    pub fn lookup16(self, table: Simd<u8, 16>) -> Self {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "ssse3"
+        ))]
+        // Lane counts are powers of two, so these vectors are whole `__m128i`s.
+        if LANES >= 16 {
+            #[cfg(target_arch = "x86")]
+            use core::arch::x86::{__m128i, _mm_shuffle_epi8};
+            #[cfg(target_arch = "x86_64")]
+            use core::arch::x86_64::{__m128i, _mm_shuffle_epi8};
+            // `pshufb` returns zero for indices with the high bit set, and otherwise uses the
+            // low four bits.  Saturating addition sets the high bit for every index from 16.
+            let indices = self.saturating_add(Self::splat(0x70)).to_array();
+            let table = __m128i::from(table);
+            let mut result = [0; LANES];
+            for (result, indices) in result.chunks_exact_mut(16).zip(indices.chunks_exact(16)) {
+                let mut chunk = [0; 16];
+                chunk.copy_from_slice(indices);
+                // Safety: the target feature is enabled.
+                let chunk =
+                    unsafe { _mm_shuffle_epi8(table, Simd::<u8, 16>::from_array(chunk).into()) };
+                result.copy_from_slice(&Simd::<u8, 16>::from(chunk).to_array());
+            }
+            return Self::from_array(result);
+        }
+
+        let mut result = Self::splat(0);
+        for (i, entry) in table.to_array().iter().enumerate() {
+            result = self
+                .lanes_eq(Self::splat(i as u8))
+                .select(Self::splat(*entry), result);
+        }
+        result
+    }
+}
@@ -0,0 +1,280 @@
+//! Vectors with a validity mask, for data that may contain nulls.
+
+use crate::{LaneCount, Mask, Simd, SimdElement, SupportedLaneCount};
+
+/// A vector paired with a mask of which lanes are valid, like a chunk of a nullable column.
+///
+/// Arithmetic propagates nulls as in SQL and Apache Arrow: a lane of the result is valid only if
+/// that lane is valid in every operand.  Null lanes always hold `T::default()`, so comparing two
+/// `MaskedSimd`s compares only their valid lanes, and [`value`](Self::value) can be reduced
+/// directly, as in the example below.
+///
+/// Null lanes are never computed on, so integer arithmetic only panics on overflow in valid
+/// lanes.
+///
+/// The bitwise operators propagate nulls strictly too, so `false & null` is null.  Use
+/// [`kleene_and`](Self::kleene_and) and [`kleene_or`](Self::kleene_or) for SQL's three-valued
+/// logic, where it's `false`.
+///
+/// # Examples
+/// ```
+/// # use core_simd::*;
+/// let a = MaskedSimd::from_options([Some(1), None, Some(3), Some(4)]);
+/// let b = MaskedSimd::from_options([Some(10), Some(20), None, Some(40)]);
+/// let sum: MaskedSimd<i32, 4> = a + b;
+/// assert_eq!(sum.to_options(), [Some(11), None, None, Some(44)]);
+/// // Nulls are zero in `value`, so they don't contribute to the sum.
+/// assert_eq!(sum.value().horizontal_sum(), 55);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MaskedSimd<T, const LANES: usize>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    value: Simd<T, LANES>,
+    valid: Mask<T::Mask, LANES>,
+}
+
+impl<T, const LANES: usize> MaskedSimd<T, LANES>
+where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Creates a vector from its values and validity mask.  The values of null lanes are
+    /// discarded.
+    #[inline]
+    pub fn new(value: Simd<T, LANES>, valid: Mask<T::Mask, LANES>) -> Self {
+        Self {
+            value: valid.select(value, Simd::splat(T::default())),
+            valid,
+        }
+    }
+
+    /// Creates a vector in which every lane is valid.
+    #[inline]
+    pub fn from_value(value: Simd<T, LANES>) -> Self {
+        Self {
+            value,
+            valid: Mask::splat(true),
+        }
+    }
+
+    /// Creates a vector in which every lane is null.
+    #[inline]
+    pub fn null() -> Self {
+        Self {
+            value: Simd::splat(T::default()),
+            valid: Mask::splat(false),
+        }
+    }
+
+    /// Creates a vector from an array, with a null lane for each `None`.
+    #[inline]
+    pub fn from_options(options: [Option<T>; LANES]) -> Self {
+        let mut value = [T::default(); LANES];
+        let mut valid = [false; LANES];
+        for (i, option) in options.iter().enumerate() {
+            if let Some(x) = option {
+                value[i] = *x;
+                valid[i] = true;
+            }
+        }
+        Self::new(Simd::from_array(value), Mask::from_array(valid))
+    }
+
+    /// Converts the vector to an array, with `None` for each null lane.
+    #[inline]
+    pub fn to_options(self) -> [Option<T>; LANES] {
+        let mut options = [None; LANES];
+        for (i, option) in options.iter_mut().enumerate() {
+            *option = self.get(i);
+        }
+        options
+    }
+
+    /// Returns the value of a lane, or `None` if it is null.
+    ///
+    /// # Panics
+    /// Panics if `lane` is out of bounds.
+    #[inline]
+    pub fn get(&self, lane: usize) -> Option<T> {
+        if self.valid.test(lane) {
+            Some(self.value[lane])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the values of the vector, with `T::default()` in null lanes.
+    #[inline]
+    pub fn value(self) -> Simd<T, LANES> {
+        self.value
+    }
+
+    /// Returns the mask of valid lanes.
+    #[inline]
+    pub fn validity(self) -> Mask<T::Mask, LANES> {
+        self.valid
+    }
+
+    /// Returns the values of the vector, replacing null lanes with the lanes of `default`.
+    #[inline]
+    pub fn unwrap_or(self, default: Simd<T, LANES>) -> Simd<T, LANES> {
+        self.valid.select(self.value, default)
+    }
+
+    /// Returns the values of `self` and `other` and the mask of lanes valid in both.
+    ///
+    /// Lanes that are null in either vector are replaced with `T::default()` in both, so that
+    /// operating on them can't overflow.
+    #[inline]
+    fn both_valid(self, other: Self) -> (Simd<T, LANES>, Simd<T, LANES>, Mask<T::Mask, LANES>) {
+        let valid = self.valid & other.valid;
+        let zero = Simd::splat(T::default());
+        (
+            valid.select(self.value, zero),
+            valid.select(other.value, zero),
+            valid,
+        )
+    }
+}
+
+impl<T, const LANES: usize> MaskedSimd<T, LANES>
+where
+    T: SimdElement + Default + PartialEq,
+    Simd<T, LANES>: core::ops::BitAnd<Output = Simd<T, LANES>>
+        + core::ops::BitOr<Output = Simd<T, LANES>>
+        + core::ops::Not<Output = Simd<T, LANES>>,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Bitwise AND with Kleene (three-valued) null handling, as in SQL and Apache Arrow's
+    /// `and_kleene`.
+    ///
+    /// A null lane is unknown in every bit, so a lane of the result is also valid if either
+    /// operand is valid and zero, since the AND is then zero whatever the other lane holds.  For
+    /// boolean lanes holding `0` or `!0`, as produced by [`Mask::to_int`], this is Kleene logic:
+    /// `false & null` is `false`, while `true & null` is null.
+    ///
+    /// # Examples
+    /// ```
+    /// # use core_simd::*;
+    /// let a = MaskedSimd::from_options([Some(0), Some(-1), None, Some(-1)]);
+    /// let b = MaskedSimd::from_options([None, None, None, Some(0)]);
+    /// assert_eq!(a.kleene_and(b).to_options(), [Some(0), None, None, Some(0)]);
+    /// ```
+    #[inline]
+    pub fn kleene_and(self, other: Self) -> Self {
+        let zero = Simd::splat(T::default());
+        let valid = (self.valid & other.valid)
+            | (self.valid & self.value.lanes_eq(zero))
+            | (other.valid & other.value.lanes_eq(zero));
+        Self::new(self.value & other.value, valid)
+    }
+
+    /// Bitwise OR with Kleene (three-valued) null handling, as in SQL and Apache Arrow's
+    /// `or_kleene`.
+    ///
+    /// A null lane is unknown in every bit, so a lane of the result is also valid if either
+    /// operand is valid and has every bit set, since the OR then has every bit set whatever the
+    /// other lane holds.  For boolean lanes holding `0` or `!0`, as produced by
+    /// [`Mask::to_int`], this is Kleene logic: `true | null` is `true`, while `false | null` is
+    /// null.
+    ///
+    /// # Examples
+    /// ```
+    /// # use core_simd::*;
+    /// let a = MaskedSimd::from_options([Some(-1), Some(0), None, Some(0)]);
+    /// let b = MaskedSimd::from_options([None, None, None, Some(0)]);
+    /// assert_eq!(a.kleene_or(b).to_options(), [Some(-1), None, None, Some(0)]);
+    /// ```
+    #[inline]
+    pub fn kleene_or(self, other: Self) -> Self {
+        let ones = !Simd::splat(T::default());
+        let valid = (self.valid & other.valid)
+            | (self.valid & self.value.lanes_eq(ones))
+            | (other.valid & other.value.lanes_eq(ones));
+        // Null lanes hold zero, so they don't change the valid operand.
+        Self::new(self.value | other.value, valid)
+    }
+}
+
+impl<T, const LANES: usize> Default for MaskedSimd<T, LANES>
+where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Returns a vector in which every lane is null.
+    #[inline]
+    fn default() -> Self {
+        Self::null()
+    }
+}
+
+impl<T, const LANES: usize> From<Simd<T, LANES>> for MaskedSimd<T, LANES>
+where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn from(value: Simd<T, LANES>) -> Self {
+        Self::from_value(value)
+    }
+}
+
+macro_rules! impl_binary_op {
+    { $($trait:ident::$fn:ident, $assign_trait:ident::$assign_fn:ident;)* } => {
+        $(
+        impl<T, const LANES: usize> core::ops::$trait for MaskedSimd<T, LANES>
+        where
+            T: SimdElement + Default,
+            Simd<T, LANES>: core::ops::$trait<Output = Simd<T, LANES>>,
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self, rhs: Self) -> Self {
+                let (lhs, rhs, valid) = self.both_valid(rhs);
+                Self::new(lhs.$fn(rhs), valid)
+            }
+        }
+
+        impl<T, const LANES: usize> core::ops::$assign_trait for MaskedSimd<T, LANES>
+        where
+            T: SimdElement + Default,
+            Simd<T, LANES>: core::ops::$trait<Output = Simd<T, LANES>>,
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = core::ops::$trait::$fn(*self, rhs);
+            }
+        }
+        )*
+    }
+}
+
+// Division and remainder aren't implemented, as null integer lanes would divide by zero.
+impl_binary_op! {
+    Add::add, AddAssign::add_assign;
+    Sub::sub, SubAssign::sub_assign;
+    Mul::mul, MulAssign::mul_assign;
+    BitAnd::bitand, BitAndAssign::bitand_assign;
+    BitOr::bitor, BitOrAssign::bitor_assign;
+    BitXor::bitxor, BitXorAssign::bitxor_assign;
+}
+
+impl<T, const LANES: usize> core::ops::Neg for MaskedSimd<T, LANES>
+where
+    T: SimdElement + Default,
+    Simd<T, LANES>: core::ops::Neg<Output = Simd<T, LANES>>,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.value, self.valid)
+    }
+}
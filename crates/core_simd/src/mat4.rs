@@ -0,0 +1,116 @@
+//! 4x4 `f32` matrices for 3D transforms, stored as one vector per column.
+
+use crate::f32x4;
+
+/// Computes `a * b + c`, fused when the target has fused multiply-add instructions.
+#[inline]
+fn mul_add(a: f32x4, b: f32x4, c: f32x4) -> f32x4 {
+    #[cfg(target_feature = "fma")]
+    {
+        unsafe { crate::intrinsics::simd_fma(a, b, c) }
+    }
+    #[cfg(not(target_feature = "fma"))]
+    {
+        a * b + c
+    }
+}
+
+/// A 4x4 matrix of `f32` values, stored in column-major order.
+///
+/// Vectors are treated as columns, so [`transform`](Self::transform) computes `M * v`, and
+/// `(a * b).transform(v)` applies `b` first.  This matches the conventions of OpenGL and most
+/// graphics math libraries.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Mat4 {
+    cols: [f32x4; 4],
+}
+
+impl Mat4 {
+    /// The identity matrix.
+    pub const IDENTITY: Self = Self::from_cols([
+        f32x4::from_array([1., 0., 0., 0.]),
+        f32x4::from_array([0., 1., 0., 0.]),
+        f32x4::from_array([0., 0., 1., 0.]),
+        f32x4::from_array([0., 0., 0., 1.]),
+    ]);
+
+    /// Creates a matrix from its columns.
+    #[inline]
+    pub const fn from_cols(cols: [f32x4; 4]) -> Self {
+        Self { cols }
+    }
+
+    /// Creates a matrix from its rows.
+    #[inline]
+    pub fn from_rows(rows: [f32x4; 4]) -> Self {
+        Self::from_cols(f32x4::transpose(rows))
+    }
+
+    /// Returns the columns of the matrix.
+    #[inline]
+    pub const fn to_cols(self) -> [f32x4; 4] {
+        self.cols
+    }
+
+    /// Returns the rows of the matrix.
+    #[inline]
+    pub fn to_rows(self) -> [f32x4; 4] {
+        f32x4::transpose(self.cols)
+    }
+
+    /// Returns the transpose of the matrix.
+    #[inline]
+    pub fn transpose(self) -> Self {
+        Self::from_cols(self.to_rows())
+    }
+
+    /// Multiplies the matrix by the column vector `v`.
+    ///
+    /// This is the sum of the columns scaled by the lanes of `v`, which only needs lanewise
+    /// multiplication, so no horizontal operations are used.
+    ///
+    /// ```
+    /// # use core_simd::{f32x4, Mat4};
+    /// let translate = Mat4::from_rows([
+    ///     f32x4::from_array([1., 0., 0., 5.]),
+    ///     f32x4::from_array([0., 1., 0., 6.]),
+    ///     f32x4::from_array([0., 0., 1., 7.]),
+    ///     f32x4::from_array([0., 0., 0., 1.]),
+    /// ]);
+    /// let point = f32x4::from_array([1., 2., 3., 1.]);
+    /// assert_eq!(translate.transform(point).to_array(), [6., 8., 10., 1.]);
+    /// ```
+    #[inline]
+    pub fn transform(self, v: f32x4) -> f32x4 {
+        let [c0, c1, c2, c3] = self.cols;
+        let xy = mul_add(c1, f32x4::splat(v[1]), c0 * f32x4::splat(v[0]));
+        let zw = mul_add(c3, f32x4::splat(v[3]), c2 * f32x4::splat(v[2]));
+        xy + zw
+    }
+}
+
+impl core::ops::Mul<f32x4> for Mat4 {
+    type Output = f32x4;
+
+    #[inline]
+    fn mul(self, rhs: f32x4) -> f32x4 {
+        self.transform(rhs)
+    }
+}
+
+impl core::ops::Mul for Mat4 {
+    type Output = Self;
+
+    /// Multiplies two matrices.  Each column of the result is `self` transforming the column
+    /// of `rhs`.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let [c0, c1, c2, c3] = rhs.cols;
+        Self::from_cols([
+            self.transform(c0),
+            self.transform(c1),
+            self.transform(c2),
+            self.transform(c3),
+        ])
+    }
+}
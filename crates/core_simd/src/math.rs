@@ -1,6 +1,315 @@
-macro_rules! impl_uint_arith {
+macro_rules! impl_wrapping_arith {
+    ($(($name:ident, $n:ident)),+) => {
+        $( impl<const LANES: usize> Simd<$n, LANES> where LaneCount<LANES>: SupportedLaneCount {
+
+            /// Lanewise wrapping addition.
+            ///
+            /// Unlike `+`, this never panics on overflow, even with debug assertions enabled.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([0, 1, MAX, MAX]);")]
+            #[doc = concat!("assert_eq!(x.wrapping_add(", stringify!($name), "::splat(1)), ", stringify!($name), "::from_array([1, 2, MAX.wrapping_add(1), MAX.wrapping_add(1)]));")]
+            /// ```
+            #[inline]
+            pub fn wrapping_add(self, rhs: Self) -> Self {
+                unsafe { crate::intrinsics::simd_add(self, rhs) }
+            }
+
+            /// Lanewise wrapping subtraction.
+            ///
+            /// Unlike `-`, this never panics on overflow, even with debug assertions enabled.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MIN;")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([MIN, 1, 2, 3]);")]
+            #[doc = concat!("assert_eq!(x.wrapping_sub(", stringify!($name), "::splat(1)), ", stringify!($name), "::from_array([MIN.wrapping_sub(1), 0, 1, 2]));")]
+            /// ```
+            #[inline]
+            pub fn wrapping_sub(self, rhs: Self) -> Self {
+                unsafe { crate::intrinsics::simd_sub(self, rhs) }
+            }
+
+            /// Lanewise wrapping multiplication.
+            ///
+            /// Unlike `*`, this never panics on overflow, even with debug assertions enabled.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([0, 1, 2, MAX]);")]
+            #[doc = concat!("assert_eq!(x.wrapping_mul(", stringify!($name), "::splat(2)), ", stringify!($name), "::from_array([0, 2, 4, MAX.wrapping_mul(2)]));")]
+            /// ```
+            #[inline]
+            pub fn wrapping_mul(self, rhs: Self) -> Self {
+                unsafe { crate::intrinsics::simd_mul(self, rhs) }
+            }
+        })+
+    }
+}
+
+macro_rules! impl_strict_arith {
+    ($(($name:ident, $n:ident)),+) => {
+        $( impl<const LANES: usize> Simd<$n, LANES> where LaneCount<LANES>: SupportedLaneCount {
+
+            /// Lanewise strict addition.
+            ///
+            /// Panics if any lane overflows, whether or not debug assertions are enabled.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([0, 1, 2, MAX - 1]);")]
+            #[doc = concat!("assert_eq!(x.strict_add(", stringify!($name), "::splat(1)), ", stringify!($name), "::from_array([1, 2, 3, MAX]));")]
+            /// ```
+            #[inline]
+            pub fn strict_add(self, rhs: Self) -> Self {
+                if self.as_slice().iter().zip(rhs.as_slice()).any(|(x, y)| x.checked_add(*y).is_none()) {
+                    panic!("attempt to add with overflow");
+                }
+                self.wrapping_add(rhs)
+            }
+
+            /// Lanewise strict subtraction.
+            ///
+            /// Panics if any lane overflows, whether or not debug assertions are enabled.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MIN;")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([MIN + 1, 1, 2, 3]);")]
+            #[doc = concat!("assert_eq!(x.strict_sub(", stringify!($name), "::splat(1)), ", stringify!($name), "::from_array([MIN, 0, 1, 2]));")]
+            /// ```
+            #[inline]
+            pub fn strict_sub(self, rhs: Self) -> Self {
+                if self.as_slice().iter().zip(rhs.as_slice()).any(|(x, y)| x.checked_sub(*y).is_none()) {
+                    panic!("attempt to subtract with overflow");
+                }
+                self.wrapping_sub(rhs)
+            }
+
+            /// Lanewise strict multiplication.
+            ///
+            /// Panics if any lane overflows, whether or not debug assertions are enabled.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([0, 1, 2, MAX / 2]);")]
+            #[doc = concat!("assert_eq!(x.strict_mul(", stringify!($name), "::splat(2)), ", stringify!($name), "::from_array([0, 2, 4, MAX - 1]));")]
+            /// ```
+            #[inline]
+            pub fn strict_mul(self, rhs: Self) -> Self {
+                if self.as_slice().iter().zip(rhs.as_slice()).any(|(x, y)| x.checked_mul(*y).is_none()) {
+                    panic!("attempt to multiply with overflow");
+                }
+                self.wrapping_mul(rhs)
+            }
+
+            /// Lanewise strict left shift.
+            ///
+            /// Panics if any shift amount is negative or at least the number of bits in a lane.
+            /// The `<<` operator already checks this in every build profile, so this is only
+            /// provided alongside the other strict operations.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::splat(1);")]
+            #[doc = concat!("let shift = ", stringify!($name), "::from_array([0, 1, 2, ", stringify!($n), "::BITS as ", stringify!($n), " - 1]);")]
+            #[doc = concat!("assert_eq!(x.strict_shl(shift), ", stringify!($name), "::from_array([1, 2, 4, 1 << (", stringify!($n), "::BITS - 1)]));")]
+            /// ```
+            #[inline]
+            pub fn strict_shl(self, rhs: Self) -> Self {
+                if rhs.as_slice().iter().copied().any(crate::ops::invalid_shift_rhs) {
+                    panic!("attempt to shift left with overflow");
+                }
+                unsafe { crate::intrinsics::simd_shl(self, rhs) }
+            }
+
+            /// Lanewise strict right shift.
+            ///
+            /// Panics if any shift amount is negative or at least the number of bits in a lane.
+            /// Like [`strict_shl`](Self::strict_shl), this matches the `>>` operator.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::splat(64);")]
+            #[doc = concat!("let shift = ", stringify!($name), "::from_array([0, 1, 2, 6]);")]
+            #[doc = concat!("assert_eq!(x.strict_shr(shift), ", stringify!($name), "::from_array([64, 32, 16, 1]));")]
+            /// ```
+            #[inline]
+            pub fn strict_shr(self, rhs: Self) -> Self {
+                if rhs.as_slice().iter().copied().any(crate::ops::invalid_shift_rhs) {
+                    panic!("attempt to shift right with overflow");
+                }
+                unsafe { crate::intrinsics::simd_shr(self, rhs) }
+            }
+        })+
+    }
+}
+
+/// Reports a precondition violation in an unchecked operation: panics with debug assertions, and
+/// is otherwise unreachable, which lets the optimizer assume the precondition holds.
+///
+/// # Safety
+/// Must not be reached unless debug assertions are enabled.
+#[inline(always)]
+pub(crate) unsafe fn unchecked_precondition(msg: &str) -> ! {
+    if cfg!(debug_assertions) {
+        panic!("{}", msg)
+    } else {
+        core::hint::unreachable_unchecked()
+    }
+}
+
+macro_rules! impl_unchecked_arith {
     ($(($name:ident, $n:ident)),+) => {
-        $( impl<const LANES: usize> $name<LANES> where Self: crate::LanesAtMost32 {
+        $( impl<const LANES: usize> Simd<$n, LANES> where LaneCount<LANES>: SupportedLaneCount {
+
+            /// Lanewise unchecked addition.
+            ///
+            /// This emits the same vector instruction as [`wrapping_add`](Self::wrapping_add), but
+            /// overflow is unreachable without debug assertions, so the optimizer may assume that
+            /// no lane overflows.  For example, `x.unchecked_add(one).lanes_gt(x)` folds to a mask
+            /// of all `true`.
+            ///
+            /// # Safety
+            /// No lane may overflow.  This is checked with debug assertions.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([0, 1, 2, MAX - 1]);")]
+            #[doc = concat!("let sum = unsafe { x.unchecked_add(", stringify!($name), "::splat(1)) };")]
+            #[doc = concat!("assert_eq!(sum, ", stringify!($name), "::from_array([1, 2, 3, MAX]));")]
+            /// ```
+            #[inline]
+            pub unsafe fn unchecked_add(self, rhs: Self) -> Self {
+                if self.as_slice().iter().zip(rhs.as_slice()).any(|(x, y)| x.checked_add(*y).is_none()) {
+                    crate::math::unchecked_precondition("attempt to add with overflow");
+                }
+                crate::intrinsics::simd_add(self, rhs)
+            }
+
+            /// Lanewise unchecked subtraction.  See [`unchecked_add`](Self::unchecked_add).
+            ///
+            /// # Safety
+            /// No lane may overflow.  This is checked with debug assertions.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MIN;")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([MIN + 1, 1, 2, 3]);")]
+            #[doc = concat!("let difference = unsafe { x.unchecked_sub(", stringify!($name), "::splat(1)) };")]
+            #[doc = concat!("assert_eq!(difference, ", stringify!($name), "::from_array([MIN, 0, 1, 2]));")]
+            /// ```
+            #[inline]
+            pub unsafe fn unchecked_sub(self, rhs: Self) -> Self {
+                if self.as_slice().iter().zip(rhs.as_slice()).any(|(x, y)| x.checked_sub(*y).is_none()) {
+                    crate::math::unchecked_precondition("attempt to subtract with overflow");
+                }
+                crate::intrinsics::simd_sub(self, rhs)
+            }
+
+            /// Lanewise unchecked multiplication.  See [`unchecked_add`](Self::unchecked_add).
+            ///
+            /// # Safety
+            /// No lane may overflow.  This is checked with debug assertions.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([0, 1, 2, MAX / 2]);")]
+            #[doc = concat!("let product = unsafe { x.unchecked_mul(", stringify!($name), "::splat(2)) };")]
+            #[doc = concat!("assert_eq!(product, ", stringify!($name), "::from_array([0, 2, 4, MAX - 1]));")]
+            /// ```
+            #[inline]
+            pub unsafe fn unchecked_mul(self, rhs: Self) -> Self {
+                if self.as_slice().iter().zip(rhs.as_slice()).any(|(x, y)| x.checked_mul(*y).is_none()) {
+                    crate::math::unchecked_precondition("attempt to multiply with overflow");
+                }
+                crate::intrinsics::simd_mul(self, rhs)
+            }
+
+            /// Lanewise unchecked left shift.
+            ///
+            /// Unlike `<<`, this doesn't check the shift amounts without debug assertions, so it
+            /// compiles to a single shift instruction.
+            ///
+            /// # Safety
+            /// Each shift amount must be nonnegative and less than the number of bits in a lane.
+            /// This is checked with debug assertions.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::splat(1);")]
+            #[doc = concat!("let shift = ", stringify!($name), "::from_array([0, 1, 2, 3]);")]
+            #[doc = concat!("assert_eq!(unsafe { x.unchecked_shl(shift) }, ", stringify!($name), "::from_array([1, 2, 4, 8]));")]
+            /// ```
+            #[inline]
+            pub unsafe fn unchecked_shl(self, rhs: Self) -> Self {
+                if rhs.as_slice().iter().copied().any(crate::ops::invalid_shift_rhs) {
+                    crate::math::unchecked_precondition("attempt to shift left with overflow");
+                }
+                crate::intrinsics::simd_shl(self, rhs)
+            }
+
+            /// Lanewise unchecked right shift.  See [`unchecked_shl`](Self::unchecked_shl).
+            ///
+            /// # Safety
+            /// Each shift amount must be nonnegative and less than the number of bits in a lane.
+            /// This is checked with debug assertions.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::splat(8);")]
+            #[doc = concat!("let shift = ", stringify!($name), "::from_array([0, 1, 2, 3]);")]
+            #[doc = concat!("assert_eq!(unsafe { x.unchecked_shr(shift) }, ", stringify!($name), "::from_array([8, 4, 2, 1]));")]
+            /// ```
+            #[inline]
+            pub unsafe fn unchecked_shr(self, rhs: Self) -> Self {
+                if rhs.as_slice().iter().copied().any(crate::ops::invalid_shift_rhs) {
+                    crate::math::unchecked_precondition("attempt to shift right with overflow");
+                }
+                crate::intrinsics::simd_shr(self, rhs)
+            }
+        })+
+    }
+}
+
+/// Computes the high half of each double-width product, by multiplying in `$wide` if given.
+macro_rules! mul_high_body {
+    { $self:ident, $other:ident, $n:ident => $wide:ident } => {{
+        let (a, b): (Simd<$wide, LANES>, Simd<$wide, LANES>) =
+            unsafe { (crate::intrinsics::simd_cast($self), crate::intrinsics::simd_cast($other)) };
+        // The product of two `$n` fits in `$wide`, and LLVM lowers the whole sequence to a single
+        // high multiplication where the target has one, such as `pmulhw`.
+        let product: Simd<$wide, LANES> = unsafe { crate::intrinsics::simd_mul(a, b) };
+        unsafe { crate::intrinsics::simd_cast(product >> <$n>::BITS as $wide) }
+    }};
+    { $self:ident, $other:ident, $n:ident } => {
+        $self.widening_mul($other).1
+    };
+}
+
+macro_rules! impl_uint_arith {
+    ($(($name:ident, $n:ident $(=> $wide:ident)?)),+) => {
+        $( impl<const LANES: usize> Simd<$n, LANES> where LaneCount<LANES>: SupportedLaneCount {
 
             /// Lanewise saturating add.
             ///
@@ -10,9 +319,9 @@ macro_rules! impl_uint_arith {
             #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
             #[doc = concat!("let x = ", stringify!($name), "::from_array([2, 1, 0, MAX]);")]
             #[doc = concat!("let max = ", stringify!($name), "::splat(MAX);")]
-            /// let unsat = x + max;
+            /// let unsat = x.wrapping_add(max);
             /// let sat = x.saturating_add(max);
-            /// assert_eq!(x - 1, unsat);
+            /// assert_eq!(x.wrapping_sub(Simd::splat(1)), unsat);
             /// assert_eq!(sat, max);
             /// ```
             #[inline]
@@ -28,21 +337,181 @@ macro_rules! impl_uint_arith {
             #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
             #[doc = concat!("let x = ", stringify!($name), "::from_array([2, 1, 0, MAX]);")]
             #[doc = concat!("let max = ", stringify!($name), "::splat(MAX);")]
-            /// let unsat = x - max;
+            /// let unsat = x.wrapping_sub(max);
             /// let sat = x.saturating_sub(max);
-            /// assert_eq!(unsat, x + 1);
+            /// assert_eq!(unsat, x.wrapping_add(Simd::splat(1)));
             #[doc = concat!("assert_eq!(sat, ", stringify!($name), "::splat(0));")]
             #[inline]
             pub fn saturating_sub(self, second: Self) -> Self {
                 unsafe { crate::intrinsics::simd_saturating_sub(self, second) }
             }
+
+            /// Lanewise absolute difference, `|self - other|`.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([0, 7, MAX, MAX]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::from_array([5, 3, 0, MAX]);")]
+            #[doc = concat!("assert_eq!(a.abs_diff(b), ", stringify!($name), "::from_array([5, 4, MAX, 0]));")]
+            /// ```
+            #[inline]
+            pub fn abs_diff(self, other: Self) -> Self {
+                self.saturating_sub(other) | other.saturating_sub(self)
+            }
+
+            /// Lanewise midpoint, `(self + rhs) / 2` rounded down, computed without overflowing.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([0, 1, MAX, MAX]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::from_array([4, 4, MAX - 2, 0]);")]
+            #[doc = concat!("assert_eq!(a.midpoint(b), ", stringify!($name), "::from_array([2, 2, MAX - 1, MAX / 2]));")]
+            /// ```
+            #[inline]
+            pub fn midpoint(self, rhs: Self) -> Self {
+                (self & rhs) + ((self ^ rhs) >> 1)
+            }
+
+            /// Lanewise rounding average, `(self + rhs + 1) / 2` rounded down, computed without
+            /// overflowing.
+            ///
+            /// This is the midpoint rounded up, as computed by the `pavgb` and `pavgw`
+            /// instructions on x86 and `urhadd` on AArch64.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([0, 1, MAX, MAX]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::from_array([4, 4, MAX - 2, 0]);")]
+            #[doc = concat!("assert_eq!(a.avg_round(b), ", stringify!($name), "::from_array([2, 3, MAX - 1, MAX / 2 + 1]));")]
+            /// ```
+            #[inline]
+            pub fn avg_round(self, rhs: Self) -> Self {
+                (self | rhs) - ((self ^ rhs) >> 1)
+            }
+
+            /// Lanewise full multiplication, returning the low and high halves of each
+            /// double-width product.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([MAX, MAX, 3, 0]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::from_array([MAX, 2, 4, MAX]);")]
+            /// let (lo, hi) = a.widening_mul(b);
+            /// assert_eq!(lo, a.wrapping_mul(b));
+            #[doc = concat!("assert_eq!(hi, ", stringify!($name), "::from_array([MAX - 1, 1, 0, 0]));")]
+            /// ```
+            #[inline]
+            pub fn widening_mul(self, other: Self) -> (Self, Self) {
+                // Schoolbook multiplication on half-width digits, none of which overflow.
+                const HALF: $n = <$n>::BITS as $n / 2;
+                let mask = Self::splat(<$n>::MAX >> HALF);
+                let (a0, a1) = (self & mask, self >> HALF);
+                let (b0, b1) = (other & mask, other >> HALF);
+                let mid = a1 * b0 + ((a0 * b0) >> HALF);
+                let carry = a0 * b1 + (mid & mask);
+                (self.wrapping_mul(other), a1 * b1 + (mid >> HALF) + (carry >> HALF))
+            }
+
+            /// Lanewise high multiplication.  Returns the high half of each double-width product,
+            /// discarding the low half.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([MAX, MAX, 3, MAX / 2 + 1]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::from_array([MAX, 2, 4, 4]);")]
+            #[doc = concat!("assert_eq!(a.mul_high(b), ", stringify!($name), "::from_array([MAX - 1, 1, 0, 2]));")]
+            /// ```
+            #[inline]
+            pub fn mul_high(self, other: Self) -> Self {
+                mul_high_body! { self, other, $n $(=> $wide)? }
+            }
+
+            /// Lanewise add with carry.  Computes `self + rhs + carry` in each lane, returning the
+            /// wrapped sum and a mask of the lanes that carried out.
+            ///
+            /// Chaining the carry through successive limbs performs multi-limb addition.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([MAX, MAX, 1, 0]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::from_array([1, 0, 1, 0]);")]
+            /// let (sum, carry) = a.carrying_add(b, Mask::from_array([false, true, true, false]));
+            #[doc = concat!("assert_eq!(sum, ", stringify!($name), "::from_array([0, 0, 3, 0]));")]
+            /// assert_eq!(carry.to_array(), [true, true, false, false]);
+            /// ```
+            #[inline]
+            pub fn carrying_add(
+                self,
+                rhs: Self,
+                carry: Mask<<$n as SimdElement>::Mask, LANES>,
+            ) -> (Self, Mask<<$n as SimdElement>::Mask, LANES>) {
+                let sum = self.wrapping_add(rhs);
+                let result = sum.wrapping_add(carry.select(Self::splat(1), Self::splat(0)));
+                (result, sum.lanes_lt(self) | result.lanes_lt(sum))
+            }
+
+            /// Lanewise subtract with borrow.  Computes `self - rhs - borrow` in each lane,
+            /// returning the wrapped difference and a mask of the lanes that borrowed.
+            ///
+            /// Chaining the borrow through successive limbs performs multi-limb subtraction.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::MAX;")]
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([0, 0, 3, MAX]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::from_array([1, 0, 1, MAX]);")]
+            /// let (diff, borrow) = a.borrowing_sub(b, Mask::from_array([false, true, true, false]));
+            #[doc = concat!("assert_eq!(diff, ", stringify!($name), "::from_array([MAX, MAX, 1, 0]));")]
+            /// assert_eq!(borrow.to_array(), [true, true, false, false]);
+            /// ```
+            #[inline]
+            pub fn borrowing_sub(
+                self,
+                rhs: Self,
+                borrow: Mask<<$n as SimdElement>::Mask, LANES>,
+            ) -> (Self, Mask<<$n as SimdElement>::Mask, LANES>) {
+                let diff = self.wrapping_sub(rhs);
+                let result = diff.wrapping_sub(borrow.select(Self::splat(1), Self::splat(0)));
+                (result, self.lanes_lt(rhs) | diff.lanes_lt(result))
+            }
+
+            /// Lanewise Euclidean division, which for unsigned integers is the same as `/`.
+            ///
+            /// # Panics
+            /// Panics if any lane of `rhs` is zero.
+            #[inline]
+            pub fn div_euclid(self, rhs: Self) -> Self {
+                self / rhs
+            }
+
+            /// Lanewise Euclidean remainder, which for unsigned integers is the same as `%`.
+            ///
+            /// # Panics
+            /// Panics if any lane of `rhs` is zero.
+            #[inline]
+            pub fn rem_euclid(self, rhs: Self) -> Self {
+                self % rhs
+            }
         })+
     }
 }
 
 macro_rules! impl_int_arith {
-    ($(($name:ident, $n:ident)),+) => {
-        $( impl<const LANES: usize> $name<LANES> where Self: crate::LanesAtMost32 {
+    ($(($name:ident, $n:ident, $unsigned:ident $(=> $wide:ident)?)),+) => {
+        $( impl<const LANES: usize> Simd<$n, LANES> where LaneCount<LANES>: SupportedLaneCount {
 
             /// Lanewise saturating add.
             ///
@@ -52,7 +521,7 @@ macro_rules! impl_int_arith {
             #[doc = concat!("# use core::", stringify!($n), "::{MIN, MAX};")]
             #[doc = concat!("let x = ", stringify!($name), "::from_array([MIN, 0, 1, MAX]);")]
             #[doc = concat!("let max = ", stringify!($name), "::splat(MAX);")]
-            /// let unsat = x + max;
+            /// let unsat = x.wrapping_add(max);
             /// let sat = x.saturating_add(max);
             #[doc = concat!("assert_eq!(unsat, ", stringify!($name), "::from_array([-1, MAX, MIN, -2]));")]
             #[doc = concat!("assert_eq!(sat, ", stringify!($name), "::from_array([-1, MAX, MAX, MAX]));")]
@@ -70,7 +539,7 @@ macro_rules! impl_int_arith {
             #[doc = concat!("# use core::", stringify!($n), "::{MIN, MAX};")]
             #[doc = concat!("let x = ", stringify!($name), "::from_array([MIN, -2, -1, MAX]);")]
             #[doc = concat!("let max = ", stringify!($name), "::splat(MAX);")]
-            /// let unsat = x - max;
+            /// let unsat = x.wrapping_sub(max);
             /// let sat = x.saturating_sub(max);
             #[doc = concat!("assert_eq!(unsat, ", stringify!($name), "::from_array([1, MAX, MIN, 0]));")]
             #[doc = concat!("assert_eq!(sat, ", stringify!($name), "::from_array([MIN, MIN, MIN, 0]));")]
@@ -79,6 +548,27 @@ macro_rules! impl_int_arith {
                 unsafe { crate::intrinsics::simd_saturating_sub(self, second) }
             }
 
+            /// Lanewise absolute difference, `|self - other|`.
+            ///
+            /// The result is unsigned, so it doesn't overflow even for the difference between
+            /// `MIN` and `MAX`.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::{MIN, MAX};")]
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([MIN, -2, 7, MAX]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::from_array([MAX, 3, 7, -1]);")]
+            #[doc = concat!("assert_eq!(a.abs_diff(b), Simd::<", stringify!($unsigned), ", 4>::from_array([", stringify!($unsigned), "::MAX, 5, 0, MAX as ", stringify!($unsigned), " + 1]));")]
+            /// ```
+            #[inline]
+            pub fn abs_diff(self, other: Self) -> Simd<$unsigned, LANES> {
+                // The wrapping difference of the larger and smaller lanes is the correct
+                // unsigned result.
+                let diff = self.lanes_gt(other).select(self.wrapping_sub(other), other.wrapping_sub(self));
+                unsafe { crate::intrinsics::simd_cast(diff) }
+            }
+
             /// Lanewise absolute value, implemented in Rust.
             /// Every lane becomes its absolute value.
             ///
@@ -93,7 +583,7 @@ macro_rules! impl_int_arith {
             pub fn abs(self) -> Self {
                 const SHR: $n = <$n>::BITS as $n - 1;
                 let m = self >> SHR;
-                (self^m) - m
+                (self^m).wrapping_sub(m)
             }
 
             /// Lanewise saturating absolute value, implemented in Rust.
@@ -117,15 +607,51 @@ macro_rules! impl_int_arith {
                 (self^m).saturating_sub(m)
             }
 
+            /// Lanewise wrapping negation.
+            /// The MIN value is its own negation.  Unlike `-x`, this never panics on overflow, even
+            /// with debug assertions enabled.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::{MIN, MAX};")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([MIN, -2, 0, MAX]);")]
+            #[doc = concat!("assert_eq!(x.wrapping_neg(), ", stringify!($name), "::from_array([MIN, 2, 0, MIN + 1]));")]
+            /// ```
+            #[inline]
+            pub fn wrapping_neg(self) -> Self {
+                Self::splat(0).wrapping_sub(self)
+            }
+
+            /// Lanewise strict negation.
+            ///
+            /// Panics if any lane is the MIN value, whose negation overflows, whether or not
+            /// debug assertions are enabled.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::{MIN, MAX};")]
+            #[doc = concat!("let x = ", stringify!($name), "::from_array([MIN + 1, -2, 0, MAX]);")]
+            #[doc = concat!("assert_eq!(x.strict_neg(), ", stringify!($name), "::from_array([MAX, 2, 0, MIN + 1]));")]
+            /// ```
+            #[inline]
+            pub fn strict_neg(self) -> Self {
+                if self.as_slice().iter().any(|x| *x == <$n>::MIN) {
+                    panic!("attempt to negate with overflow");
+                }
+                self.wrapping_neg()
+            }
+
             /// Lanewise saturating negation, implemented in Rust.
-            /// As neg(), except the MIN value becomes MAX instead of itself.
+            /// As wrapping_neg(), except the MIN value becomes MAX instead of itself.
             ///
             /// # Examples
             /// ```
             /// # use core_simd::*;
             #[doc = concat!("# use core::", stringify!($n), "::{MIN, MAX};")]
             #[doc = concat!("let x = ", stringify!($name), "::from_array([MIN, -2, 3, MAX]);")]
-            /// let unsat = -x;
+            /// let unsat = x.wrapping_neg();
             /// let sat = x.saturating_neg();
             #[doc = concat!("assert_eq!(unsat, ", stringify!($name), "::from_array([MIN, 2, -3, MIN + 1]));")]
             #[doc = concat!("assert_eq!(sat, ", stringify!($name), "::from_array([MAX, 2, -3, MIN + 1]));")]
@@ -134,11 +660,426 @@ macro_rules! impl_int_arith {
             pub fn saturating_neg(self) -> Self {
                 Self::splat(0).saturating_sub(self)
             }
+
+            /// Lanewise full multiplication, returning the low and high halves of each
+            /// double-width product.
+            ///
+            /// The high half carries the sign of the product, and the low half holds its low bits.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::{MIN, MAX};")]
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([MIN, MAX, -1, -3]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::from_array([MIN, MAX, MAX, 4]);")]
+            /// let (lo, hi) = a.widening_mul(b);
+            #[doc = concat!("assert_eq!(lo, ", stringify!($name), "::from_array([0, 1, MIN + 1, -12]));")]
+            #[doc = concat!("assert_eq!(hi, ", stringify!($name), "::from_array([-(MIN / 2), MAX / 2, -1, -1]));")]
+            /// ```
+            #[inline]
+            pub fn widening_mul(self, other: Self) -> (Self, Self) {
+                // Schoolbook multiplication on half-width digits, where only the high digits are
+                // signed.  Only the product of the low digits can overflow, and masking its high
+                // digit after the shift undoes the wrap.
+                const HALF: $n = <$n>::BITS as $n / 2;
+                let mask = Self::splat(<$n>::MAX >> (HALF - 1));
+                let (a0, a1) = (self & mask, self >> HALF);
+                let (b0, b1) = (other & mask, other >> HALF);
+                let mid = a1 * b0 + ((a0.wrapping_mul(b0) >> HALF) & mask);
+                let carry = a0 * b1 + (mid & mask);
+                (self.wrapping_mul(other), a1 * b1 + (mid >> HALF) + (carry >> HALF))
+            }
+
+            /// Lanewise high multiplication.  Returns the high half of each double-width product,
+            /// discarding the low half, so the result is the product divided by `2^BITS` and
+            /// rounded toward negative infinity.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::{MIN, MAX};")]
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([MIN, MAX, -1, 3]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::from_array([MIN, MIN, 1, 4]);")]
+            #[doc = concat!("assert_eq!(a.mul_high(b), ", stringify!($name), "::from_array([-(MIN / 2), MIN / 2, -1, 0]));")]
+            /// ```
+            #[inline]
+            pub fn mul_high(self, other: Self) -> Self {
+                mul_high_body! { self, other, $n $(=> $wide)? }
+            }
+
+            /// Lanewise midpoint, `(self + rhs) / 2` rounded toward zero, computed without
+            /// overflowing.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::{MIN, MAX};")]
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([0, -1, MIN, MAX]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::from_array([3, -4, MIN, MIN]);")]
+            #[doc = concat!("assert_eq!(a.midpoint(b), ", stringify!($name), "::from_array([1, -2, MIN, 0]));")]
+            /// ```
+            #[inline]
+            pub fn midpoint(self, rhs: Self) -> Self {
+                // Round down, then round odd negative sums back up.
+                const SHR: $n = <$n>::BITS as $n - 1;
+                let floor = (self & rhs) + ((self ^ rhs) >> 1);
+                floor + ((self ^ rhs) & (floor >> SHR) & Self::splat(1))
+            }
+
+            /// Lanewise Euclidean division, the quotient `q` such that `self = q * rhs + r` with
+            /// `0 <= r < rhs.abs()`.
+            ///
+            /// This rounds toward negative infinity when `rhs` is positive, and toward positive
+            /// infinity when it is negative.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([7, -7, 7, -7]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::from_array([4, 4, -4, -4]);")]
+            #[doc = concat!("assert_eq!(a.div_euclid(b), ", stringify!($name), "::from_array([1, -2, -1, 2]));")]
+            /// ```
+            ///
+            /// # Panics
+            /// Panics if any lane of `rhs` is zero, or if a lane of `self` is `MIN` and the
+            /// corresponding lane of `rhs` is `-1`.
+            #[inline]
+            pub fn div_euclid(self, rhs: Self) -> Self {
+                let quotient = self / rhs;
+                let negative = (self % rhs).lanes_lt(Self::splat(0));
+                let positive_rhs = rhs.lanes_gt(Self::splat(0));
+                // Masks convert to -1 in true lanes.
+                quotient + (negative & positive_rhs).to_int() - (negative & !positive_rhs).to_int()
+            }
+
+            /// Lanewise Euclidean remainder, the least nonnegative `r` such that
+            /// `self = q * rhs + r` for some integer `q`.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([7, -7, 7, -7]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::from_array([4, 4, -4, -4]);")]
+            #[doc = concat!("assert_eq!(a.rem_euclid(b), ", stringify!($name), "::from_array([3, 1, 3, 1]));")]
+            /// ```
+            ///
+            /// # Panics
+            /// Panics if any lane of `rhs` is zero, or if a lane of `self` is `MIN` and the
+            /// corresponding lane of `rhs` is `-1`.
+            #[inline]
+            pub fn rem_euclid(self, rhs: Self) -> Self {
+                let remainder = self % rhs;
+                remainder.lanes_lt(Self::splat(0)).select(remainder.wrapping_add(rhs.abs()), remainder)
+            }
         })+
     }
 }
 
-use crate::vector::*;
+macro_rules! impl_float_arith {
+    ($($n:ident),+) => {
+        $(
+        #[cfg(feature = "std")]
+        impl<const LANES: usize> Simd<$n, LANES> where LaneCount<LANES>: SupportedLaneCount {
+            /// Lanewise Euclidean division, `self / rhs` rounded to the integer `q` such that
+            /// `self = q * rhs + r` with `0 <= r < rhs.abs()`.
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn div_euclid(self, rhs: Self) -> Self {
+                let quotient = (self / rhs).trunc();
+                let negative = (self % rhs).lanes_lt(Self::splat(0.0));
+                let adjustment = rhs.lanes_gt(Self::splat(0.0)).select(Self::splat(1.0), Self::splat(-1.0));
+                negative.select(quotient - adjustment, quotient)
+            }
 
-impl_uint_arith! { (SimdU8, u8), (SimdU16, u16), (SimdU32, u32), (SimdU64, u64), (SimdUsize, usize) }
-impl_int_arith! { (SimdI8, i8), (SimdI16, i16), (SimdI32, i32), (SimdI64, i64), (SimdIsize, isize) }
+            /// Lanewise Euclidean remainder, the least nonnegative `r` such that
+            /// `self = q * rhs + r` for some integer `q`.
+            ///
+            /// Rounding may cause the result to equal `rhs.abs()` when a lane of `self` is much
+            /// smaller than the corresponding lane of `rhs`.
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[inline]
+            pub fn rem_euclid(self, rhs: Self) -> Self {
+                let remainder = self % rhs;
+                remainder.lanes_lt(Self::splat(0.0)).select(remainder + rhs.abs(), remainder)
+            }
+        }
+        )+
+    }
+}
+
+impl<const LANES: usize> Simd<u64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Lanewise carry-less multiplication, returning the low half of each product.
+    ///
+    /// This multiplies the lanes as polynomials over GF(2), where addition is `xor`.  See
+    /// [`widening_carryless_mul`](Self::widening_carryless_mul) for the full product.
+    ///
+    /// # Examples
+    /// ```
+    /// # use core_simd::*;
+    /// let a = u64x2::from_array([0b101, 1 << 63]);
+    /// let b = u64x2::from_array([0b011, 0b10]);
+    /// assert_eq!(a.carryless_mul(b).to_array(), [0b1111, 0]);
+    /// ```
+    #[inline]
+    pub fn carryless_mul(self, other: Self) -> Self {
+        self.widening_carryless_mul(other).0
+    }
+
+    /// Lanewise carry-less multiplication, returning the low and high halves of each 128-bit
+    /// product.
+    ///
+    /// This multiplies the lanes as polynomials over GF(2), where addition is `xor`, which is
+    /// the basis of CRCs, GHASH, and Reed-Solomon codes.  It uses the `pclmulqdq` instruction on
+    /// x86-64 when that target feature is enabled, and shifts and `xor`s otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// # use core_simd::*;
+    /// let a = u64x2::from_array([0b101, 1 << 63]);
+    /// let b = u64x2::from_array([0b011, 0b10]);
+    /// let (lo, hi) = a.widening_carryless_mul(b);
+    /// assert_eq!(lo.to_array(), [0b1111, 0]);
+    /// assert_eq!(hi.to_array(), [0, 1]);
+    /// ```
+    #[inline]
+    pub fn widening_carryless_mul(self, other: Self) -> (Self, Self) {
+        #[cfg(all(target_arch = "x86_64", target_feature = "pclmulqdq"))]
+        {
+            use core::arch::x86_64::{
+                _mm_clmulepi64_si128, _mm_cvtsi128_si64, _mm_cvtsi64_si128, _mm_unpackhi_epi64,
+            };
+            let (mut lo, mut hi) = (self, self);
+            for lane in 0..LANES {
+                // Safety: the target feature is enabled.
+                unsafe {
+                    let product = _mm_clmulepi64_si128(
+                        _mm_cvtsi64_si128(self[lane] as i64),
+                        _mm_cvtsi64_si128(other[lane] as i64),
+                        0,
+                    );
+                    lo[lane] = _mm_cvtsi128_si64(product) as u64;
+                    hi[lane] = _mm_cvtsi128_si64(_mm_unpackhi_epi64(product, product)) as u64;
+                }
+            }
+            (lo, hi)
+        }
+
+        #[cfg(not(all(target_arch = "x86_64", target_feature = "pclmulqdq")))]
+        {
+            // Add a shifted copy of `self` for each set bit of `other`.
+            let (mut lo, mut hi) = (Self::splat(0), Self::splat(0));
+            for i in 0..64 {
+                let mask = Self::splat(0).wrapping_sub((other >> i) & Self::splat(1));
+                lo ^= (self << i) & mask;
+                if i > 0 {
+                    hi ^= (self >> (64 - i)) & mask;
+                }
+            }
+            (lo, hi)
+        }
+    }
+}
+
+/// Implements fixed-point multiplication for vectors of `$n`, computed in `$wide`.
+macro_rules! impl_fixed_point_mul {
+    { $($n:ty => $wide:ty),+ } => {
+        $(
+        impl<const LANES: usize> Simd<$n, LANES> where LaneCount<LANES>: SupportedLaneCount {
+            /// Lanewise saturating rounding doubling multiply, returning the high half.
+            ///
+            /// Each lane is `(2 * self * other + (1 << (BITS - 1))) >> BITS`, computed without
+            /// overflow and saturated.  Treating the lanes as fixed-point numbers in the range
+            /// `-1.0..1.0` (Q15 for `i16` and Q31 for `i32`), this is their product rounded to
+            /// nearest.  The only case that saturates is `MIN * MIN`, which is `MAX`.
+            ///
+            /// This is the `sqrdmulh` instruction on AArch64.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("# use core::", stringify!($n), "::{MIN, MAX};")]
+            #[doc = concat!("let half = 1 << (", stringify!($n), "::BITS - 2);")]
+            #[doc = concat!("let a = Simd::<", stringify!($n), ", 4>::from_array([half, half, -half, MIN]);")]
+            #[doc = concat!("let b = Simd::<", stringify!($n), ", 4>::from_array([half, 1, half, MIN]);")]
+            #[doc = concat!("assert_eq!(a.saturating_rounding_doubling_mul_high(b).to_array(), [half / 2, 1, -half / 2, MAX]);")]
+            /// ```
+            #[inline]
+            pub fn saturating_rounding_doubling_mul_high(self, other: Self) -> Self {
+                const BITS: $wide = core::mem::size_of::<$n>() as $wide * 8;
+                let (a, b): (Simd<$wide, LANES>, Simd<$wide, LANES>) =
+                    unsafe { (crate::intrinsics::simd_cast(self), crate::intrinsics::simd_cast(other)) };
+                // Halving the rounding constant and the shift instead of doubling the product
+                // keeps `MIN * MIN` in range of the wide type.
+                let product = (a * b + Simd::splat(1 << (BITS - 2))) >> (BITS - 1);
+                let max = Simd::splat(<$n>::MAX as $wide);
+                let saturated = product.lanes_gt(max).select(max, product);
+                unsafe { crate::intrinsics::simd_cast(saturated) }
+            }
+        }
+        )+
+    }
+}
+
+/// Implements `widening_pairwise_add` for vectors of `$narrow` with each even number of lanes.
+macro_rules! impl_widening_pairwise_add {
+    { $($narrow:ty => $wide:ty),+ } => {
+        $(
+        impl_widening_pairwise_add! {
+            @lanes $narrow => $wide; 2 => 1, 4 => 2, 8 => 4, 16 => 8, 32 => 16, 64 => 32, 128 => 64
+        }
+        )+
+    };
+    { @lanes $narrow:ty => $wide:ty; $($lanes:literal => $half:literal),+ } => {
+        $(
+        impl Simd<$narrow, $lanes> {
+            /// Adds adjacent pairs of lanes, producing half as many lanes of twice the width.
+            ///
+            /// Lane `i` of the result is the sum of lanes `2 * i` and `2 * i + 1`, which can't
+            /// overflow.  This is the `paddl` instruction on AArch64, and `pmaddubsw` or
+            /// `pmaddwd` with a multiplier of one on x86.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = Simd::<", stringify!($narrow), ", 4>::from_array([", stringify!($narrow), "::MAX, ", stringify!($narrow), "::MAX, 1, 2]);")]
+            #[doc = concat!("assert_eq!(x.widening_pairwise_add().to_array(), [2 * ", stringify!($narrow), "::MAX as ", stringify!($wide), ", 3]);")]
+            /// ```
+            #[inline]
+            pub fn widening_pairwise_add(self) -> Simd<$wide, $half> {
+                const HALF_BITS: $wide = (core::mem::size_of::<$narrow>() * 8) as $wide;
+                // Reinterpret each pair of lanes as one wide lane, then extend each half of it.
+                // The sum is the same whichever half holds the first lane of the pair.
+                // Safety: the vectors have the same size, and any bits are valid integers.
+                let pairs: Simd<$wide, $half> = unsafe { core::mem::transmute(self) };
+                (pairs << HALF_BITS >> HALF_BITS) + (pairs >> HALF_BITS)
+            }
+        }
+        )+
+    };
+}
+
+/// Implements `abs_diff_sum` for each vector of `u8` made of whole blocks of eight lanes.
+macro_rules! impl_abs_diff_sum {
+    { $($lanes:literal => $blocks:literal),+ } => {
+        $(
+        impl crate::SimdU8<$lanes> {
+            /// Sums the absolute differences between the lanes of `self` and `other` in each
+            /// block of eight lanes.
+            ///
+            /// Lane `i` of the result is the sum for lanes `8 * i` to `8 * i + 7`, as computed by
+            /// the `psadbw` instruction on x86.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            /// let a = u8x16::from_array([0, 1, 2, 3, 4, 5, 6, 7, 255, 0, 0, 0, 0, 0, 0, 0]);
+            /// let b = u8x16::from_array([7, 6, 5, 4, 3, 2, 1, 0, 0, 255, 0, 0, 0, 0, 0, 0]);
+            /// assert_eq!(a.abs_diff_sum(b), u64x2::from_array([32, 510]));
+            /// ```
+            #[inline]
+            pub fn abs_diff_sum(self, other: Self) -> crate::SimdU64<$blocks> {
+                self.abs_diff(other)
+                    .widening_pairwise_add()
+                    .widening_pairwise_add()
+                    .widening_pairwise_add()
+            }
+        }
+        )+
+    }
+}
+
+/// Implements the 8-bit dot products for each vector of `i32` that has a quarter as many lanes
+/// as a supported vector of bytes.
+macro_rules! impl_dot_i8_accumulate {
+    { $($lanes:literal => $bytes:literal),+ } => {
+        $(
+        impl Simd<i32, $lanes> {
+            /// Multiplies the lanes of `a` and `b`, and adds each group of four products to the
+            /// corresponding lane of `self`.
+            ///
+            /// Lane `i` of the result is `self[i]` plus the products of lanes `4 * i` to
+            /// `4 * i + 3`.  The products and their sums can't overflow, but the addition to
+            /// `self` wraps.  This is the `sdot` instruction on AArch64.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            /// let a = i8x8::from_array([1, 2, 3, 4, -128, -128, -128, -128]);
+            /// let b = i8x8::from_array([5, 6, 7, 8, -128, -128, -128, -128]);
+            /// let acc = i32x2::from_array([100, 0]);
+            /// assert_eq!(acc.dot_i8_accumulate(a, b).to_array(), [170, 65536]);
+            /// ```
+            #[inline]
+            pub fn dot_i8_accumulate(self, a: Simd<i8, $bytes>, b: Simd<i8, $bytes>) -> Self {
+                // Each product fits in an `i16`, and each sum of four fits in an `i32`.
+                let (a, b): (Simd<i16, $bytes>, Simd<i16, $bytes>) =
+                    unsafe { (crate::intrinsics::simd_cast(a), crate::intrinsics::simd_cast(b)) };
+                let sums: Simd<i64, $lanes> = (a * b).widening_pairwise_add().widening_pairwise_add();
+                self.wrapping_add(unsafe { crate::intrinsics::simd_cast::<_, Self>(sums) })
+            }
+
+            /// Multiplies the unsigned lanes of `a` by the signed lanes of `b`, and adds each
+            /// group of four products to the corresponding lane of `self`.
+            ///
+            /// This is the same as [`dot_i8_accumulate`](Self::dot_i8_accumulate) except that `a`
+            /// is unsigned, which is the `vpdpbusd` instruction on x86 and `usdot` on AArch64.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            /// let a = u8x8::from_array([1, 2, 3, 4, 255, 255, 255, 255]);
+            /// let b = i8x8::from_array([5, 6, 7, 8, -128, -128, -128, -128]);
+            /// let acc = i32x2::from_array([100, 0]);
+            /// assert_eq!(acc.dot_u8i8_accumulate(a, b).to_array(), [170, -130560]);
+            /// ```
+            #[inline]
+            pub fn dot_u8i8_accumulate(self, a: Simd<u8, $bytes>, b: Simd<i8, $bytes>) -> Self {
+                // Each product fits in an `i16`, and each sum of four fits in an `i32`.
+                let (a, b): (Simd<i16, $bytes>, Simd<i16, $bytes>) =
+                    unsafe { (crate::intrinsics::simd_cast(a), crate::intrinsics::simd_cast(b)) };
+                let sums: Simd<i64, $lanes> = (a * b).widening_pairwise_add().widening_pairwise_add();
+                self.wrapping_add(unsafe { crate::intrinsics::simd_cast::<_, Self>(sums) })
+            }
+        }
+        )+
+    }
+}
+
+use crate::{LaneCount, Mask, Simd, SimdElement, SupportedLaneCount};
+
+impl_wrapping_arith! {
+    (SimdU8, u8), (SimdU16, u16), (SimdU32, u32), (SimdU64, u64), (SimdU128, u128), (SimdUsize, usize),
+    (SimdI8, i8), (SimdI16, i16), (SimdI32, i32), (SimdI64, i64), (SimdI128, i128), (SimdIsize, isize)
+}
+impl_strict_arith! {
+    (SimdU8, u8), (SimdU16, u16), (SimdU32, u32), (SimdU64, u64), (SimdU128, u128), (SimdUsize, usize),
+    (SimdI8, i8), (SimdI16, i16), (SimdI32, i32), (SimdI64, i64), (SimdI128, i128), (SimdIsize, isize)
+}
+impl_unchecked_arith! {
+    (SimdU8, u8), (SimdU16, u16), (SimdU32, u32), (SimdU64, u64), (SimdU128, u128), (SimdUsize, usize),
+    (SimdI8, i8), (SimdI16, i16), (SimdI32, i32), (SimdI64, i64), (SimdI128, i128), (SimdIsize, isize)
+}
+impl_uint_arith! {
+    (SimdU8, u8 => u16),
+    (SimdU16, u16 => u32),
+    (SimdU32, u32 => u64),
+    (SimdU64, u64),
+    (SimdU128, u128),
+    (SimdUsize, usize)
+}
+impl_int_arith! {
+    (SimdI8, i8, u8 => i16),
+    (SimdI16, i16, u16 => i32),
+    (SimdI32, i32, u32 => i64),
+    (SimdI64, i64, u64),
+    (SimdI128, i128, u128),
+    (SimdIsize, isize, usize)
+}
+impl_float_arith! { f32, f64 }
+impl_fixed_point_mul! { i16 => i32, i32 => i64 }
+impl_widening_pairwise_add! { u8 => u16, u16 => u32, u32 => u64, i8 => i16, i16 => i32, i32 => i64 }
+impl_dot_i8_accumulate! { 1 => 4, 2 => 8, 4 => 16, 8 => 32, 16 => 64, 32 => 128 }
+impl_abs_diff_sum! { 8 => 1, 16 => 2, 32 => 4, 64 => 8, 128 => 16 }
@@ -0,0 +1,156 @@
+//! Morton (Z-order) codes, which interleave the bits of two or three coordinates.
+//!
+//! Without a vector `pdep`/`pext`, the bits are spread apart and gathered together with a few
+//! rounds of shifts and masks, each of which moves half of the remaining bits.
+
+use crate::intrinsics;
+use crate::{LaneCount, Simd, SupportedLaneCount};
+
+/// Spreads the low 16 bits of each lane so that there is a zero bit between each pair.
+#[inline]
+fn spread_2<const LANES: usize>(x: Simd<u32, LANES>) -> Simd<u32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let x = x & Simd::splat(0x0000ffff);
+    let x = (x | x << 8) & Simd::splat(0x00ff00ff);
+    let x = (x | x << 4) & Simd::splat(0x0f0f0f0f);
+    let x = (x | x << 2) & Simd::splat(0x33333333);
+    (x | x << 1) & Simd::splat(0x55555555)
+}
+
+/// Gathers the even bits of each lane into the low 16 bits.  The inverse of `spread_2`.
+#[inline]
+fn compact_2<const LANES: usize>(x: Simd<u32, LANES>) -> Simd<u32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let x = x & Simd::splat(0x55555555);
+    let x = (x | x >> 1) & Simd::splat(0x33333333);
+    let x = (x | x >> 2) & Simd::splat(0x0f0f0f0f);
+    let x = (x | x >> 4) & Simd::splat(0x00ff00ff);
+    (x | x >> 8) & Simd::splat(0x0000ffff)
+}
+
+/// Spreads the low 10 bits of each lane so that there are two zero bits between each pair.
+#[inline]
+fn spread_3<const LANES: usize>(x: Simd<u32, LANES>) -> Simd<u32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let x = x & Simd::splat(0x000003ff);
+    let x = (x | x << 16) & Simd::splat(0x030000ff);
+    let x = (x | x << 8) & Simd::splat(0x0300f00f);
+    let x = (x | x << 4) & Simd::splat(0x030c30c3);
+    (x | x << 2) & Simd::splat(0x09249249)
+}
+
+/// Gathers every third bit of each lane into the low 10 bits.  The inverse of `spread_3`.
+#[inline]
+fn compact_3<const LANES: usize>(x: Simd<u32, LANES>) -> Simd<u32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let x = x & Simd::splat(0x09249249);
+    let x = (x | x >> 2) & Simd::splat(0x030c30c3);
+    let x = (x | x >> 4) & Simd::splat(0x0300f00f);
+    let x = (x | x >> 8) & Simd::splat(0x030000ff);
+    (x | x >> 16) & Simd::splat(0x000003ff)
+}
+
+macro_rules! impl_morton {
+    { $($code:ty => $coord:ty, $bits_3d:literal, $code_bits_3d:literal;)* } => {
+        $(
+        impl<const LANES: usize> Simd<$code, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            /// Interleaves the bits of two coordinates into a 2D Morton code.
+            ///
+            /// Bit `i` of `x` becomes bit `2 * i` of the code, and bit `i` of `y` becomes bit
+            /// `2 * i + 1`.  Sorting points by their codes orders them along a Z-order curve,
+            /// which keeps nearby points close together.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = Simd::<", stringify!($coord), ", 4>::from_array([0, 1, 0, 3]);")]
+            #[doc = concat!("let y = Simd::<", stringify!($coord), ", 4>::from_array([0, 0, 1, 2]);")]
+            #[doc = concat!("let code = Simd::<", stringify!($code), ", 4>::morton_encode_2d(x, y);")]
+            /// assert_eq!(code.to_array(), [0, 0b01, 0b10, 0b1101]);
+            /// assert_eq!(code.morton_decode_2d(), (x, y));
+            /// ```
+            #[inline]
+            pub fn morton_encode_2d(x: Simd<$coord, LANES>, y: Simd<$coord, LANES>) -> Self {
+                let (x, y): (Simd<u32, LANES>, Simd<u32, LANES>) =
+                    unsafe { (intrinsics::simd_cast(x), intrinsics::simd_cast(y)) };
+                unsafe { intrinsics::simd_cast(spread_2(x) | spread_2(y) << 1) }
+            }
+
+            /// Splits a 2D Morton code into its two coordinates.
+            ///
+            /// This is the inverse of [`morton_encode_2d`](Self::morton_encode_2d).
+            #[inline]
+            pub fn morton_decode_2d(self) -> (Simd<$coord, LANES>, Simd<$coord, LANES>) {
+                let code: Simd<u32, LANES> = unsafe { intrinsics::simd_cast(self) };
+                unsafe {
+                    (
+                        intrinsics::simd_cast(compact_2(code)),
+                        intrinsics::simd_cast(compact_2(code >> 1)),
+                    )
+                }
+            }
+
+            /// Interleaves the bits of three coordinates into a 3D Morton code.
+            ///
+            #[doc = concat!("Only the low ", $bits_3d, " bits of each coordinate are used, so that the code fits in `", stringify!($code), "`.")]
+            /// Bit `i` of `x`, `y` and `z` becomes bit `3 * i`, `3 * i + 1` and `3 * i + 2` of
+            /// the code, respectively.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = Simd::<", stringify!($code), ", 4>::from_array([1, 0, 0, 3]);")]
+            #[doc = concat!("let y = Simd::<", stringify!($code), ", 4>::from_array([0, 1, 0, 0]);")]
+            #[doc = concat!("let z = Simd::<", stringify!($code), ", 4>::from_array([0, 0, 1, 2]);")]
+            #[doc = concat!("let code = Simd::<", stringify!($code), ", 4>::morton_encode_3d(x, y, z);")]
+            /// assert_eq!(code.to_array(), [0b001, 0b010, 0b100, 0b101_001]);
+            /// assert_eq!(code.morton_decode_3d(), (x, y, z));
+            /// ```
+            #[inline]
+            pub fn morton_encode_3d(x: Self, y: Self, z: Self) -> Self {
+                const MASK: u32 = (1 << $bits_3d) - 1;
+                let (x, y, z): (Simd<u32, LANES>, Simd<u32, LANES>, Simd<u32, LANES>) = unsafe {
+                    (intrinsics::simd_cast(x), intrinsics::simd_cast(y), intrinsics::simd_cast(z))
+                };
+                let mask = Simd::splat(MASK);
+                let code = spread_3(x & mask) | spread_3(y & mask) << 1 | spread_3(z & mask) << 2;
+                unsafe { intrinsics::simd_cast(code) }
+            }
+
+            /// Splits a 3D Morton code into its three coordinates.
+            ///
+            /// This is the inverse of [`morton_encode_3d`](Self::morton_encode_3d).  Only the
+            #[doc = concat!("low ", $code_bits_3d, " bits of the code are used.")]
+            #[inline]
+            pub fn morton_decode_3d(self) -> (Self, Self, Self) {
+                const MASK: u32 = (1 << $code_bits_3d) - 1;
+                let code: Simd<u32, LANES> = unsafe { intrinsics::simd_cast(self) };
+                let code = code & Simd::splat(MASK);
+                unsafe {
+                    (
+                        intrinsics::simd_cast(compact_3(code)),
+                        intrinsics::simd_cast(compact_3(code >> 1)),
+                        intrinsics::simd_cast(compact_3(code >> 2)),
+                    )
+                }
+            }
+        }
+        )*
+    }
+}
+
+impl_morton! {
+    u16 => u8, 5, 15;
+    u32 => u16, 10, 30;
+}
@@ -0,0 +1,66 @@
+//! Stores that bypass the cache, for data that won't be read again soon.
+
+use crate::{LaneCount, Simd, SimdElement, SupportedLaneCount};
+
+impl<T, const LANES: usize> Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Stores the vector to the first `LANES` elements of `slice` with a non-temporal hint.
+    ///
+    /// A non-temporal store writes to memory without loading the destination into the cache,
+    /// which avoids evicting other data when filling large buffers that aren't read again
+    /// soon, such as in `memset`-like kernels or when writing frame buffers.
+    ///
+    /// The `movntdq` instruction is used on x86 when the `sse2` target feature is enabled, the
+    /// vector is at least 16 bytes, and `slice` is aligned to 16 bytes.  Otherwise this is an
+    /// ordinary store.
+    ///
+    /// On x86, non-temporal stores aren't ordered with other stores, so an `sfence` is issued
+    /// after them to keep the stores visible to any thread that later synchronizes with this one.
+    /// When storing many vectors, it's faster to use `_mm_stream_si128` directly and fence once.
+    ///
+    /// # Panics
+    /// Panics if `slice` has fewer than `LANES` elements.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mut buffer = [0u8; 64];
+    /// for chunk in buffer.chunks_exact_mut(16) {
+    ///     u8x16::splat(0xff).store_nontemporal(chunk);
+    /// }
+    /// assert_eq!(buffer, [0xff; 64]);
+    /// ```
+    #[inline]
+    pub fn store_nontemporal(self, slice: &mut [T]) {
+        assert!(
+            slice.len() >= LANES,
+            "slice must contain at least `LANES` elements"
+        );
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2"
+        ))]
+        {
+            #[cfg(target_arch = "x86")]
+            use core::arch::x86::{__m128i, _mm_sfence, _mm_stream_si128};
+            #[cfg(target_arch = "x86_64")]
+            use core::arch::x86_64::{__m128i, _mm_sfence, _mm_stream_si128};
+            let dest = slice.as_mut_ptr();
+            // Vector sizes are powers of two, so these vectors are whole `__m128i`s.
+            if core::mem::size_of::<Self>() >= 16 && dest.align_offset(16) == 0 {
+                let src = (&self as *const Self).cast::<__m128i>();
+                let dest = dest.cast::<__m128i>();
+                for i in 0..core::mem::size_of::<Self>() / 16 {
+                    // Safety: `dest` is aligned and the slice contains the whole vector.
+                    unsafe { _mm_stream_si128(dest.add(i), src.add(i).read_unaligned()) };
+                }
+                // Safety: `sse2` implies `sse`.
+                unsafe { _mm_sfence() };
+                return;
+            }
+        }
+        slice[..LANES].copy_from_slice(self.as_slice());
+    }
+}
@@ -0,0 +1,482 @@
+//! Traits for vectors of each kind of element, for writing code that is generic over the element
+//! type.
+
+use crate::{LaneCount, Mask, Simd, SimdElement, SupportedLaneCount};
+use core::fmt::Debug;
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, Shr, Sub, SubAssign,
+};
+
+mod sealed {
+    pub trait Sealed {}
+}
+use sealed::Sealed;
+
+impl<T, const LANES: usize> Sealed for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
+
+/// Operations on vectors of any integer or float element type.
+///
+/// The vector types also implement these operations as inherent methods, so this trait is only
+/// needed for code that is generic over the vector type.  See [`SimdFloat`] for an example.
+///
+/// This trait is sealed and implemented for `Simd<T, LANES>` for every integer and float `T`.
+pub trait SimdNum:
+    Sealed
+    + Copy
+    + Debug
+    + Default
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + RemAssign
+{
+    /// The element type.
+    type Scalar: SimdElement + Debug + Default + PartialOrd;
+
+    /// The mask type returned by comparisons.
+    type Mask: Copy
+        + Debug
+        + PartialEq
+        + Not<Output = Self::Mask>
+        + BitAnd<Output = Self::Mask>
+        + BitOr<Output = Self::Mask>
+        + BitXor<Output = Self::Mask>;
+
+    /// The number of lanes.
+    const LANES: usize;
+
+    /// Constructs a vector by setting all lanes to `value`.
+    fn splat(value: Self::Scalar) -> Self;
+
+    /// Chooses each lane from `true_values` if the lane of `mask` is set, and from
+    /// `false_values` otherwise.
+    fn select(mask: Self::Mask, true_values: Self, false_values: Self) -> Self;
+
+    /// Tests if each lane is equal to the corresponding lane in `other`.
+    fn lanes_eq(self, other: Self) -> Self::Mask;
+
+    /// Tests if each lane is not equal to the corresponding lane in `other`.
+    fn lanes_ne(self, other: Self) -> Self::Mask;
+
+    /// Tests if each lane is less than the corresponding lane in `other`.
+    fn lanes_lt(self, other: Self) -> Self::Mask;
+
+    /// Tests if each lane is greater than the corresponding lane in `other`.
+    fn lanes_gt(self, other: Self) -> Self::Mask;
+
+    /// Tests if each lane is less than or equal to the corresponding lane in `other`.
+    fn lanes_le(self, other: Self) -> Self::Mask;
+
+    /// Tests if each lane is greater than or equal to the corresponding lane in `other`.
+    fn lanes_ge(self, other: Self) -> Self::Mask;
+
+    /// Returns the sum of the lanes.
+    fn horizontal_sum(self) -> Self::Scalar;
+
+    /// Returns the product of the lanes.
+    fn horizontal_product(self) -> Self::Scalar;
+
+    /// Returns the maximum lane.
+    fn horizontal_max(self) -> Self::Scalar;
+
+    /// Returns the minimum lane.
+    fn horizontal_min(self) -> Self::Scalar;
+}
+
+/// Operations on vectors of floats.
+///
+/// This trait is sealed and implemented for `Simd<f32, LANES>` and `Simd<f64, LANES>`.
+///
+/// # Examples
+/// ```
+/// # use core_simd::*;
+/// /// Evaluates `c[0] + c[1] * x + c[2] * x^2 + ...` in each lane.
+/// fn polynomial<V: SimdFloat>(x: V, coefficients: &[V::Scalar]) -> V {
+///     coefficients
+///         .iter()
+///         .rev()
+///         .fold(V::default(), |acc, c| acc * x + V::splat(*c))
+/// }
+///
+/// let x = f32x4::from_array([0., 1., 2., 3.]);
+/// assert_eq!(polynomial(x, &[1., 2., 3.]).to_array(), [1., 6., 17., 34.]);
+/// let x = f64x2::from_array([-1., 0.5]);
+/// assert_eq!(polynomial(x, &[1., 2., 3.]).to_array(), [2., 2.75]);
+/// ```
+pub trait SimdFloat: SimdNum + Neg<Output = Self> {
+    /// The unsigned integer vector with the same bit width.
+    type Bits: SimdUint;
+
+    /// Raw transmutation to an unsigned integer vector.
+    fn to_bits(self) -> Self::Bits;
+
+    /// Raw transmutation from an unsigned integer vector.
+    fn from_bits(bits: Self::Bits) -> Self;
+
+    /// Returns the absolute value of each lane.
+    fn abs(self) -> Self;
+
+    /// Tests if each lane is `NaN`.
+    fn is_nan(self) -> Self::Mask;
+
+    /// Tests if each lane is positive or negative infinity.
+    fn is_infinite(self) -> Self::Mask;
+
+    /// Tests if each lane is neither infinite nor `NaN`.
+    fn is_finite(self) -> Self::Mask;
+
+    /// Tests if each lane has a positive sign, including `+0.0` and `NaN`s with a positive sign
+    /// bit.
+    fn is_sign_positive(self) -> Self::Mask;
+
+    /// Tests if each lane has a negative sign, including `-0.0` and `NaN`s with a negative sign
+    /// bit.
+    fn is_sign_negative(self) -> Self::Mask;
+}
+
+/// Operations on vectors of integers, signed or unsigned.
+///
+/// This trait is sealed and implemented for `Simd<T, LANES>` for every integer `T`.
+pub trait SimdInteger:
+    SimdNum
+    + Eq
+    + Not<Output = Self>
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Shl<Output = Self>
+    + Shr<Output = Self>
+    + BitAndAssign
+    + BitOrAssign
+    + BitXorAssign
+{
+    /// Lanewise wrapping addition.
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    /// Lanewise wrapping subtraction.
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    /// Lanewise wrapping multiplication.
+    fn wrapping_mul(self, rhs: Self) -> Self;
+
+    /// Lanewise saturating addition.
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    /// Lanewise saturating subtraction.
+    fn saturating_sub(self, rhs: Self) -> Self;
+
+    /// Returns the bitwise AND of the lanes.
+    fn horizontal_and(self) -> Self::Scalar;
+
+    /// Returns the bitwise OR of the lanes.
+    fn horizontal_or(self) -> Self::Scalar;
+
+    /// Returns the bitwise XOR of the lanes.
+    fn horizontal_xor(self) -> Self::Scalar;
+}
+
+/// Operations on vectors of signed integers.
+///
+/// This trait is sealed and implemented for `Simd<T, LANES>` for every signed integer `T`.
+pub trait SimdInt: SimdInteger + Neg<Output = Self> {
+    /// The unsigned integer vector with the same bit width.
+    type Unsigned: SimdUint;
+
+    /// Returns the absolute value of each lane, wrapping `MIN` to itself.
+    fn abs(self) -> Self;
+
+    /// Returns the absolute value of each lane, saturating `MIN` to `MAX`.
+    fn saturating_abs(self) -> Self;
+
+    /// Lanewise wrapping negation.
+    fn wrapping_neg(self) -> Self;
+
+    /// Lanewise saturating negation.
+    fn saturating_neg(self) -> Self;
+
+    /// Returns the absolute difference of each pair of lanes, which always fits in the unsigned
+    /// type.
+    fn abs_diff(self, other: Self) -> Self::Unsigned;
+
+    /// Tests if each lane is positive.
+    fn is_positive(self) -> Self::Mask;
+
+    /// Tests if each lane is negative.
+    fn is_negative(self) -> Self::Mask;
+}
+
+/// Operations on vectors of unsigned integers.
+///
+/// This trait is sealed and implemented for `Simd<T, LANES>` for every unsigned integer `T`.
+pub trait SimdUint: SimdInteger {
+    /// Returns the absolute difference of each pair of lanes.
+    fn abs_diff(self, other: Self) -> Self;
+}
+
+macro_rules! impl_num {
+    { $($ty:ty),* } => {
+        $(
+        impl<const LANES: usize> SimdNum for Simd<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Scalar = $ty;
+            type Mask = Mask<<$ty as SimdElement>::Mask, LANES>;
+            const LANES: usize = LANES;
+
+            #[inline]
+            fn splat(value: $ty) -> Self {
+                Self::splat(value)
+            }
+
+            #[inline]
+            fn select(mask: Self::Mask, true_values: Self, false_values: Self) -> Self {
+                mask.select(true_values, false_values)
+            }
+
+            #[inline]
+            fn lanes_eq(self, other: Self) -> Self::Mask {
+                self.lanes_eq(other)
+            }
+
+            #[inline]
+            fn lanes_ne(self, other: Self) -> Self::Mask {
+                self.lanes_ne(other)
+            }
+
+            #[inline]
+            fn lanes_lt(self, other: Self) -> Self::Mask {
+                self.lanes_lt(other)
+            }
+
+            #[inline]
+            fn lanes_gt(self, other: Self) -> Self::Mask {
+                self.lanes_gt(other)
+            }
+
+            #[inline]
+            fn lanes_le(self, other: Self) -> Self::Mask {
+                self.lanes_le(other)
+            }
+
+            #[inline]
+            fn lanes_ge(self, other: Self) -> Self::Mask {
+                self.lanes_ge(other)
+            }
+
+            #[inline]
+            fn horizontal_sum(self) -> $ty {
+                self.horizontal_sum()
+            }
+
+            #[inline]
+            fn horizontal_product(self) -> $ty {
+                self.horizontal_product()
+            }
+
+            #[inline]
+            fn horizontal_max(self) -> $ty {
+                self.horizontal_max()
+            }
+
+            #[inline]
+            fn horizontal_min(self) -> $ty {
+                self.horizontal_min()
+            }
+        }
+        )*
+    }
+}
+
+macro_rules! impl_float {
+    { $($ty:ty => $bits:ty),* } => {
+        $(
+        impl_num! { $ty }
+
+        impl<const LANES: usize> SimdFloat for Simd<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Bits = Simd<$bits, LANES>;
+
+            #[inline]
+            fn to_bits(self) -> Self::Bits {
+                self.to_bits()
+            }
+
+            #[inline]
+            fn from_bits(bits: Self::Bits) -> Self {
+                Self::from_bits(bits)
+            }
+
+            #[inline]
+            fn abs(self) -> Self {
+                self.abs()
+            }
+
+            #[inline]
+            fn is_nan(self) -> Self::Mask {
+                self.is_nan()
+            }
+
+            #[inline]
+            fn is_infinite(self) -> Self::Mask {
+                self.is_infinite()
+            }
+
+            #[inline]
+            fn is_finite(self) -> Self::Mask {
+                self.is_finite()
+            }
+
+            #[inline]
+            fn is_sign_positive(self) -> Self::Mask {
+                self.is_sign_positive()
+            }
+
+            #[inline]
+            fn is_sign_negative(self) -> Self::Mask {
+                self.is_sign_negative()
+            }
+        }
+        )*
+    }
+}
+
+macro_rules! impl_integer {
+    { $($ty:ty),* } => {
+        $(
+        impl_num! { $ty }
+
+        impl<const LANES: usize> SimdInteger for Simd<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn wrapping_add(self, rhs: Self) -> Self {
+                self.wrapping_add(rhs)
+            }
+
+            #[inline]
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                self.wrapping_sub(rhs)
+            }
+
+            #[inline]
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                self.wrapping_mul(rhs)
+            }
+
+            #[inline]
+            fn saturating_add(self, rhs: Self) -> Self {
+                self.saturating_add(rhs)
+            }
+
+            #[inline]
+            fn saturating_sub(self, rhs: Self) -> Self {
+                self.saturating_sub(rhs)
+            }
+
+            #[inline]
+            fn horizontal_and(self) -> $ty {
+                self.horizontal_and()
+            }
+
+            #[inline]
+            fn horizontal_or(self) -> $ty {
+                self.horizontal_or()
+            }
+
+            #[inline]
+            fn horizontal_xor(self) -> $ty {
+                self.horizontal_xor()
+            }
+        }
+        )*
+    }
+}
+
+macro_rules! impl_int {
+    { $($ty:ty => $unsigned:ty),* } => {
+        $(
+        impl_integer! { $ty }
+
+        impl<const LANES: usize> SimdInt for Simd<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Unsigned = Simd<$unsigned, LANES>;
+
+            #[inline]
+            fn abs(self) -> Self {
+                self.abs()
+            }
+
+            #[inline]
+            fn saturating_abs(self) -> Self {
+                self.saturating_abs()
+            }
+
+            #[inline]
+            fn wrapping_neg(self) -> Self {
+                self.wrapping_neg()
+            }
+
+            #[inline]
+            fn saturating_neg(self) -> Self {
+                self.saturating_neg()
+            }
+
+            #[inline]
+            fn abs_diff(self, other: Self) -> Self::Unsigned {
+                self.abs_diff(other)
+            }
+
+            #[inline]
+            fn is_positive(self) -> Self::Mask {
+                self.is_positive()
+            }
+
+            #[inline]
+            fn is_negative(self) -> Self::Mask {
+                self.is_negative()
+            }
+        }
+        )*
+    }
+}
+
+macro_rules! impl_uint {
+    { $($ty:ty),* } => {
+        $(
+        impl_integer! { $ty }
+
+        impl<const LANES: usize> SimdUint for Simd<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn abs_diff(self, other: Self) -> Self {
+                self.abs_diff(other)
+            }
+        }
+        )*
+    }
+}
+
+impl_float! { f32 => u32, f64 => u64 }
+impl_int! { i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize }
+impl_uint! { u8, u16, u32, u64, u128, usize }
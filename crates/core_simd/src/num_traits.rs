@@ -0,0 +1,181 @@
+//! `num-traits` support, so generic numeric code can be instantiated with vectors.
+//!
+//! Only the traits that make sense lanewise are implemented.  Traits that require a total order
+//! or a scalar interpretation of the whole vector, such as `Num` and `Float`, are not.
+
+use crate::{LaneCount, Simd, SimdElement, SupportedLaneCount};
+use core::ops::{Add, Mul};
+use num_traits::float::FloatConst;
+use num_traits::ops::inv::Inv;
+#[cfg(feature = "std")]
+use num_traits::ops::mul_add::MulAdd;
+use num_traits::ops::saturating::{SaturatingAdd, SaturatingSub};
+use num_traits::ops::wrapping::{WrappingAdd, WrappingMul, WrappingNeg, WrappingSub};
+use num_traits::{Bounded, One, Zero};
+
+impl<T, const LANES: usize> Zero for Simd<T, LANES>
+where
+    T: SimdElement + Zero,
+    Self: Add<Output = Self>,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self::splat(T::zero())
+    }
+
+    /// Returns true if every lane is zero.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.as_slice().iter().all(T::is_zero)
+    }
+}
+
+impl<T, const LANES: usize> One for Simd<T, LANES>
+where
+    T: SimdElement + One,
+    Self: Mul<Output = Self>,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn one() -> Self {
+        Self::splat(T::one())
+    }
+}
+
+impl<T, const LANES: usize> Bounded for Simd<T, LANES>
+where
+    T: SimdElement + Bounded,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn min_value() -> Self {
+        Self::splat(T::min_value())
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Self::splat(T::max_value())
+    }
+}
+
+/// The wrapping traits forward to the inherent wrapping methods.
+macro_rules! impl_integer_traits {
+    { $($type:ty),* } => {
+        $(
+        impl<const LANES: usize> WrappingAdd for Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn wrapping_add(&self, v: &Self) -> Self {
+                Self::wrapping_add(*self, *v)
+            }
+        }
+
+        impl<const LANES: usize> WrappingSub for Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn wrapping_sub(&self, v: &Self) -> Self {
+                Self::wrapping_sub(*self, *v)
+            }
+        }
+
+        impl<const LANES: usize> WrappingMul for Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn wrapping_mul(&self, v: &Self) -> Self {
+                Self::wrapping_mul(*self, *v)
+            }
+        }
+
+        impl<const LANES: usize> WrappingNeg for Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn wrapping_neg(&self) -> Self {
+                Self::wrapping_sub(Self::splat(0), *self)
+            }
+        }
+
+        impl<const LANES: usize> SaturatingAdd for Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn saturating_add(&self, v: &Self) -> Self {
+                Self::saturating_add(*self, *v)
+            }
+        }
+
+        impl<const LANES: usize> SaturatingSub for Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn saturating_sub(&self, v: &Self) -> Self {
+                Self::saturating_sub(*self, *v)
+            }
+        }
+        )*
+    }
+}
+
+impl_integer_traits! { u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize }
+
+macro_rules! impl_float_traits {
+    { $($type:ident),* } => {
+        $(
+        impl<const LANES: usize> Inv for Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn inv(self) -> Self {
+                Self::splat(1.) / self
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<const LANES: usize> MulAdd for Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                crate::StdFloat::mul_add(self, a, b)
+            }
+        }
+
+        impl<const LANES: usize> FloatConst for Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            impl_float_traits! { @consts $type,
+                E, FRAC_1_PI, FRAC_1_SQRT_2, FRAC_2_PI, FRAC_2_SQRT_PI, FRAC_PI_2, FRAC_PI_3,
+                FRAC_PI_4, FRAC_PI_6, FRAC_PI_8, LN_10, LN_2, LOG10_E, LOG2_E, PI, SQRT_2,
+                TAU, LOG10_2, LOG2_10
+            }
+        }
+        )*
+    };
+    { @consts $type:ident, $($constant:ident),* } => {
+        $(
+        #[inline]
+        fn $constant() -> Self {
+            Self::splat(<$type as FloatConst>::$constant())
+        }
+        )*
+    };
+}
+
+impl_float_traits! { f32, f64 }
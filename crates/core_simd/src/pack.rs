@@ -0,0 +1,90 @@
+//! Narrowing two vectors into one vector of half-width lanes, with saturation.
+
+use crate::{Simd, SimdElement};
+
+macro_rules! impl_pack {
+    { $wide:ty => $signed:ty, $unsigned:ty; $($lanes:literal => $packed:literal, $fn:ident;)* } => {
+        $(
+        impl Simd<$wide, $lanes> {
+            /// Clamps each lane to `min..=max`, narrows it to `T`, and concatenates the lanes of
+            /// `self` and `other`.
+            #[inline]
+            fn pack_clamped<T: SimdElement>(self, other: Self, min: $wide, max: $wide) -> Simd<T, $packed> {
+                const IDX: [u32; $packed] = {
+                    let mut idx = [0u32; $packed];
+                    let mut i = 0;
+                    while i < $packed {
+                        idx[i] = i as u32;
+                        i += 1;
+                    }
+                    idx
+                };
+                let (min, max) = (Self::splat(min), Self::splat(max));
+                let clamp = |x: Self| x.lanes_lt(min).select(min, x.lanes_gt(max).select(max, x));
+                unsafe {
+                    let lo: Simd<T, $lanes> = crate::intrinsics::simd_cast(clamp(self));
+                    let hi: Simd<T, $lanes> = crate::intrinsics::simd_cast(clamp(other));
+                    crate::intrinsics::$fn(lo, hi, IDX)
+                }
+            }
+
+            /// Narrows the lanes of `self` and `other` to half their width, saturating lanes
+            /// that are out of range, and concatenates them.
+            ///
+            /// The first half of the result comes from `self` and the second half from `other`.
+            /// This matches the `packss` instructions on x86 for 128-bit vectors, but wider x86
+            /// instructions interleave the two vectors in 128-bit blocks instead.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = Simd::<", stringify!($wide), ", 2>::from_array([", stringify!($wide), "::MIN, -1]);")]
+            #[doc = concat!("let b = Simd::<", stringify!($wide), ", 2>::from_array([1, ", stringify!($wide), "::MAX]);")]
+            #[doc = concat!("assert_eq!(a.saturating_pack(b).to_array(), [", stringify!($signed), "::MIN, -1, 1, ", stringify!($signed), "::MAX]);")]
+            /// ```
+            #[inline]
+            pub fn saturating_pack(self, other: Self) -> Simd<$signed, $packed> {
+                self.pack_clamped(other, <$signed>::MIN as $wide, <$signed>::MAX as $wide)
+            }
+
+            /// Narrows the lanes of `self` and `other` to unsigned integers of half their width,
+            /// saturating lanes that are out of range, and concatenates them.
+            ///
+            /// Negative lanes become zero.  The lanes are ordered as in
+            /// [`saturating_pack`](Self::saturating_pack), which matches the `packus`
+            /// instructions on x86 for 128-bit vectors.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = Simd::<", stringify!($wide), ", 2>::from_array([", stringify!($wide), "::MIN, -1]);")]
+            #[doc = concat!("let b = Simd::<", stringify!($wide), ", 2>::from_array([1, ", stringify!($wide), "::MAX]);")]
+            #[doc = concat!("assert_eq!(a.saturating_pack_unsigned(b).to_array(), [0, 0, 1, ", stringify!($unsigned), "::MAX]);")]
+            /// ```
+            #[inline]
+            pub fn saturating_pack_unsigned(self, other: Self) -> Simd<$unsigned, $packed> {
+                self.pack_clamped(other, 0, <$unsigned>::MAX as $wide)
+            }
+        }
+        )*
+    }
+}
+
+macro_rules! impl_pack_all_lanes {
+    { $($wide:ty => $signed:ty, $unsigned:ty);* } => {
+        $(
+        impl_pack! {
+            $wide => $signed, $unsigned;
+            1 => 2, simd_shuffle2;
+            2 => 4, simd_shuffle4;
+            4 => 8, simd_shuffle8;
+            8 => 16, simd_shuffle16;
+            16 => 32, simd_shuffle32;
+            32 => 64, simd_shuffle64;
+            64 => 128, simd_shuffle128;
+        }
+        )*
+    }
+}
+
+impl_pack_all_lanes! { i16 => i8, u8; i32 => i16, u16; i64 => i32, u32 }
@@ -1,6 +1,236 @@
+/// Shuffle indices that shift the lanes of the first vector toward the end by `k` lanes, filling
+/// the start with the first lane of the second vector.
+const fn shift_lanes_up<const N: usize>(k: usize) -> [u32; N] {
+    let mut idx = [0u32; N];
+    let mut i = 0;
+    while i < N {
+        idx[i] = if i >= k { i - k } else { N } as u32;
+        i += 1;
+    }
+    idx
+}
+
+/// Shuffle indices that exchange each lane with the lane whose index differs in bit `j`.  Lanes
+/// without a partner in range are left in place.
+const fn xor_lanes<const N: usize>(j: usize) -> [u32; N] {
+    let mut idx = [0u32; N];
+    let mut i = 0;
+    while i < N {
+        idx[i] = if i ^ j < N { i ^ j } else { i } as u32;
+        i += 1;
+    }
+    idx
+}
+
+/// Whether each lane has an even index.
+const fn even_lanes<const N: usize>() -> [bool; N] {
+    let mut even = [false; N];
+    let mut i = 0;
+    while i < N {
+        even[i] = i % 2 == 0;
+        i += 1;
+    }
+    even
+}
+
+/// For the bitonic sorting step comparing lanes `j` apart within blocks of `k` lanes, whether each
+/// lane should keep the smaller of itself and its partner.
+const fn keeps_smaller<const N: usize>(k: usize, j: usize) -> [bool; N] {
+    let mut keep = [false; N];
+    let mut i = 0;
+    while i < N {
+        keep[i] = (i & j == 0) == (i & k == 0);
+        i += 1;
+    }
+    keep
+}
+
+/// Shuffle indices that take lane `i` of stream `s` of three interleaved streams, element
+/// `3 * i + s` of three consecutive vectors, from the first two vectors.  Lanes in the third
+/// vector are filled in by [`stream3_last`].
+const fn stream3_first<const N: usize>(s: usize) -> [u32; N] {
+    let mut idx = [0u32; N];
+    let mut i = 0;
+    while i < N {
+        idx[i] = if 3 * i + s < 2 * N { 3 * i + s } else { 0 } as u32;
+        i += 1;
+    }
+    idx
+}
+
+/// Shuffle indices that complete [`stream3_first`] with the lanes of stream `s` in the third
+/// vector, passed as the second shuffle operand.
+const fn stream3_last<const N: usize>(s: usize) -> [u32; N] {
+    let mut idx = [0u32; N];
+    let mut i = 0;
+    while i < N {
+        idx[i] = if 3 * i + s < 2 * N { i } else { 3 * i + s - N } as u32;
+        i += 1;
+    }
+    idx
+}
+
+/// Shuffle indices that take lane `j` of vector `k` of three streams written interleaved,
+/// element `k * N + j` of the interleaved lanes, from the first two streams.  Lanes of the third
+/// stream are filled in by [`interleave3_last`].
+const fn interleave3_first<const N: usize>(k: usize) -> [u32; N] {
+    let mut idx = [0u32; N];
+    let mut i = 0;
+    while i < N {
+        let element = k * N + i;
+        idx[i] = match element % 3 {
+            0 => element / 3,
+            1 => N + element / 3,
+            _ => 0,
+        } as u32;
+        i += 1;
+    }
+    idx
+}
+
+/// Shuffle indices that complete [`interleave3_first`] with the lanes of the third stream,
+/// passed as the second shuffle operand.
+const fn interleave3_last<const N: usize>(k: usize) -> [u32; N] {
+    let mut idx = [0u32; N];
+    let mut i = 0;
+    while i < N {
+        let element = k * N + i;
+        idx[i] = if element % 3 == 2 { N + element / 3 } else { i } as u32;
+        i += 1;
+    }
+    idx
+}
+
+/// Converts `$x`, a `Simd<T, LANES>` or an array of `$len` of them, to the type with the
+/// matching number of lanes, binds it to `$v` and evaluates `$body`, or evaluates `$default` for
+/// a single lane.
+///
+/// The shuffle intrinsics only exist for each lane count, so functions generic over the number
+/// of lanes dispatch through this.  The transmutes are between identical types.
+macro_rules! dispatch_lanes {
+    { $x:expr, $v:ident => $body:expr, _ => $default:expr } => {
+        dispatch_lanes! {
+            @ $x, $v => $body, $default,
+            (2, crate::Simd<T, 2>), (4, crate::Simd<T, 4>), (8, crate::Simd<T, 8>),
+            (16, crate::Simd<T, 16>), (32, crate::Simd<T, 32>), (64, crate::Simd<T, 64>),
+            (128, crate::Simd<T, 128>)
+        }
+    };
+    { $x:expr, $v:ident: [_; $len:expr] => $body:expr, _ => $default:expr } => {
+        dispatch_lanes! {
+            @ $x, $v => $body, $default,
+            (2, [crate::Simd<T, 2>; $len]), (4, [crate::Simd<T, 4>; $len]),
+            (8, [crate::Simd<T, 8>; $len]), (16, [crate::Simd<T, 16>; $len]),
+            (32, [crate::Simd<T, 32>; $len]), (64, [crate::Simd<T, 64>; $len]),
+            (128, [crate::Simd<T, 128>; $len])
+        }
+    };
+    { @ $x:expr, $v:ident => $body:expr, $default:expr, $(($n:literal, $ty:ty)),* } => {
+        match LANES {
+            $(
+            $n => unsafe {
+                let $v: $ty = core::mem::transmute_copy(&$x);
+                $body
+            }
+            )*
+            _ => $default,
+        }
+    };
+}
+
+/// Reduces the lanes of a vector with `f` as a balanced tree, using
+/// [`fold_halves`](crate::Simd::fold_halves).
+#[inline]
+pub(crate) fn reduce_tree<T, const LANES: usize>(
+    x: crate::Simd<T, LANES>,
+    f: impl Fn(crate::Simd<T, LANES>, crate::Simd<T, LANES>) -> crate::Simd<T, LANES>,
+) -> T
+where
+    T: crate::SimdElement,
+    crate::LaneCount<LANES>: crate::SupportedLaneCount,
+{
+    reduce_lanes(x, LANES, f)
+}
+
+/// Reduces the first `lanes` lanes of a vector with `f` as a balanced tree, where `lanes` is a
+/// power of two no greater than `LANES`.
+///
+/// After a first step done by hand on [`rotate_half`], only the lower half is left to reduce.
+#[inline]
+pub(crate) fn reduce_lanes<T, const LANES: usize>(
+    x: crate::Simd<T, LANES>,
+    lanes: usize,
+    f: impl Fn(crate::Simd<T, LANES>, crate::Simd<T, LANES>) -> crate::Simd<T, LANES>,
+) -> T
+where
+    T: crate::SimdElement,
+    crate::LaneCount<LANES>: crate::SupportedLaneCount,
+{
+    use core::mem::transmute_copy;
+    dispatch_lanes! {
+        x,
+        v => v.fold_halves(lanes, |a, b| transmute_copy(&f(transmute_copy(&a), transmute_copy(&b)))),
+        _ => x[0]
+    }
+}
+
+/// Rotates the upper half of the lanes onto the lower half, and the lower half onto the upper
+/// half.  A single lane is returned unchanged.
+#[inline]
+pub(crate) fn rotate_half<T, const LANES: usize>(x: crate::Simd<T, LANES>) -> crate::Simd<T, LANES>
+where
+    T: crate::SimdElement,
+    crate::LaneCount<LANES>: crate::SupportedLaneCount,
+{
+    dispatch_lanes! {
+        x,
+        v => core::mem::transmute_copy(&v.rotate_half()),
+        _ => x
+    }
+}
+
+/// Deinterleaves `N` vectors loaded from consecutive memory into `N` streams, so lane `i` of
+/// stream `s` is lane `i * N + s` of the concatenated vectors.  Returns `None` if there is no
+/// shuffle sequence for `N` streams.
+#[inline]
+pub(crate) fn deinterleave_streams<T, const LANES: usize, const N: usize>(
+    vectors: [crate::Simd<T, LANES>; N],
+) -> Option<[crate::Simd<T, LANES>; N]>
+where
+    T: crate::SimdElement,
+    crate::LaneCount<LANES>: crate::SupportedLaneCount,
+{
+    dispatch_lanes! {
+        vectors,
+        v: [_; N] => crate::Simd::deinterleave_streams(v).map(|s| core::mem::transmute_copy(&s)),
+        // With a single lane, the vectors already hold one element of each stream.
+        _ => Some(vectors)
+    }
+}
+
+/// Interleaves `N` streams into `N` vectors to be stored to consecutive memory.  This is the
+/// inverse of [`deinterleave_streams`], and returns `None` for the same `N`.
+#[inline]
+pub(crate) fn interleave_streams<T, const LANES: usize, const N: usize>(
+    streams: [crate::Simd<T, LANES>; N],
+) -> Option<[crate::Simd<T, LANES>; N]>
+where
+    T: crate::SimdElement,
+    crate::LaneCount<LANES>: crate::SupportedLaneCount,
+{
+    dispatch_lanes! {
+        streams,
+        v: [_; N] => crate::Simd::interleave_streams(v).map(|s| core::mem::transmute_copy(&s)),
+        _ => Some(streams)
+    }
+}
+
 macro_rules! impl_shuffle_lane {
-    { $name:ident, $fn:ident, $n:literal } => {
-        impl $name<$n> {
+    { $fn:ident, $n:literal } => {
+        impl<T> crate::Simd<T, $n>
+        where
+            T: crate::SimdElement,
+        {
             /// A const SIMD shuffle that takes 2 SIMD vectors and produces another vector, using
             /// the indices in the const parameter. The first or "self" vector will have its lanes
             /// indexed from 0, and the second vector will have its first lane indexed at $n.
@@ -14,19 +244,37 @@ macro_rules! impl_shuffle_lane {
                 unsafe { crate::intrinsics::$fn(self, second, IDX) }
             }
 
+            /// Rearranges the lanes of the vector according to a [`Swizzle`](crate::Swizzle)
+            /// pattern.
+            ///
+            /// ```
+            /// # use core_simd::{RotateLanesLeft, SimdU32};
+            /// let a = SimdU32::from_array([0, 1, 2, 3]);
+            /// assert_eq!(a.swizzle::<RotateLanesLeft<1>>().to_array(), [1, 2, 3, 0]);
+            /// ```
+            #[inline]
+            pub fn swizzle<S: crate::Swizzle<$n>>(self) -> Self {
+                unsafe { crate::intrinsics::$fn(self, self, S::INDEX) }
+            }
+
+            /// Selects lanes from two vectors according to a [`Swizzle`](crate::Swizzle)
+            /// pattern, indexing the lanes of `second` after the lanes of `self`.
+            ///
+            /// ```
+            /// # use core_simd::{InterleaveLo, SimdU32};
+            /// let a = SimdU32::from_array([0, 1, 2, 3]);
+            /// let b = SimdU32::from_array([4, 5, 6, 7]);
+            /// assert_eq!(a.swizzle2::<InterleaveLo>(b).to_array(), [0, 4, 1, 5]);
+            /// ```
+            #[inline]
+            pub fn swizzle2<S: crate::Swizzle<$n>>(self, second: Self) -> Self {
+                unsafe { crate::intrinsics::$fn(self, second, S::INDEX) }
+            }
+
             /// Reverse the order of the lanes in the vector.
             #[inline]
             pub fn reverse(self) -> Self {
-                const fn idx() -> [u32; $n] {
-                    let mut idx = [0u32; $n];
-                    let mut i = 0;
-                    while i < $n {
-                        idx[i] = ($n - i - 1) as u32;
-                        i += 1;
-                    }
-                    idx
-                }
-                self.shuffle::<{ idx() }>(self)
+                self.swizzle::<crate::Reverse>()
             }
 
             /// Interleave two vectors.
@@ -51,35 +299,10 @@ macro_rules! impl_shuffle_lane {
             /// ```
             #[inline]
             pub fn interleave(self, other: Self) -> (Self, Self) {
-                const fn lo() -> [u32; $n] {
-                    let mut idx = [0u32; $n];
-                    let mut i = 0;
-                    while i < $n {
-                        let offset = i / 2;
-                        idx[i] = if i % 2 == 0 {
-                            offset
-                        } else {
-                            $n + offset
-                        } as u32;
-                        i += 1;
-                    }
-                    idx
-                }
-                const fn hi() -> [u32; $n] {
-                    let mut idx = [0u32; $n];
-                    let mut i = 0;
-                    while i < $n {
-                        let offset = ($n + i) / 2;
-                        idx[i] = if i % 2 == 0 {
-                            offset
-                        } else {
-                            $n + offset
-                        } as u32;
-                        i += 1;
-                    }
-                    idx
-                }
-                (self.shuffle::<{ lo() }>(other), self.shuffle::<{ hi() }>(other))
+                (
+                    self.swizzle2::<crate::InterleaveLo>(other),
+                    self.swizzle2::<crate::InterleaveHi>(other),
+                )
             }
 
             /// Deinterleave two vectors.
@@ -122,16 +345,397 @@ macro_rules! impl_shuffle_lane {
                 }
                 (self.shuffle::<{ even() }>(other), self.shuffle::<{ odd() }>(other))
             }
+
+            /// Deinterleaves `N` vectors loaded from consecutive memory into `N` streams.  See
+            /// [`deinterleave_streams`].
+            #[inline]
+            pub(crate) fn deinterleave_streams<const N: usize>(vectors: [Self; N]) -> Option<[Self; N]> {
+                use core::mem::transmute_copy;
+                // Each arm transmutes between `[Self; N]` and an array of the matched length.
+                unsafe {
+                    match N {
+                        1 => Some(vectors),
+                        2 => {
+                            let [a, b]: [Self; 2] = transmute_copy(&vectors);
+                            let (x, y) = a.deinterleave(b);
+                            Some(transmute_copy(&[x, y]))
+                        }
+                        3 => {
+                            let [a, b, c]: [Self; 3] = transmute_copy(&vectors);
+                            let x = a.shuffle::<{ stream3_first::<$n>(0) }>(b).shuffle::<{ stream3_last::<$n>(0) }>(c);
+                            let y = a.shuffle::<{ stream3_first::<$n>(1) }>(b).shuffle::<{ stream3_last::<$n>(1) }>(c);
+                            let z = a.shuffle::<{ stream3_first::<$n>(2) }>(b).shuffle::<{ stream3_last::<$n>(2) }>(c);
+                            Some(transmute_copy(&[x, y, z]))
+                        }
+                        4 => {
+                            // The even lanes hold streams 0 and 2, and the odd lanes 1 and 3.
+                            let [a, b, c, d]: [Self; 4] = transmute_copy(&vectors);
+                            let (ab_even, ab_odd) = a.deinterleave(b);
+                            let (cd_even, cd_odd) = c.deinterleave(d);
+                            let (w, y) = ab_even.deinterleave(cd_even);
+                            let (x, z) = ab_odd.deinterleave(cd_odd);
+                            Some(transmute_copy(&[w, x, y, z]))
+                        }
+                        _ => None,
+                    }
+                }
+            }
+
+            /// Interleaves `N` streams into `N` vectors to be stored to consecutive memory.  See
+            /// [`interleave_streams`].
+            #[inline]
+            pub(crate) fn interleave_streams<const N: usize>(streams: [Self; N]) -> Option<[Self; N]> {
+                use core::mem::transmute_copy;
+                // Each arm transmutes between `[Self; N]` and an array of the matched length.
+                unsafe {
+                    match N {
+                        1 => Some(streams),
+                        2 => {
+                            let [x, y]: [Self; 2] = transmute_copy(&streams);
+                            let (a, b) = x.interleave(y);
+                            Some(transmute_copy(&[a, b]))
+                        }
+                        3 => {
+                            let [x, y, z]: [Self; 3] = transmute_copy(&streams);
+                            let a = x.shuffle::<{ interleave3_first::<$n>(0) }>(y).shuffle::<{ interleave3_last::<$n>(0) }>(z);
+                            let b = x.shuffle::<{ interleave3_first::<$n>(1) }>(y).shuffle::<{ interleave3_last::<$n>(1) }>(z);
+                            let c = x.shuffle::<{ interleave3_first::<$n>(2) }>(y).shuffle::<{ interleave3_last::<$n>(2) }>(z);
+                            Some(transmute_copy(&[a, b, c]))
+                        }
+                        4 => {
+                            let [w, x, y, z]: [Self; 4] = transmute_copy(&streams);
+                            let (even_lo, even_hi) = w.interleave(y);
+                            let (odd_lo, odd_hi) = x.interleave(z);
+                            let (a, b) = even_lo.interleave(odd_lo);
+                            let (c, d) = even_hi.interleave(odd_hi);
+                            Some(transmute_copy(&[a, b, c, d]))
+                        }
+                        _ => None,
+                    }
+                }
+            }
+
+            /// Add adjacent pairs of lanes.
+            ///
+            /// The first half of the result contains the sums of adjacent pairs of lanes of
+            /// `self`, and the second half the sums for `other`.  Unlike `+`, integer sums wrap on
+            /// overflow even with debug assertions enabled.
+            ///
+            /// This is the `hadd` operation on x86 for 128-bit vectors.
+            ///
+            /// ```
+            /// # use core_simd::SimdF32;
+            /// let a = SimdF32::from_array([1., 2., 3., 4.]);
+            /// let b = SimdF32::from_array([10., 20., 30., 40.]);
+            /// assert_eq!(a.horizontal_pairwise_add(b).to_array(), [3., 7., 30., 70.]);
+            /// ```
+            #[inline]
+            pub fn horizontal_pairwise_add(self, other: Self) -> Self
+            where
+                Self: core::ops::Add<Output = Self>,
+            {
+                let (even, odd) = self.deinterleave(other);
+                // The `Add` bound restricts this to numeric lanes, and unlike `+` the intrinsic
+                // wraps integer sums in debug builds too.
+                unsafe { crate::intrinsics::simd_add(even, odd) }
+            }
+
+            /// Inclusive prefix sum of the lanes.
+            ///
+            /// Each lane of the result is the sum of the lanes of `self` up to and including that
+            /// lane.  This takes `log2(LANES)` shuffles and additions, adding lanes in a tree
+            /// rather than in order, so the rounding of floating-point sums may differ from a
+            /// sequential sum.  Unlike `+`, integer sums wrap on overflow even with debug
+            /// assertions enabled.
+            ///
+            /// ```
+            /// # use core_simd::SimdU32;
+            /// let a = SimdU32::from_array([1, 2, 3, 4]);
+            /// assert_eq!(a.prefix_sum().to_array(), [1, 3, 6, 10]);
+            /// ```
+            #[inline]
+            pub fn prefix_sum(self) -> Self
+            where
+                T: Default,
+                Self: core::ops::Add<Output = Self>,
+            {
+                // As in `horizontal_pairwise_add`, the intrinsic wraps integer sums.
+                let zero = Self::splat(T::default());
+                let mut sum = self;
+                if $n > 1 {
+                    sum = unsafe { crate::intrinsics::simd_add(sum, sum.shuffle::<{ shift_lanes_up::<$n>(1) }>(zero)) };
+                }
+                if $n > 2 {
+                    sum = unsafe { crate::intrinsics::simd_add(sum, sum.shuffle::<{ shift_lanes_up::<$n>(2) }>(zero)) };
+                }
+                if $n > 4 {
+                    sum = unsafe { crate::intrinsics::simd_add(sum, sum.shuffle::<{ shift_lanes_up::<$n>(4) }>(zero)) };
+                }
+                if $n > 8 {
+                    sum = unsafe { crate::intrinsics::simd_add(sum, sum.shuffle::<{ shift_lanes_up::<$n>(8) }>(zero)) };
+                }
+                if $n > 16 {
+                    sum = unsafe { crate::intrinsics::simd_add(sum, sum.shuffle::<{ shift_lanes_up::<$n>(16) }>(zero)) };
+                }
+                if $n > 32 {
+                    sum = unsafe { crate::intrinsics::simd_add(sum, sum.shuffle::<{ shift_lanes_up::<$n>(32) }>(zero)) };
+                }
+                if $n > 64 {
+                    sum = unsafe { crate::intrinsics::simd_add(sum, sum.shuffle::<{ shift_lanes_up::<$n>(64) }>(zero)) };
+                }
+                sum
+            }
+
+            /// Exclusive prefix sum of the lanes.
+            ///
+            /// Each lane of the result is the sum of the lanes of `self` before that lane, so the
+            /// first lane is zero.  See [`prefix_sum`](Self::prefix_sum).
+            ///
+            /// ```
+            /// # use core_simd::SimdU32;
+            /// let a = SimdU32::from_array([1, 2, 3, 4]);
+            /// assert_eq!(a.exclusive_prefix_sum().to_array(), [0, 1, 3, 6]);
+            /// ```
+            #[inline]
+            pub fn exclusive_prefix_sum(self) -> Self
+            where
+                T: Default,
+                Self: core::ops::Add<Output = Self>,
+            {
+                self.prefix_sum().shuffle::<{ shift_lanes_up::<$n>(1) }>(Self::splat(T::default()))
+            }
+
+            /// Rotates the upper half of the lanes onto the lower half, and the lower half onto
+            /// the upper half.
+            #[inline]
+            pub(crate) fn rotate_half(self) -> Self {
+                self.shuffle::<{ crate::swizzle::rotate_left::<$n>($n / 2) }>(self)
+            }
+
+            /// Combines the first `lanes` lanes, a power of two, as a balanced tree: each step
+            /// rotates the upper half of the remaining lanes onto the lower half and combines
+            /// them with `f`, for `log2(lanes)` steps.  Returns the first lane.
+            #[inline]
+            pub(crate) fn fold_halves(self, lanes: usize, f: impl Fn(Self, Self) -> Self) -> T {
+                let mut x = self;
+                if lanes > 64 {
+                    x = f(x, x.shuffle::<{ crate::swizzle::rotate_left::<$n>(64) }>(x));
+                }
+                if lanes > 32 {
+                    x = f(x, x.shuffle::<{ crate::swizzle::rotate_left::<$n>(32) }>(x));
+                }
+                if lanes > 16 {
+                    x = f(x, x.shuffle::<{ crate::swizzle::rotate_left::<$n>(16) }>(x));
+                }
+                if lanes > 8 {
+                    x = f(x, x.shuffle::<{ crate::swizzle::rotate_left::<$n>(8) }>(x));
+                }
+                if lanes > 4 {
+                    x = f(x, x.shuffle::<{ crate::swizzle::rotate_left::<$n>(4) }>(x));
+                }
+                if lanes > 2 {
+                    x = f(x, x.shuffle::<{ crate::swizzle::rotate_left::<$n>(2) }>(x));
+                }
+                if lanes > 1 {
+                    x = f(x, x.shuffle::<{ crate::swizzle::rotate_left::<$n>(1) }>(x));
+                }
+                x[0]
+            }
+
+            /// One step of a sorting network: exchanges each lane with its partner in `PARTNER`
+            /// if the pair is out of order.  Lanes set in `SMALLER` keep the smaller value.
+            ///
+            /// Both lanes of a pair test the same comparison, so unordered values such as `NaN`
+            /// are never duplicated or lost.
+            #[inline]
+            fn compare_exchange<const PARTNER: [u32; $n], const SMALLER: [bool; $n]>(self) -> Self
+            where
+                T: PartialOrd,
+            {
+                let partner = self.shuffle::<PARTNER>(self);
+                let smaller = crate::Mask::from_array(SMALLER);
+                let swap = (smaller & self.lanes_gt(partner)) | (!smaller & self.lanes_lt(partner));
+                swap.select(partner, self)
+            }
+
+            /// Sorts the lanes in ascending order.
+            ///
+            /// This uses a bitonic sorting network, a fixed sequence of shuffles and lanewise
+            /// comparisons chosen for the number of lanes at compile time, so it has no branches
+            /// and takes the same time for any input.  Sorting `LANES` lanes takes
+            /// `log2(LANES) * (log2(LANES) + 1) / 2` steps.  The sort isn't stable, so lanes that
+            /// compare equal, such as `0.` and `-0.`, may be reordered.  If any lane is `NaN`,
+            /// the result is a permutation of the lanes in an unspecified order.
+            ///
+            /// ```
+            /// # use core_simd::SimdI32;
+            /// let a = SimdI32::from_array([3, -1, 4, 1, -5, 9, 2, 6]);
+            /// assert_eq!(a.sorted().to_array(), [-5, -1, 1, 2, 3, 4, 6, 9]);
+            /// ```
+            #[inline]
+            pub fn sorted(self) -> Self
+            where
+                T: PartialOrd,
+            {
+                let mut v = self;
+                if $n > 2 {
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(1) }, { keeps_smaller::<$n>(2, 1) }>();
+                }
+                if $n > 4 {
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(2) }, { keeps_smaller::<$n>(4, 2) }>();
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(1) }, { keeps_smaller::<$n>(4, 1) }>();
+                }
+                if $n > 8 {
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(4) }, { keeps_smaller::<$n>(8, 4) }>();
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(2) }, { keeps_smaller::<$n>(8, 2) }>();
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(1) }, { keeps_smaller::<$n>(8, 1) }>();
+                }
+                if $n > 16 {
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(8) }, { keeps_smaller::<$n>(16, 8) }>();
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(4) }, { keeps_smaller::<$n>(16, 4) }>();
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(2) }, { keeps_smaller::<$n>(16, 2) }>();
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(1) }, { keeps_smaller::<$n>(16, 1) }>();
+                }
+                if $n > 32 {
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(16) }, { keeps_smaller::<$n>(32, 16) }>();
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(8) }, { keeps_smaller::<$n>(32, 8) }>();
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(4) }, { keeps_smaller::<$n>(32, 4) }>();
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(2) }, { keeps_smaller::<$n>(32, 2) }>();
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(1) }, { keeps_smaller::<$n>(32, 1) }>();
+                }
+                if $n > 64 {
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(32) }, { keeps_smaller::<$n>(64, 32) }>();
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(16) }, { keeps_smaller::<$n>(64, 16) }>();
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(8) }, { keeps_smaller::<$n>(64, 8) }>();
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(4) }, { keeps_smaller::<$n>(64, 4) }>();
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(2) }, { keeps_smaller::<$n>(64, 2) }>();
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(1) }, { keeps_smaller::<$n>(64, 1) }>();
+                }
+                // The last stage, over blocks of every lane, is a bitonic merge.
+                v.bitonic_merge()
+            }
+
+            /// Sorts a bitonic sequence of lanes, one that increases and then decreases, in
+            /// ascending order.  This is the last stage of [`sorted`](Self::sorted), and takes
+            /// `log2(LANES)` steps.
+            #[inline]
+            pub(crate) fn bitonic_merge(self) -> Self
+            where
+                T: PartialOrd,
+            {
+                let mut v = self;
+                if $n >= 128 {
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(64) }, { keeps_smaller::<$n>($n, 64) }>();
+                }
+                if $n >= 64 {
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(32) }, { keeps_smaller::<$n>($n, 32) }>();
+                }
+                if $n >= 32 {
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(16) }, { keeps_smaller::<$n>($n, 16) }>();
+                }
+                if $n >= 16 {
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(8) }, { keeps_smaller::<$n>($n, 8) }>();
+                }
+                if $n >= 8 {
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(4) }, { keeps_smaller::<$n>($n, 4) }>();
+                }
+                if $n >= 4 {
+                    v = v.compare_exchange::<{ xor_lanes::<$n>(2) }, { keeps_smaller::<$n>($n, 2) }>();
+                }
+                v.compare_exchange::<{ xor_lanes::<$n>(1) }, { keeps_smaller::<$n>($n, 1) }>()
+            }
+
+            /// Sorts the lanes in descending order.  See [`sorted`](Self::sorted).
+            ///
+            /// ```
+            /// # use core_simd::SimdI32;
+            /// let a = SimdI32::from_array([3, -1, 4, 1]);
+            /// assert_eq!(a.sorted_descending().to_array(), [4, 3, 1, -1]);
+            /// ```
+            #[inline]
+            pub fn sorted_descending(self) -> Self
+            where
+                T: PartialOrd,
+            {
+                self.sorted().reverse()
+            }
+
+            /// Transposes a square matrix stored as one vector per row.
+            ///
+            /// Lane `j` of row `i` of the result is lane `i` of row `j` of `rows`.  This converts
+            /// a tile of interleaved structures to one vector per field, and back.  It takes
+            /// `log2(LANES)` rounds of [`interleave`](Self::interleave), each pairing the rows of
+            /// the first half with the rows of the second half.
+            ///
+            /// ```
+            /// # use core_simd::SimdF32;
+            /// let rows = [
+            ///     SimdF32::from_array([0., 1., 2., 3.]),
+            ///     SimdF32::from_array([4., 5., 6., 7.]),
+            ///     SimdF32::from_array([8., 9., 10., 11.]),
+            ///     SimdF32::from_array([12., 13., 14., 15.]),
+            /// ];
+            /// let columns = SimdF32::<4>::transpose(rows);
+            /// assert_eq!(columns[0].to_array(), [0., 4., 8., 12.]);
+            /// assert_eq!(columns[3].to_array(), [3., 7., 11., 15.]);
+            /// ```
+            #[inline]
+            pub fn transpose(rows: [Self; $n]) -> [Self; $n] {
+                // Each round rotates the bits of the flattened lane index, `row * LANES + lane`,
+                // by one, so after `log2(LANES)` rounds the row and lane bits have swapped.
+                let mut rows = rows;
+                let mut rounds = 1;
+                while rounds < $n {
+                    let mut next = rows;
+                    for i in 0..$n / 2 {
+                        let (lo, hi) = rows[i].interleave(rows[i + $n / 2]);
+                        next[2 * i] = lo;
+                        next[2 * i + 1] = hi;
+                    }
+                    rows = next;
+                    rounds *= 2;
+                }
+                rows
+            }
         }
-    }
-}
 
-macro_rules! impl_shuffle_2pow_lanes {
-    { $name:ident } => {
-        impl_shuffle_lane!{ $name, simd_shuffle2, 2 }
-        impl_shuffle_lane!{ $name, simd_shuffle4, 4 }
-        impl_shuffle_lane!{ $name, simd_shuffle8, 8 }
-        impl_shuffle_lane!{ $name, simd_shuffle16, 16 }
-        impl_shuffle_lane!{ $name, simd_shuffle32, 32 }
-    }
+        impl_shuffle_lane! { @float $n, f32 }
+        impl_shuffle_lane! { @float $n, f64 }
+    };
+    { @float $n:literal, $scalar:ty } => {
+        impl crate::Simd<$scalar, $n> {
+            /// Multiply complex numbers stored as interleaved real and imaginary parts.
+            ///
+            /// Each pair of lanes, starting with the first, is the real and imaginary part of a
+            /// complex number.  The result contains the product of each pair of `self` and
+            /// `other`.  Each product is rounded before it is added, so the result is the same on
+            /// every target.
+            ///
+            /// ```
+            /// # use core_simd::SimdF32;
+            /// // (1 + 2i)(3 + 4i) = -5 + 10i, and i * i = -1
+            /// let a = SimdF32::from_array([1., 2., 0., 1.]);
+            /// let b = SimdF32::from_array([3., 4., 0., 1.]);
+            /// assert_eq!(a.complex_mul(b).to_array(), [-5., 10., -1., 0.]);
+            /// ```
+            #[inline]
+            pub fn complex_mul(self, other: Self) -> Self {
+                // For (a + bi)(c + di), compute [bd, bc], negate the real parts, and add
+                // [ac, ad].
+                let real = self.swizzle::<crate::DuplicateEven>();
+                let imag = self.swizzle::<crate::DuplicateOdd>();
+                let cross = imag * other.shuffle::<{ xor_lanes::<$n>(1) }>(other);
+                let cross = crate::Mask::from_array(even_lanes::<$n>()).select(-cross, cross);
+                real * other + cross
+            }
+        }
+    };
 }
+
+impl_shuffle_lane! { simd_shuffle2, 2 }
+impl_shuffle_lane! { simd_shuffle4, 4 }
+impl_shuffle_lane! { simd_shuffle8, 8 }
+impl_shuffle_lane! { simd_shuffle16, 16 }
+impl_shuffle_lane! { simd_shuffle32, 32 }
+impl_shuffle_lane! { simd_shuffle64, 64 }
+impl_shuffle_lane! { simd_shuffle128, 128 }
@@ -0,0 +1,135 @@
+//! A pseudorandom number generator that produces a vector of independent streams at once.
+
+use crate::{LaneCount, Simd, SupportedLaneCount};
+
+/// The increment of the SplitMix64 generator, used for seeding.
+const GOLDEN_GAMMA: u64 = 0x9e3779b97f4a7c15;
+
+/// Rotates each lane left by `k` bits.
+#[inline]
+fn rotl<const LANES: usize>(x: Simd<u64, LANES>, k: u64) -> Simd<u64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    (x << k) | (x >> (64 - k))
+}
+
+/// The output function of SplitMix64, which maps consecutive counters to well-mixed values.
+#[inline]
+fn splitmix64<const LANES: usize>(x: Simd<u64, LANES>) -> Simd<u64, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let x = (x ^ (x >> 30)).wrapping_mul(Simd::splat(0xbf58476d1ce4e5b9));
+    let x = (x ^ (x >> 27)).wrapping_mul(Simd::splat(0x94d049bb133111eb));
+    x ^ (x >> 31)
+}
+
+/// A fast pseudorandom number generator running one xoshiro256++ generator in each lane.
+///
+/// Each call advances every lane, so a generator with `LANES` lanes produces `LANES` random
+/// values for about the cost of one scalar value.  Each lane produces the same sequence as the
+/// reference xoshiro256++ generator would with the same state.
+///
+/// This generator is not cryptographically secure.
+///
+/// # Examples
+/// ```
+/// # use core_simd::*;
+/// // Estimate pi by sampling points in the unit square.
+/// let mut rng = SimdRng::<8>::seed_from_u64(42);
+/// let mut inside = u32x8::splat(0);
+/// for _ in 0..1000 {
+///     let (x, y) = (rng.next_f32(), rng.next_f32());
+///     let hit = (x * x + y * y).lanes_le(f32x8::splat(1.));
+///     inside += hit.select(u32x8::splat(1), u32x8::splat(0));
+/// }
+/// let pi = 4. * inside.horizontal_sum() as f32 / 8000.;
+/// assert!((pi - core::f32::consts::PI).abs() < 0.1);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimdRng<const LANES: usize>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    state: [Simd<u64, LANES>; 4],
+}
+
+impl<const LANES: usize> SimdRng<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Creates a generator whose lanes are seeded from the corresponding lanes of `seeds`.
+    ///
+    /// Each lane's state is filled with the next four outputs of a SplitMix64 generator
+    /// starting from its seed, so that similar seeds still produce unrelated streams.  This is
+    /// useful for counter-based generation, such as seeding each lane with a pixel index.
+    #[inline]
+    pub fn from_seeds(seeds: Simd<u64, LANES>) -> Self {
+        let mut state = [Simd::splat(0); 4];
+        let mut counter = seeds;
+        for word in &mut state {
+            counter = counter.wrapping_add(Simd::splat(GOLDEN_GAMMA));
+            *word = splitmix64(counter);
+        }
+        Self { state }
+    }
+
+    /// Creates a generator from a single seed, giving each lane a different stream.
+    ///
+    /// The lanes are seeded with consecutive outputs of one SplitMix64 generator starting from
+    /// `seed`, so no two lanes share a stream.
+    #[inline]
+    pub fn seed_from_u64(seed: u64) -> Self {
+        let mut seeds = [seed; LANES];
+        for (i, lane) in seeds.iter_mut().enumerate() {
+            *lane = lane.wrapping_add((4 * i as u64).wrapping_mul(GOLDEN_GAMMA));
+        }
+        Self::from_seeds(Simd::from_array(seeds))
+    }
+
+    /// Returns the next random `u64` of each lane.
+    #[inline]
+    pub fn next_u64(&mut self) -> Simd<u64, LANES> {
+        let [s0, s1, s2, s3] = self.state;
+        let result = rotl(s0.wrapping_add(s3), 23).wrapping_add(s0);
+        let t = s1 << 17;
+        let s2 = s2 ^ s0;
+        let s3 = s3 ^ s1;
+        let s1 = s1 ^ s2;
+        let s0 = s0 ^ s3;
+        let s2 = s2 ^ t;
+        let s3 = rotl(s3, 45);
+        self.state = [s0, s1, s2, s3];
+        result
+    }
+
+    /// Returns the next random `u32` of each lane, taken from the high bits of
+    /// [`next_u64`](Self::next_u64).
+    #[inline]
+    pub fn next_u32(&mut self) -> Simd<u32, LANES> {
+        unsafe { crate::intrinsics::simd_cast(self.next_u64() >> 32) }
+    }
+
+    /// Returns a random `f32` in `0.0..1.0` for each lane.
+    ///
+    /// The values are multiples of `2^-24`, chosen uniformly.
+    #[inline]
+    pub fn next_f32(&mut self) -> Simd<f32, LANES> {
+        let bits: Simd<u64, LANES> = self.next_u64() >> 40;
+        // The conversion is exact, as the value has 24 bits.
+        let value: Simd<f32, LANES> = unsafe { crate::intrinsics::simd_cast(bits) };
+        value * Simd::splat(1. / (1u32 << 24) as f32)
+    }
+
+    /// Returns a random `f64` in `0.0..1.0` for each lane.
+    ///
+    /// The values are multiples of `2^-53`, chosen uniformly.
+    #[inline]
+    pub fn next_f64(&mut self) -> Simd<f64, LANES> {
+        let bits: Simd<u64, LANES> = self.next_u64() >> 11;
+        // The conversion is exact, as the value has 53 bits.
+        let value: Simd<f64, LANES> = unsafe { crate::intrinsics::simd_cast(bits) };
+        value * Simd::splat(1. / (1u64 << 53) as f64)
+    }
+}
@@ -0,0 +1,103 @@
+//! Quantization of `f32` vectors to 8-bit integers and back, as used by quantized neural networks.
+
+use crate::{LaneCount, Simd, SupportedLaneCount};
+
+/// Adding and subtracting `1.5 * 2^23` rounds an `f32` of magnitude at most `2^22` to an integer,
+/// using the current rounding mode, which is round to nearest with ties to even.
+const ROUND: f32 = 12582912.;
+
+impl<const LANES: usize> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Computes `self * scale + zero_point`, rounds it to nearest with ties to even, and
+    /// saturates it to `min..=max`.  `NaN` lanes become `zero_point`.
+    #[inline]
+    fn quantize(self, scale: f32, zero_point: f32, min: f32, max: f32) -> Simd<i32, LANES> {
+        let scaled = self * Self::splat(scale) + Self::splat(zero_point);
+        let (min, max) = (Self::splat(min), Self::splat(max));
+        let clamped = scaled
+            .lanes_lt(min)
+            .select(min, scaled.lanes_gt(max).select(max, scaled));
+        // The bounds are integers, so rounding a clamped lane keeps it in range.
+        let rounded = (clamped + Self::splat(ROUND)) - Self::splat(ROUND);
+        let rounded = scaled.is_nan().select(Self::splat(zero_point), rounded);
+        // Safety: the value is an integer in `min..=max`.
+        unsafe { rounded.to_int_unchecked() }
+    }
+
+    /// Quantizes each lane to an `i8`: multiplies it by `scale`, adds `zero_point`, rounds to
+    /// nearest with ties to even, and saturates to `i8::MIN..=i8::MAX`.
+    ///
+    /// `scale` is the number of quantization steps per unit, the reciprocal of the step size
+    /// that [`dequantize`](Simd::dequantize) takes.  `NaN` lanes become `zero_point`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use core_simd::*;
+    /// let x = f32x8::from_array([0.0, 0.25, 0.75, -0.25, 1.0, -100.0, 100.0, f32::NAN]);
+    /// let q = x.quantize_i8(2.0, 1);
+    /// assert_eq!(q.to_array(), [1, 2, 2, 0, 3, -128, 127, 1]);
+    /// ```
+    #[inline]
+    pub fn quantize_i8(self, scale: f32, zero_point: i8) -> Simd<i8, LANES> {
+        let q = self.quantize(scale, zero_point as f32, i8::MIN as f32, i8::MAX as f32);
+        unsafe { crate::intrinsics::simd_cast(q) }
+    }
+
+    /// Quantizes each lane to a `u8`: multiplies it by `scale`, adds `zero_point`, rounds to
+    /// nearest with ties to even, and saturates to `0..=u8::MAX`.
+    ///
+    /// `scale` is the number of quantization steps per unit, the reciprocal of the step size
+    /// that [`dequantize`](Simd::dequantize) takes.  `NaN` lanes become `zero_point`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use core_simd::*;
+    /// let x = f32x8::from_array([0.0, 0.25, 0.75, -0.25, 1.0, -100.0, 100.0, f32::NAN]);
+    /// let q = x.quantize_u8(2.0, 128);
+    /// assert_eq!(q.to_array(), [128, 128, 130, 128, 130, 0, 255, 128]);
+    /// ```
+    #[inline]
+    pub fn quantize_u8(self, scale: f32, zero_point: u8) -> Simd<u8, LANES> {
+        let q = self.quantize(scale, zero_point as f32, 0., u8::MAX as f32);
+        unsafe { crate::intrinsics::simd_cast(q) }
+    }
+}
+
+macro_rules! impl_dequantize {
+    { $($int:ty),* } => {
+        $(
+        impl<const LANES: usize> Simd<$int, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            /// Dequantizes each lane to an `f32`: subtracts `zero_point` and multiplies the
+            /// difference by `scale`.
+            ///
+            /// This is the inverse of
+            #[doc = concat!("[`quantize_", stringify!($int), "`](Simd::quantize_", stringify!($int), ")")]
+            /// with the reciprocal `scale` and the same `zero_point`, up to the rounding of the
+            /// quantized value.
+            /// The difference is converted exactly, so the only rounding is in the
+            /// multiplication.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            /// let x = f32x4::from_array([0.5, -1.0, 2.0, 0.0]);
+            #[doc = concat!("let q = x.quantize_", stringify!($int), "(4.0, 100);")]
+            /// assert_eq!(q.dequantize(0.25, 100), x);
+            /// ```
+            #[inline]
+            pub fn dequantize(self, scale: f32, zero_point: $int) -> Simd<f32, LANES> {
+                let wide: Simd<i32, LANES> = unsafe { crate::intrinsics::simd_cast(self) };
+                let centered = wide - Simd::splat(zero_point as i32);
+                Simd::<f32, LANES>::round_from_int(centered) * Simd::splat(scale)
+            }
+        }
+        )*
+    }
+}
+
+impl_dequantize! { i8, u8 }
@@ -0,0 +1,113 @@
+//! `rand` support, for sampling every lane of a vector from a distribution.
+
+use crate::{LaneCount, Mask, MaskElement, Simd, SimdElement, SupportedLaneCount};
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+impl<T, const LANES: usize> Distribution<Simd<T, LANES>> for Standard
+where
+    T: SimdElement + Default,
+    Standard: Distribution<T>,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Simd<T, LANES> {
+        let mut value = Simd::default();
+        for lane in value.as_mut_slice() {
+            *lane = self.sample(rng);
+        }
+        value
+    }
+}
+
+impl<T, const LANES: usize> Distribution<Mask<T, LANES>> for Standard
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Mask<T, LANES> {
+        let mut value = Mask::splat(false);
+        for lane in 0..LANES {
+            value.set(lane, self.sample(rng));
+        }
+        value
+    }
+}
+
+/// Samples each lane of a vector uniformly from the range between the corresponding lanes of
+/// the bounds.
+///
+/// This is the [`UniformSampler`] for vectors, so it is usually used through
+/// [`Uniform`](rand::distributions::Uniform).
+#[derive(Clone, Copy, Debug)]
+pub struct UniformSimd<T, const LANES: usize>
+where
+    T: SampleUniform,
+{
+    lanes: [T::Sampler; LANES],
+}
+
+impl<T, const LANES: usize> UniformSimd<T, LANES>
+where
+    T: SimdElement + SampleUniform,
+    T::Sampler: Copy,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn from_fn(low: Simd<T, LANES>, high: Simd<T, LANES>, f: impl Fn(T, T) -> T::Sampler) -> Self {
+        let mut lanes = [f(low[0], high[0]); LANES];
+        for i in 1..LANES {
+            lanes[i] = f(low[i], high[i]);
+        }
+        Self { lanes }
+    }
+}
+
+impl<T, const LANES: usize> UniformSampler for UniformSimd<T, LANES>
+where
+    T: SimdElement + SampleUniform + Default,
+    T::Sampler: Copy,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type X = Simd<T, LANES>;
+
+    /// Constructs a sampler for the lanewise range `low..high`.
+    ///
+    /// # Panics
+    /// Panics if any lane of `low` is not less than the corresponding lane of `high`.
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self::from_fn(*low.borrow(), *high.borrow(), T::Sampler::new)
+    }
+
+    /// Constructs a sampler for the lanewise range `low..=high`.
+    ///
+    /// # Panics
+    /// Panics if any lane of `low` is greater than the corresponding lane of `high`.
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self::from_fn(*low.borrow(), *high.borrow(), T::Sampler::new_inclusive)
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        let mut value = Simd::default();
+        for (lane, sampler) in value.as_mut_slice().iter_mut().zip(self.lanes.iter()) {
+            *lane = sampler.sample(rng);
+        }
+        value
+    }
+}
+
+impl<T, const LANES: usize> SampleUniform for Simd<T, LANES>
+where
+    T: SimdElement + SampleUniform + Default,
+    T::Sampler: Copy,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Sampler = UniformSimd<T, LANES>;
+}
@@ -0,0 +1,216 @@
+//! `rayon` support, for splitting a slice into vectors that are processed on several threads.
+
+use crate::{LaneCount, Simd, SimdElement, SupportedLaneCount};
+use rayon::iter::plumbing::{Consumer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+use rayon::prelude::*;
+
+/// Loads a chunk of exactly `LANES` elements.
+fn load<T, const LANES: usize>(chunk: &[T]) -> Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    debug_assert_eq!(chunk.len(), LANES);
+    // Safety: the chunks produced by `par_chunks_exact` contain exactly `LANES` elements.
+    unsafe { Simd::read_unaligned(chunk.as_ptr()) }
+}
+
+/// Converts a chunk of exactly `LANES` elements to an array reference.
+fn as_array_mut<T, const LANES: usize>(chunk: &mut [T]) -> &mut [T; LANES] {
+    debug_assert_eq!(chunk.len(), LANES);
+    // Safety: the chunks produced by `par_chunks_exact_mut` contain exactly `LANES` elements.
+    unsafe { &mut *chunk.as_mut_ptr().cast::<[T; LANES]>() }
+}
+
+/// Parallel iteration over the vectors of a slice, combining thread-level parallelism from
+/// `rayon` with the lane-level parallelism of SIMD vectors.
+///
+/// This trait is implemented for slices of every vector element type.
+///
+/// # Examples
+/// ```
+/// # use core_simd::*;
+/// use rayon::prelude::*;
+///
+/// let values: Vec<f32> = (0..1000).map(|x| x as f32).collect();
+/// let chunks = values.par_simd_chunks::<8>();
+/// let tail: f32 = chunks.remainder().iter().sum();
+/// let sum = chunks.reduce(|| f32x8::splat(0.), |a, b| a + b).horizontal_sum() + tail;
+/// assert_eq!(sum, 499500.);
+/// ```
+pub trait ParSimdSlice<T: SimdElement + Send + Sync> {
+    /// Returns a parallel iterator over the vectors of `LANES` consecutive elements of the
+    /// slice.
+    ///
+    /// If the length of the slice isn't a multiple of `LANES`, the last elements are skipped,
+    /// and can be retrieved with [`ParSimdChunks::remainder`].
+    fn par_simd_chunks<const LANES: usize>(&self) -> ParSimdChunks<'_, T, LANES>
+    where
+        LaneCount<LANES>: SupportedLaneCount;
+
+    /// Returns a parallel iterator over arrays of `LANES` consecutive elements of the slice,
+    /// which can be loaded as vectors with [`Simd::from_array`] and stored back.
+    ///
+    /// If the length of the slice isn't a multiple of `LANES`, the last elements are skipped,
+    /// and can be retrieved with [`ParSimdChunksMut::take_remainder`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use core_simd::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut values = [1u32; 10];
+    /// let mut chunks = values.par_simd_chunks_mut::<4>();
+    /// chunks.take_remainder().iter_mut().for_each(|x| *x *= 3);
+    /// chunks.for_each(|chunk| *chunk = (u32x4::from_array(*chunk) * 2).to_array());
+    /// assert_eq!(values, [2, 2, 2, 2, 2, 2, 2, 2, 3, 3]);
+    /// ```
+    fn par_simd_chunks_mut<const LANES: usize>(&mut self) -> ParSimdChunksMut<'_, T, LANES>
+    where
+        LaneCount<LANES>: SupportedLaneCount;
+}
+
+impl<T> ParSimdSlice<T> for [T]
+where
+    T: SimdElement + Send + Sync,
+{
+    fn par_simd_chunks<const LANES: usize>(&self) -> ParSimdChunks<'_, T, LANES>
+    where
+        LaneCount<LANES>: SupportedLaneCount,
+    {
+        ParSimdChunks {
+            chunks: self.par_chunks_exact(LANES),
+        }
+    }
+
+    fn par_simd_chunks_mut<const LANES: usize>(&mut self) -> ParSimdChunksMut<'_, T, LANES>
+    where
+        LaneCount<LANES>: SupportedLaneCount,
+    {
+        ParSimdChunksMut {
+            chunks: self.par_chunks_exact_mut(LANES),
+        }
+    }
+}
+
+/// Parallel iterator over the vectors of a slice.
+///
+/// This struct is created by [`ParSimdSlice::par_simd_chunks`].
+#[derive(Debug)]
+pub struct ParSimdChunks<'a, T, const LANES: usize>
+where
+    T: SimdElement + Sync,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    chunks: rayon::slice::ChunksExact<'a, T>,
+}
+
+impl<'a, T, const LANES: usize> ParSimdChunks<'a, T, LANES>
+where
+    T: SimdElement + Send + Sync,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Returns the elements at the end of the slice that don't fill a vector.
+    pub fn remainder(&self) -> &'a [T] {
+        self.chunks.remainder()
+    }
+}
+
+impl<'a, T, const LANES: usize> ParallelIterator for ParSimdChunks<'a, T, LANES>
+where
+    T: SimdElement + Send + Sync,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Item = Simd<T, LANES>;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.chunks.map(load).drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.chunks.len())
+    }
+}
+
+impl<'a, T, const LANES: usize> IndexedParallelIterator for ParSimdChunks<'a, T, LANES>
+where
+    T: SimdElement + Send + Sync,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn len(&self) -> usize {
+        self.chunks.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.chunks.map(load).drive(consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        self.chunks.map(load).with_producer(callback)
+    }
+}
+
+/// Parallel iterator over arrays of `LANES` elements of a mutable slice.
+///
+/// This struct is created by [`ParSimdSlice::par_simd_chunks_mut`].
+#[derive(Debug)]
+pub struct ParSimdChunksMut<'a, T, const LANES: usize>
+where
+    T: SimdElement + Send,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    chunks: rayon::slice::ChunksExactMut<'a, T>,
+}
+
+impl<'a, T, const LANES: usize> ParSimdChunksMut<'a, T, LANES>
+where
+    T: SimdElement + Send + Sync,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Takes the elements at the end of the slice that don't fill a vector, leaving the
+    /// remainder of the iterator empty.
+    pub fn take_remainder(&mut self) -> &'a mut [T] {
+        self.chunks.take_remainder()
+    }
+}
+
+impl<'a, T, const LANES: usize> ParallelIterator for ParSimdChunksMut<'a, T, LANES>
+where
+    T: SimdElement + Send + Sync,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Item = &'a mut [T; LANES];
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.chunks.map(as_array_mut).drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.chunks.len())
+    }
+}
+
+impl<'a, T, const LANES: usize> IndexedParallelIterator for ParSimdChunksMut<'a, T, LANES>
+where
+    T: SimdElement + Send + Sync,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn len(&self) -> usize {
+        self.chunks.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.chunks.map(as_array_mut).drive(consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        self.chunks.map(as_array_mut).with_producer(callback)
+    }
+}
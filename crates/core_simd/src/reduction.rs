@@ -1,19 +1,52 @@
+/// Implements a helper that returns the index of the first set lane of a mask.
+macro_rules! impl_first_set_lane {
+    { $($int:ty),* } => {
+        $(
+        impl<const LANES: usize> crate::Mask<$int, LANES>
+        where
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
+        {
+            /// Returns the index of the first set lane.  At least one lane must be set.
+            #[inline]
+            pub(crate) fn first_set_lane(self) -> usize {
+                // Lane indices always fit in the mask element, since there are at most 128 lanes.
+                let mut indices = [0 as $int; LANES];
+                for (i, index) in indices.iter_mut().enumerate() {
+                    *index = i as $int;
+                }
+                let indices = crate::Simd::from_array(indices);
+                self.select(indices, crate::Simd::splat(<$int>::MAX)).horizontal_min() as usize
+            }
+        }
+        )*
+    }
+}
+
+impl_first_set_lane! { i8, i16, i32, i64, i128, isize }
+
 macro_rules! impl_integer_reductions {
-    { $name:ident, $scalar:ty } => {
-        impl<const LANES: usize> crate::$name<LANES>
+    { $scalar:ty } => {
+        impl<const LANES: usize> crate::Simd<$scalar, LANES>
         where
-            Self: crate::LanesAtMost32
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
         {
             /// Horizontal wrapping add.  Returns the sum of the lanes of the vector, with wrapping addition.
             #[inline]
             pub fn horizontal_sum(self) -> $scalar {
-                unsafe { crate::intrinsics::simd_reduce_add_ordered(self, 0) }
+                unsafe { crate::intrinsics::simd_reduce_add_unordered(self) }
+            }
+
+            /// Dot product.  Returns the sum of the products of corresponding lanes of `self` and
+            /// `other`, with wrapping arithmetic.
+            #[inline]
+            pub fn dot(self, other: Self) -> $scalar {
+                self.wrapping_mul(other).horizontal_sum()
             }
 
             /// Horizontal wrapping multiply.  Returns the product of the lanes of the vector, with wrapping multiplication.
             #[inline]
             pub fn horizontal_product(self) -> $scalar {
-                unsafe { crate::intrinsics::simd_reduce_mul_ordered(self, 1) }
+                unsafe { crate::intrinsics::simd_reduce_mul_unordered(self) }
             }
 
             /// Horizontal bitwise "and".  Returns the cumulative bitwise "and" across the lanes of
@@ -37,6 +70,34 @@ macro_rules! impl_integer_reductions {
                 unsafe { crate::intrinsics::simd_reduce_xor(self) }
             }
 
+            /// Returns true if any lane is nonzero.
+            ///
+            /// This is a quick test for whether a block of data is entirely zero.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("assert!(Simd::<", stringify!($scalar), ", 4>::from_array([0, 0, 1, 0]).any());")]
+            #[doc = concat!("assert!(!Simd::<", stringify!($scalar), ", 4>::splat(0).any());")]
+            /// ```
+            #[inline]
+            pub fn any(self) -> bool {
+                self.horizontal_or() != 0
+            }
+
+            /// Returns true if every lane is nonzero.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("assert!(Simd::<", stringify!($scalar), ", 4>::from_array([1, 2, 3, 4]).all());")]
+            #[doc = concat!("assert!(!Simd::<", stringify!($scalar), ", 4>::from_array([1, 0, 3, 4]).all());")]
+            /// ```
+            #[inline]
+            pub fn all(self) -> bool {
+                self.lanes_ne(Self::splat(0)).all()
+            }
+
             /// Horizontal maximum.  Returns the maximum lane in the vector.
             #[inline]
             pub fn horizontal_max(self) -> $scalar {
@@ -48,37 +109,125 @@ macro_rules! impl_integer_reductions {
             pub fn horizontal_min(self) -> $scalar {
                 unsafe { crate::intrinsics::simd_reduce_min(self) }
             }
+
+            /// Horizontal minimum and maximum.  Returns the minimum and the maximum lane in the
+            /// vector.
+            ///
+            /// The lanes are split into minimums and maximums in one pass over the vector, which
+            /// halves the lanes left for each of the two reductions.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let v = Simd::<", stringify!($scalar), ", 4>::from_array([3, 1, 4, 2]);")]
+            /// assert_eq!(v.horizontal_minmax(), (1, 4));
+            /// ```
+            #[inline]
+            pub fn horizontal_minmax(self) -> ($scalar, $scalar) {
+                let upper = crate::permute::rotate_half(self);
+                let lower_is_less = self.lanes_lt(upper);
+                let min = lower_is_less.select(self, upper);
+                let max = lower_is_less.select(upper, self);
+                (
+                    crate::permute::reduce_lanes(min, LANES / 2, |a, b| a.lanes_lt(b).select(a, b)),
+                    crate::permute::reduce_lanes(max, LANES / 2, |a, b| a.lanes_gt(b).select(a, b)),
+                )
+            }
+
+            /// Returns the index of the maximum lane.  If several lanes are equal to the
+            /// maximum, returns the index of the first of them.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let v = Simd::<", stringify!($scalar), ", 4>::from_array([1, 3, 2, 3]);")]
+            /// assert_eq!(v.horizontal_argmax(), 1);
+            /// ```
+            #[inline]
+            pub fn horizontal_argmax(self) -> usize {
+                self.lanes_eq(Self::splat(self.horizontal_max())).first_set_lane()
+            }
+
+            /// Returns the index of the minimum lane.  If several lanes are equal to the
+            /// minimum, returns the index of the first of them.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let v = Simd::<", stringify!($scalar), ", 4>::from_array([3, 1, 2, 1]);")]
+            /// assert_eq!(v.horizontal_argmin(), 1);
+            /// ```
+            #[inline]
+            pub fn horizontal_argmin(self) -> usize {
+                self.lanes_eq(Self::splat(self.horizontal_min())).first_set_lane()
+            }
+
+            /// Returns the index of the first lane equal to `value`, or `None` if no lane is
+            /// equal to it.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let v = Simd::<", stringify!($scalar), ", 4>::from_array([3, 1, 2, 1]);")]
+            /// assert_eq!(v.position_eq(1), Some(1));
+            /// assert_eq!(v.position_eq(4), None);
+            /// ```
+            #[inline]
+            pub fn position_eq(self, value: $scalar) -> Option<usize> {
+                let eq = self.lanes_eq(Self::splat(value));
+                if eq.any() {
+                    Some(eq.first_set_lane())
+                } else {
+                    None
+                }
+            }
         }
     }
 }
 
 macro_rules! impl_float_reductions {
-    { $name:ident, $scalar:ty } => {
-        impl<const LANES: usize> crate::$name<LANES>
+    { $scalar:ty } => {
+        impl<const LANES: usize> crate::Simd<$scalar, LANES>
         where
-            Self: crate::LanesAtMost32
+            crate::LaneCount<LANES>: crate::SupportedLaneCount,
         {
-
             /// Horizontal add.  Returns the sum of the lanes of the vector.
+            ///
+            /// The lanes are summed pairwise, as a balanced tree: lane `i` is added to lane
+            /// `i + LANES / 2`, and so on until one lane remains.  This is faster than adding
+            /// the lanes in order, and may round differently.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let v = Simd::<", stringify!($scalar), ", 4>::from_array([1e20, 1., -1e20, 1.]);")]
+            /// // Lanes 0 and 2 cancel before either is added to a 1.
+            /// assert_eq!(v.horizontal_sum(), 2.);
+            /// ```
             #[inline]
             pub fn horizontal_sum(self) -> $scalar {
-                // LLVM sum is inaccurate on i586
-                if cfg!(all(target_arch = "x86", not(target_feature = "sse2"))) {
-                    self.as_slice().iter().sum()
-                } else {
-                    unsafe { crate::intrinsics::simd_reduce_add_ordered(self, 0.) }
-                }
+                crate::permute::reduce_tree(self, |a, b| a + b)
+            }
+
+            /// Dot product.  Returns the sum of the products of corresponding lanes of `self` and
+            /// `other`.
+            ///
+            /// The products are added in the same order as
+            /// [`horizontal_sum`](Self::horizontal_sum), and each is rounded before it is added.
+            /// [`FastFloat::fast_dot`](crate::FastFloat::fast_dot) may fuse the multiplications
+            /// into the sum instead.
+            #[inline]
+            pub fn dot(self, other: Self) -> $scalar {
+                (self * other).horizontal_sum()
             }
 
             /// Horizontal multiply.  Returns the product of the lanes of the vector.
+            ///
+            /// The lanes are multiplied pairwise, in the same order as
+            /// [`horizontal_sum`](Self::horizontal_sum).
             #[inline]
             pub fn horizontal_product(self) -> $scalar {
-                // LLVM product is inaccurate on i586
-                if cfg!(all(target_arch = "x86", not(target_feature = "sse2"))) {
-                    self.as_slice().iter().product()
-                } else {
-                    unsafe { crate::intrinsics::simd_reduce_mul_ordered(self, 1.) }
-                }
+                crate::permute::reduce_tree(self, |a, b| a * b)
             }
 
             /// Horizontal maximum.  Returns the maximum lane in the vector.
@@ -98,46 +247,68 @@ macro_rules! impl_float_reductions {
             pub fn horizontal_min(self) -> $scalar {
                 unsafe { crate::intrinsics::simd_reduce_min(self) }
             }
-        }
-    }
-}
 
-macro_rules! impl_full_mask_reductions {
-    { $name:ident, $bits_ty:ident } => {
-        impl<T: crate::Mask, const LANES: usize> $name<T, LANES>
-        where
-            crate::$bits_ty<LANES>: crate::LanesAtMost32
-        {
+            /// Horizontal minimum and maximum.  Returns the minimum and the maximum lane in the
+            /// vector.
+            ///
+            /// As with [`horizontal_min`](Self::horizontal_min) and
+            /// [`horizontal_max`](Self::horizontal_max), `0.` and `-0.` may be returned for each
+            /// other, and `NaN` lanes are ignored unless all lanes are `NaN`.  The lanes are split
+            /// into minimums and maximums in one pass over the vector, which halves the lanes left
+            /// for each of the two reductions.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let v = Simd::<", stringify!($scalar), ", 4>::from_array([3., ", stringify!($scalar), "::NAN, 4., -2.]);")]
+            /// assert_eq!(v.horizontal_minmax(), (-2., 4.));
+            /// ```
             #[inline]
-            pub fn any(self) -> bool {
-                unsafe { crate::intrinsics::simd_reduce_any(self.to_int()) }
+            pub fn horizontal_minmax(self) -> ($scalar, $scalar) {
+                // A `NaN` lane loses to any other lane, in both directions.
+                let min = |a: Self, b: Self| (a.lanes_lt(b) | b.is_nan()).select(a, b);
+                let max = |a: Self, b: Self| (a.lanes_gt(b) | b.is_nan()).select(a, b);
+                let upper = crate::permute::rotate_half(self);
+                (
+                    crate::permute::reduce_lanes(min(self, upper), LANES / 2, min),
+                    crate::permute::reduce_lanes(max(self, upper), LANES / 2, max),
+                )
             }
 
+            /// Returns the index of the maximum lane.  If several lanes are equal to the
+            /// maximum, returns the index of the first of them.
+            ///
+            /// `0.` and `-0.` are equal, so either may be chosen.  `NaN` lanes are ignored, and if
+            /// all lanes are `NaN`, returns 0.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let v = Simd::<", stringify!($scalar), ", 4>::from_array([1., ", stringify!($scalar), "::NAN, 3., 3.]);")]
+            /// assert_eq!(v.horizontal_argmax(), 2);
+            /// ```
             #[inline]
-            pub fn all(self) -> bool {
-                unsafe { crate::intrinsics::simd_reduce_all(self.to_int()) }
-            }
-        }
-    }
-}
-
-macro_rules! impl_opaque_mask_reductions {
-    { $name:ident, $bits_ty:ident } => {
-        impl<const LANES: usize> $name<LANES>
-        where
-            crate::$bits_ty<LANES>: crate::LanesAtMost32,
-            $name<LANES>: crate::Mask,
-        {
-            /// Returns true if any lane is set, or false otherwise.
-            #[inline]
-            pub fn any(self) -> bool {
-                self.0.any()
+            pub fn horizontal_argmax(self) -> usize {
+                let max = Self::splat(self.horizontal_max());
+                (self.lanes_eq(max) | max.is_nan()).first_set_lane()
             }
 
-            /// Returns true if all lanes are set, or false otherwise.
+            /// Returns the index of the minimum lane.  If several lanes are equal to the
+            /// minimum, returns the index of the first of them.
+            ///
+            /// `0.` and `-0.` are equal, so either may be chosen.  `NaN` lanes are ignored, and if
+            /// all lanes are `NaN`, returns 0.
+            ///
+            /// # Examples
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let v = Simd::<", stringify!($scalar), ", 4>::from_array([", stringify!($scalar), "::NAN, 1., 3., 1.]);")]
+            /// assert_eq!(v.horizontal_argmin(), 1);
+            /// ```
             #[inline]
-            pub fn all(self) -> bool {
-                self.0.all()
+            pub fn horizontal_argmin(self) -> usize {
+                let min = Self::splat(self.horizontal_min());
+                (self.lanes_eq(min) | min.is_nan()).first_set_lane()
             }
         }
     }
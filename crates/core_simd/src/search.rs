@@ -0,0 +1,33 @@
+//! Searching slices with vector comparisons.
+
+use crate::u8xN;
+use core::convert::TryInto;
+
+/// Returns the index of the first byte of `haystack` equal to `needle`, or `None` if there is
+/// none.
+///
+/// The slice is compared [`u8xN::LANES`] bytes at a time, using
+/// [`position_eq`](crate::Simd::position_eq), and the remaining bytes at the end are compared
+/// one at a time.
+///
+/// ```
+/// # use core_simd::find_byte;
+/// let haystack = b"the quick brown fox jumps over the lazy dog";
+/// assert_eq!(find_byte(haystack, b'z'), Some(37));
+/// assert_eq!(find_byte(haystack, b'!'), None);
+/// ```
+pub fn find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+    let mut chunks = haystack.chunks_exact(u8xN::LANES);
+    for (i, chunk) in chunks.by_ref().enumerate() {
+        let bytes = u8xN::from_array(chunk.try_into().unwrap());
+        if let Some(lane) = bytes.position_eq(needle) {
+            return Some(i * u8xN::LANES + lane);
+        }
+    }
+    let remainder = chunks.remainder();
+    let offset = haystack.len() - remainder.len();
+    remainder
+        .iter()
+        .position(|&byte| byte == needle)
+        .map(|i| offset + i)
+}
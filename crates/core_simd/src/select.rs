@@ -1,3 +1,5 @@
+use crate::{LaneCount, Mask, MaskElement, Simd, SimdElement, SupportedLaneCount};
+
 mod sealed {
     pub trait Sealed {}
 }
@@ -9,81 +11,201 @@ pub trait Select<Mask>: Sealed {
     fn select(mask: Mask, true_values: Self, false_values: Self) -> Self;
 }
 
-macro_rules! impl_select {
-    {
-        $mask:ident ($bits_ty:ident): $($type:ident),*
-    } => {
+impl<T, const LANES: usize> Sealed for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
+
+impl<T, const LANES: usize> Select<Mask<T::Mask, LANES>> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[doc(hidden)]
+    #[inline]
+    fn select(mask: Mask<T::Mask, LANES>, true_values: Self, false_values: Self) -> Self {
+        unsafe { crate::intrinsics::simd_select(mask.to_int(), true_values, false_values) }
+    }
+}
+
+impl<T, const LANES: usize> Sealed for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
+
+impl<T, const LANES: usize> Select<Self> for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[doc(hidden)]
+    #[inline]
+    fn select(mask: Self, true_values: Self, false_values: Self) -> Self {
+        mask & true_values | !mask & false_values
+    }
+}
+
+impl<T, const LANES: usize> Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Choose lanes from two vectors.
+    ///
+    /// For each lane in the mask, choose the corresponding lane from `true_values` if
+    /// that lane mask is true, and `false_values` if that lane mask is false.
+    ///
+    /// ```
+    /// # use core_simd::{Mask32, SimdI32};
+    /// let a = SimdI32::from_array([0, 1, 2, 3]);
+    /// let b = SimdI32::from_array([4, 5, 6, 7]);
+    /// let mask = Mask32::from_array([true, false, false, true]);
+    /// let c = mask.select(a, b);
+    /// assert_eq!(c.to_array(), [0, 5, 6, 3]);
+    /// ```
+    ///
+    /// `select` can also be used on masks:
+    /// ```
+    /// # use core_simd::Mask32;
+    /// let a = Mask32::from_array([true, true, false, false]);
+    /// let b = Mask32::from_array([false, false, true, true]);
+    /// let mask = Mask32::from_array([true, false, false, true]);
+    /// let c = mask.select(a, b);
+    /// assert_eq!(c.to_array(), [true, false, true, false]);
+    /// ```
+    #[inline]
+    pub fn select<S: Select<Self>>(self, true_values: S, false_values: S) -> S {
+        S::select(self, true_values, false_values)
+    }
+}
+
+impl<T, const LANES: usize> Simd<T, LANES>
+where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Packs the lanes selected by `mask` to the start of the vector, in order.
+    ///
+    /// Returns the packed vector and the number of selected lanes.  The remaining lanes of the
+    /// vector are zero.  This is the inverse of [`expand`](Self::expand).
+    ///
+    /// The lanes are moved one at a time without branching on the mask, since there is no
+    /// portable instruction for a shuffle chosen at run time.
+    ///
+    /// ```
+    /// # use core_simd::{Mask32, SimdI32};
+    /// let a = SimdI32::from_array([1, 2, 3, 4]);
+    /// let mask = Mask32::from_array([false, true, false, true]);
+    /// let (packed, count) = a.compress(mask);
+    /// assert_eq!(packed.to_array(), [2, 4, 0, 0]);
+    /// assert_eq!(count, 2);
+    /// ```
+    #[inline]
+    pub fn compress(self, mask: Mask<T::Mask, LANES>) -> (Self, usize) {
+        let lanes = self.to_array();
+        let mask = mask.to_array();
+        let mut packed = [T::default(); LANES];
+        let mut count = 0;
+        for i in 0..LANES {
+            // Each lane is written to the next free position, which only advances past selected
+            // lanes, so later lanes overwrite the unselected ones.
+            packed[count] = lanes[i];
+            count += mask[i] as usize;
+        }
+        for lane in packed.iter_mut().skip(count) {
+            *lane = T::default();
+        }
+        (Self::from_array(packed), count)
+    }
+
+    /// Spreads the lanes at the start of the vector, in order, into the lanes selected by
+    /// `mask`.
+    ///
+    /// The unselected lanes are zero.  This is the inverse of [`compress`](Self::compress).
+    ///
+    /// ```
+    /// # use core_simd::{Mask32, SimdI32};
+    /// let a = SimdI32::from_array([1, 2, 3, 4]);
+    /// let mask = Mask32::from_array([false, true, false, true]);
+    /// assert_eq!(a.expand(mask).to_array(), [0, 1, 0, 2]);
+    /// ```
+    #[inline]
+    pub fn expand(self, mask: Mask<T::Mask, LANES>) -> Self {
+        let lanes = self.to_array();
+        let selected = mask.to_array();
+        let mut expanded = [T::default(); LANES];
+        let mut next = 0;
+        for i in 0..LANES {
+            // `next` never passes `i`, so it is always in bounds.
+            expanded[i] = lanes[next];
+            next += selected[i] as usize;
+        }
+        mask.select(Self::from_array(expanded), Self::splat(T::default()))
+    }
+}
+
+macro_rules! impl_integer_bitselect {
+    { $($type:ty),* } => {
         $(
-        impl<const LANES: usize> Sealed for crate::$type<LANES> where Self: crate::LanesAtMost32 {}
-        impl<const LANES: usize> Select<crate::$mask<LANES>> for crate::$type<LANES>
+        impl<const LANES: usize> Simd<$type, LANES>
         where
-            crate::$mask<LANES>: crate::Mask,
-            crate::$bits_ty<LANES>: crate::LanesAtMost32,
-            Self: crate::LanesAtMost32,
+            LaneCount<LANES>: SupportedLaneCount,
         {
-            #[doc(hidden)]
+            /// Chooses each bit from `self` if the corresponding bit of `bits` is set, and from
+            /// `other` otherwise.
+            ///
+            /// Unlike [`Mask::select`], which chooses whole lanes, this chooses individual bits.
+            /// It is a single `vbsl` instruction on NEON.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = Simd::<", stringify!($type), ", 2>::splat(0b1111);")]
+            #[doc = concat!("let b = Simd::<", stringify!($type), ", 2>::splat(0b0000);")]
+            #[doc = concat!("let bits = Simd::<", stringify!($type), ", 2>::from_array([0b1010, 0b0011]);")]
+            /// assert_eq!(a.bitselect(b, bits).to_array(), [0b1010, 0b0011]);
+            /// ```
             #[inline]
-            fn select(mask: crate::$mask<LANES>, true_values: Self, false_values: Self) -> Self {
-                unsafe { crate::intrinsics::simd_select(mask.to_int(), true_values, false_values) }
+            pub fn bitselect(self, other: Self, bits: Self) -> Self {
+                (self & bits) | (other & !bits)
             }
         }
         )*
+    }
+}
 
-        impl<const LANES: usize> Sealed for crate::$mask<LANES>
-        where
-            Self: crate::Mask,
-            crate::$bits_ty<LANES>: crate::LanesAtMost32,
-        {}
-        impl<const LANES: usize> Select<Self> for crate::$mask<LANES>
-        where
-            Self: crate::Mask,
-            crate::$bits_ty<LANES>: crate::LanesAtMost32,
-        {
-            #[doc(hidden)]
-            #[inline]
-            fn select(mask: Self, true_values: Self, false_values: Self) -> Self {
-                mask & true_values | !mask & false_values
-            }
-        }
+impl_integer_bitselect! { u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize }
 
-        impl<const LANES: usize> crate::$mask<LANES>
+macro_rules! impl_float_bitselect {
+    { $($type:ty => $bits_ty:ty),* } => {
+        $(
+        impl<const LANES: usize> Simd<$type, LANES>
         where
-            Self: crate::Mask,
-            crate::$bits_ty<LANES>: crate::LanesAtMost32,
+            LaneCount<LANES>: SupportedLaneCount,
         {
-            /// Choose lanes from two vectors.
+            /// Chooses each bit of the binary representation from `self` if the corresponding
+            /// bit of `bits` is set, and from `other` otherwise.
             ///
-            /// For each lane in the mask, choose the corresponding lane from `true_values` if
-            /// that lane mask is true, and `false_values` if that lane mask is false.
+            /// For example, selecting the sign bit from `other` and the remaining bits from
+            /// `self` copies the sign of `other`.
             ///
             /// ```
-            /// # use core_simd::{Mask32, SimdI32};
-            /// let a = SimdI32::from_array([0, 1, 2, 3]);
-            /// let b = SimdI32::from_array([4, 5, 6, 7]);
-            /// let mask = Mask32::from_array([true, false, false, true]);
-            /// let c = mask.select(a, b);
-            /// assert_eq!(c.to_array(), [0, 5, 6, 3]);
-            /// ```
-            ///
-            /// `select` can also be used on masks:
-            /// ```
-            /// # use core_simd::Mask32;
-            /// let a = Mask32::from_array([true, true, false, false]);
-            /// let b = Mask32::from_array([false, false, true, true]);
-            /// let mask = Mask32::from_array([true, false, false, true]);
-            /// let c = mask.select(a, b);
-            /// assert_eq!(c.to_array(), [true, false, true, false]);
+            /// # use core_simd::*;
+            #[doc = concat!("let a = Simd::<", stringify!($type), ", 2>::from_array([1.5, -2.0]);")]
+            #[doc = concat!("let b = Simd::<", stringify!($type), ", 2>::from_array([-0.0, 0.0]);")]
+            #[doc = concat!("let bits = Simd::splat(!(", stringify!($type), "::to_bits(-0.0)));")]
+            /// assert_eq!(a.bitselect(b, bits).to_array(), [-1.5, 2.0]);
             /// ```
             #[inline]
-            pub fn select<S: Select<Self>>(self, true_values: S, false_values: S) -> S {
-                S::select(self, true_values, false_values)
+            pub fn bitselect(self, other: Self, bits: Simd<$bits_ty, LANES>) -> Self {
+                Self::from_bits(self.to_bits().bitselect(other.to_bits(), bits))
             }
         }
+        )*
     }
 }
 
-impl_select! { Mask8 (SimdI8): SimdU8, SimdI8 }
-impl_select! { Mask16 (SimdI16): SimdU16, SimdI16 }
-impl_select! { Mask32 (SimdI32): SimdU32, SimdI32, SimdF32}
-impl_select! { Mask64 (SimdI64): SimdU64, SimdI64, SimdF64}
-impl_select! { MaskSize (SimdIsize): SimdUsize, SimdIsize }
+impl_float_bitselect! { f32 => u32, f64 => u64 }
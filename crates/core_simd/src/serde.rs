@@ -0,0 +1,140 @@
+//! `serde` support.  Vectors and masks serialize as fixed-length tuples of their lanes, the same
+//! as arrays.
+
+use crate::{LaneCount, Mask, MaskElement, Simd, SimdElement, SupportedLaneCount};
+use core::marker::PhantomData;
+use serde::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+
+fn serialize_lanes<S, T>(lanes: &[T], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let mut tuple = serializer.serialize_tuple(lanes.len())?;
+    for lane in lanes {
+        tuple.serialize_element(lane)?;
+    }
+    tuple.end()
+}
+
+fn deserialize_lanes<'de, D, T, const LANES: usize>(deserializer: D) -> Result<[T; LANES], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Copy + Default,
+{
+    struct LanesVisitor<T, const LANES: usize>(PhantomData<T>);
+
+    impl<'de, T, const LANES: usize> Visitor<'de> for LanesVisitor<T, LANES>
+    where
+        T: Deserialize<'de> + Copy + Default,
+    {
+        type Value = [T; LANES];
+
+        fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            write!(f, "an array of length {}", LANES)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut lanes = [T::default(); LANES];
+            for (i, lane) in lanes.iter_mut().enumerate() {
+                *lane = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(i, &self))?;
+            }
+            Ok(lanes)
+        }
+    }
+
+    deserializer.deserialize_tuple(LANES, LanesVisitor(PhantomData))
+}
+
+impl<T, const LANES: usize> Serialize for Simd<T, LANES>
+where
+    T: SimdElement + Serialize,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_lanes(self.as_slice(), serializer)
+    }
+}
+
+impl<'de, T, const LANES: usize> Deserialize<'de> for Simd<T, LANES>
+where
+    T: SimdElement + Deserialize<'de> + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_lanes(deserializer).map(Self::from_array)
+    }
+}
+
+impl<T, const LANES: usize> Serialize for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_lanes(&self.to_array(), serializer)
+    }
+}
+
+impl<'de, T, const LANES: usize> Deserialize<'de> for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_lanes(deserializer).map(Self::from_array)
+    }
+}
+
+/// Half-precision vectors serialize as the bit patterns of their lanes.
+macro_rules! impl_half_serde {
+    { $($feature:literal => $type:ident),* } => {
+        $(
+        #[cfg(feature = $feature)]
+        impl<const LANES: usize> Serialize for crate::$type<LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                self.to_bits().serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl<'de, const LANES: usize> Deserialize<'de> for crate::$type<LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                crate::SimdU16::deserialize(deserializer).map(Self::from_bits)
+            }
+        }
+        )*
+    }
+}
+
+impl_half_serde! { "f16" => SimdF16, "bf16" => SimdBf16 }
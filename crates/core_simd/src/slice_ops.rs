@@ -0,0 +1,260 @@
+//! Reductions over slices, vectorized with the target's native vector width.
+//!
+//! Each function loads vectors from the start of the slice, without regard to alignment, and
+//! accumulates the few elements after the last vector one at a time.  The vectors are accumulated
+//! in four independent accumulators, so that consecutive additions don't wait on each other.
+//!
+//! The functions are implemented for every integer and float element type.  Integer sums wrap on
+//! overflow.  Float sums add the elements in a different order than a sequential loop, so they
+//! may round differently.  The order only depends on the length of the slice and the native
+//! vector width, not on where the slice is in memory, so a float sum of the same values is the
+//! same on every run.
+//!
+//! ```
+//! use core_simd::slice_ops;
+//! let values: Vec<f32> = (1..=100).map(|x| x as f32).collect();
+//! assert_eq!(slice_ops::sum(&values), 5050.);
+//! assert_eq!(slice_ops::min(&values), Some(1.));
+//! assert_eq!(slice_ops::max(&values), Some(100.));
+//! assert_eq!(slice_ops::dot(&values[..3], &values[..3]), 14.);
+//! ```
+
+use crate::vector::*;
+use crate::{LaneCount, Simd, SimdElement, SupportedLaneCount};
+
+mod sealed {
+    pub trait Sealed {}
+}
+use sealed::Sealed;
+
+/// An element type supported by the functions in [`slice_ops`](self).
+///
+/// This trait is sealed and implemented for every integer and float type.
+pub trait Element: Sealed + Copy {
+    #[doc(hidden)]
+    fn sum(slice: &[Self]) -> Self;
+
+    #[doc(hidden)]
+    fn min(slice: &[Self]) -> Option<Self>;
+
+    #[doc(hidden)]
+    fn max(slice: &[Self]) -> Option<Self>;
+
+    #[doc(hidden)]
+    fn dot(a: &[Self], b: &[Self]) -> Self;
+}
+
+/// Returns the sum of the elements of `slice`, or zero if it is empty.
+///
+/// Integer sums wrap on overflow.  Float sums may round differently than a sequential sum, but
+/// don't depend on the alignment of the slice.
+#[inline]
+pub fn sum<T: Element>(slice: &[T]) -> T {
+    T::sum(slice)
+}
+
+/// Returns the smallest element of `slice`, or `None` if it is empty.
+///
+/// For floats, `NaN` elements are ignored unless every element is `NaN`, as in
+/// [`f32::min`].  If the smallest value is zero, either `0.` or `-0.` may be returned.
+#[inline]
+pub fn min<T: Element>(slice: &[T]) -> Option<T> {
+    T::min(slice)
+}
+
+/// Returns the largest element of `slice`, or `None` if it is empty.
+///
+/// As in [`min`], `NaN` elements are ignored unless every element is `NaN`.
+#[inline]
+pub fn max<T: Element>(slice: &[T]) -> Option<T> {
+    T::max(slice)
+}
+
+/// Returns the dot product of `a` and `b`: the sum of the products of their elements.
+///
+/// Integer products and sums wrap on overflow.  Float products are added in a different order
+/// than a sequential loop, so they may round differently.
+///
+/// # Panics
+/// Panics if `a` and `b` have different lengths.
+#[inline]
+pub fn dot<T: Element>(a: &[T], b: &[T]) -> T {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    T::dot(a, b)
+}
+
+/// Reduces `slice` with `vector_op` on its vectors, loaded without regard to alignment, and
+/// `scalar_op` on the elements after the last vector.
+#[inline]
+fn reduce<T, const LANES: usize>(
+    slice: &[T],
+    init: T,
+    vector_op: impl Fn(Simd<T, LANES>, Simd<T, LANES>) -> Simd<T, LANES>,
+    horizontal_op: impl Fn(Simd<T, LANES>) -> T,
+    scalar_op: impl Fn(T, T) -> T,
+) -> T
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mut acc = [Simd::splat(init); 4];
+    let mut chunks = slice.chunks_exact(4 * LANES);
+    for chunk in chunks.by_ref() {
+        for i in 0..4 {
+            // Safety: each chunk contains four vectors.
+            let v = unsafe { Simd::read_unaligned(chunk[i * LANES..].as_ptr()) };
+            acc[i] = vector_op(acc[i], v);
+        }
+    }
+    let mut vectors = chunks.remainder().chunks_exact(LANES);
+    for (i, v) in vectors.by_ref().enumerate() {
+        // Safety: each chunk is one vector.
+        acc[i] = vector_op(acc[i], unsafe { Simd::read_unaligned(v.as_ptr()) });
+    }
+    let acc = vector_op(vector_op(acc[0], acc[1]), vector_op(acc[2], acc[3]));
+    vectors
+        .remainder()
+        .iter()
+        .fold(horizontal_op(acc), |acc, x| scalar_op(acc, *x))
+}
+
+/// Computes the dot product of `a` and `b`, which have the same length, loading `a` and `b`
+/// without regard to alignment.
+#[inline]
+fn dot_product<T, const LANES: usize>(
+    a: &[T],
+    b: &[T],
+    zero: T,
+    vector_mul_add: impl Fn(Simd<T, LANES>, Simd<T, LANES>, Simd<T, LANES>) -> Simd<T, LANES>,
+    vector_add: impl Fn(Simd<T, LANES>, Simd<T, LANES>) -> Simd<T, LANES>,
+    horizontal_sum: impl Fn(Simd<T, LANES>) -> T,
+    scalar_mul_add: impl Fn(T, T, T) -> T,
+) -> T
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mut acc = [Simd::splat(zero); 4];
+    let (mut a_chunks, mut b_chunks) = (a.chunks_exact(4 * LANES), b.chunks_exact(4 * LANES));
+    for (a, b) in a_chunks.by_ref().zip(b_chunks.by_ref()) {
+        for i in 0..4 {
+            // Safety: each chunk contains four vectors.
+            let (x, y) = unsafe {
+                (
+                    Simd::read_unaligned(a[i * LANES..].as_ptr()),
+                    Simd::read_unaligned(b[i * LANES..].as_ptr()),
+                )
+            };
+            acc[i] = vector_mul_add(acc[i], x, y);
+        }
+    }
+    let (mut a_vectors, mut b_vectors) = (
+        a_chunks.remainder().chunks_exact(LANES),
+        b_chunks.remainder().chunks_exact(LANES),
+    );
+    for (i, (a, b)) in a_vectors.by_ref().zip(b_vectors.by_ref()).enumerate() {
+        // Safety: each chunk is one vector.
+        let (x, y) = unsafe {
+            (
+                Simd::read_unaligned(a.as_ptr()),
+                Simd::read_unaligned(b.as_ptr()),
+            )
+        };
+        acc[i] = vector_mul_add(acc[i], x, y);
+    }
+    let acc = vector_add(vector_add(acc[0], acc[1]), vector_add(acc[2], acc[3]));
+    a_vectors
+        .remainder()
+        .iter()
+        .zip(b_vectors.remainder())
+        .fold(horizontal_sum(acc), |acc, (x, y)| {
+            scalar_mul_add(acc, *x, *y)
+        })
+}
+
+macro_rules! impl_element {
+    { float: $($float:ident => $float_vector:ident),*; int: $($int:ident => $int_vector:ident),* } => {
+        $(
+        impl Sealed for $float {}
+
+        impl Element for $float {
+            #[inline]
+            fn sum(slice: &[Self]) -> Self {
+                reduce(slice, 0., |a: $float_vector, b| a + b, $float_vector::horizontal_sum, |a, b| a + b)
+            }
+
+            #[inline]
+            fn min(slice: &[Self]) -> Option<Self> {
+                let first = *slice.first()?;
+                // Selecting `a` when `b` is `NaN` ignores `NaN` lanes, like the scalar `min`.
+                let min = |a: $float_vector, b: $float_vector| (a.lanes_lt(b) | b.is_nan()).select(a, b);
+                Some(reduce(slice, first, min, $float_vector::horizontal_min, $float::min))
+            }
+
+            #[inline]
+            fn max(slice: &[Self]) -> Option<Self> {
+                let first = *slice.first()?;
+                let max = |a: $float_vector, b: $float_vector| (a.lanes_gt(b) | b.is_nan()).select(a, b);
+                Some(reduce(slice, first, max, $float_vector::horizontal_max, $float::max))
+            }
+
+            #[inline]
+            fn dot(a: &[Self], b: &[Self]) -> Self {
+                dot_product(
+                    a,
+                    b,
+                    0.,
+                    |acc: $float_vector, x, y| acc + x * y,
+                    |a, b| a + b,
+                    $float_vector::horizontal_sum,
+                    |acc, x, y| acc + x * y,
+                )
+            }
+        }
+        )*
+
+        $(
+        impl Sealed for $int {}
+
+        impl Element for $int {
+            #[inline]
+            fn sum(slice: &[Self]) -> Self {
+                reduce(slice, 0, $int_vector::wrapping_add, $int_vector::horizontal_sum, $int::wrapping_add)
+            }
+
+            #[inline]
+            fn min(slice: &[Self]) -> Option<Self> {
+                let first = *slice.first()?;
+                let min = |a: $int_vector, b: $int_vector| a.lanes_lt(b).select(a, b);
+                Some(reduce(slice, first, min, $int_vector::horizontal_min, Ord::min))
+            }
+
+            #[inline]
+            fn max(slice: &[Self]) -> Option<Self> {
+                let first = *slice.first()?;
+                let max = |a: $int_vector, b: $int_vector| a.lanes_gt(b).select(a, b);
+                Some(reduce(slice, first, max, $int_vector::horizontal_max, Ord::max))
+            }
+
+            #[inline]
+            fn dot(a: &[Self], b: &[Self]) -> Self {
+                dot_product(
+                    a,
+                    b,
+                    0,
+                    |acc: $int_vector, x, y| acc.wrapping_add(x.wrapping_mul(y)),
+                    $int_vector::wrapping_add,
+                    $int_vector::horizontal_sum,
+                    |acc, x, y| acc.wrapping_add(x.wrapping_mul(y)),
+                )
+            }
+        }
+        )*
+    }
+}
+
+impl_element! {
+    float: f32 => f32xN, f64 => f64xN;
+    int: u8 => u8xN, u16 => u16xN, u32 => u32xN, u64 => u64xN, u128 => u128xN, usize => usizexN,
+        i8 => i8xN, i16 => i16xN, i32 => i32xN, i64 => i64xN, i128 => i128xN, isize => isizexN
+}
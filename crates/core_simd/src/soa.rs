@@ -0,0 +1,125 @@
+//! Converting slices of structs to a struct of vectors, for vectorizing code over records.
+
+/// Defines a struct together with a "structure of arrays" version holding a vector per field.
+///
+/// Simulation code usually stores records such as particles as an array of structs, but vector
+/// code operates on one field of many records at a time.  This macro defines the struct as
+/// written, plus a vector struct generic over `LANES`, with the same field names but each field
+/// a `Simd` of the field's type.  The vector struct loads `LANES` records from a slice by
+/// transposing them, and stores them back the same way.
+///
+/// The fields must all be vector element types, and the struct must be `Copy`.  Attributes and
+/// doc comments before each struct are applied to that struct, and field attributes are applied
+/// to the fields of both.  The vector struct always derives `Copy`, `Clone`, `Debug`,
+/// `Default`, and `PartialEq`.
+///
+/// The vector struct has these methods:
+/// * `splat(value)`, which copies a record to every lane.
+/// * `from_slice(slice)`, which loads the first `LANES` records of a slice.
+/// * `write_to_slice(self, slice)`, which stores the lanes to the first `LANES` records of a
+///   slice.
+/// * `get(self, lane)`, which returns the record in a lane.
+///
+/// `from_slice` and `write_to_slice` panic if the slice is shorter than `LANES`.
+///
+/// # Example
+/// ```
+/// # use core_simd::*;
+/// core_simd::soa! {
+///     /// A point mass.
+///     #[derive(Copy, Clone, Debug, PartialEq)]
+///     pub struct Particle {
+///         pub x: f32,
+///         pub y: f32,
+///         pub mass: f32,
+///     }
+///
+///     /// `LANES` point masses.
+///     pub struct ParticleXN;
+/// }
+///
+/// let mut particles = [Particle { x: 0., y: 1., mass: 2. }; 8];
+/// for chunk in particles.chunks_exact_mut(4) {
+///     let mut p = ParticleXN::<4>::from_slice(chunk);
+///     p.y -= f32x4::splat(0.5);
+///     p.write_to_slice(chunk);
+/// }
+/// assert_eq!(particles[7], Particle { x: 0., y: 0.5, mass: 2. });
+/// ```
+#[macro_export]
+macro_rules! soa {
+    {
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[$field_attr:meta])* $field_vis:vis $field:ident: $ty:ty),* $(,)?
+        }
+
+        $(#[$vector_attr:meta])*
+        $vector_vis:vis struct $vector_name:ident;
+    } => {
+        $(#[$attr])*
+        $vis struct $name {
+            $($(#[$field_attr])* $field_vis $field: $ty),*
+        }
+
+        $(#[$vector_attr])*
+        #[derive(Copy, Clone, Debug, Default, PartialEq)]
+        $vector_vis struct $vector_name<const LANES: usize>
+        where
+            $crate::LaneCount<LANES>: $crate::SupportedLaneCount,
+        {
+            $($(#[$field_attr])* $field_vis $field: $crate::Simd<$ty, LANES>),*
+        }
+
+        impl<const LANES: usize> $vector_name<LANES>
+        where
+            $crate::LaneCount<LANES>: $crate::SupportedLaneCount,
+        {
+            #[doc = concat!("Copies a `", stringify!($name), "` to every lane.")]
+            #[inline]
+            $vector_vis fn splat(value: $name) -> Self {
+                Self {
+                    $($field: $crate::Simd::splat(value.$field)),*
+                }
+            }
+
+            #[doc = concat!("Loads the first `LANES` records of a slice of `", stringify!($name), "`.")]
+            ///
+            /// # Panics
+            /// Panics if the slice is shorter than `LANES`.
+            #[inline]
+            $vector_vis fn from_slice(slice: &[$name]) -> Self {
+                assert!(slice.len() >= LANES, "slice must contain at least LANES records");
+                let slice = &slice[..LANES];
+                let mut value = Self::splat(slice[0]);
+                for (i, record) in slice.iter().enumerate() {
+                    $(value.$field[i] = record.$field;)*
+                }
+                value
+            }
+
+            /// Stores the lanes to the first `LANES` records of a slice.
+            ///
+            /// # Panics
+            /// Panics if the slice is shorter than `LANES`.
+            #[inline]
+            $vector_vis fn write_to_slice(self, slice: &mut [$name]) {
+                assert!(slice.len() >= LANES, "slice must contain at least LANES records");
+                for (i, record) in slice[..LANES].iter_mut().enumerate() {
+                    $(record.$field = self.$field[i];)*
+                }
+            }
+
+            #[doc = concat!("Returns the `", stringify!($name), "` in a lane.")]
+            ///
+            /// # Panics
+            /// Panics if `lane` is out of bounds.
+            #[inline]
+            $vector_vis fn get(self, lane: usize) -> $name {
+                $name {
+                    $($field: self.$field[lane]),*
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,304 @@
+//! Sorting slices of 32-bit elements, using sorting networks for short runs.
+//!
+//! Every element type is mapped to a `u32` key with the same ordering, so that only one sort is
+//! needed.  Long slices are partitioned around a pivot as in quicksort, comparing a vector of keys
+//! at a time, and runs of up to `2 * LANES` keys are sorted with the bitonic network of
+//! [`Simd::sorted`] instead of insertion sort, which is where most of the comparisons of a
+//! quicksort are made.
+
+use crate::{Mask, Simd};
+use core::convert::TryInto;
+
+/// Lanes in each vector of keys.
+const LANES: usize = 16;
+
+/// Runs this short or shorter are sorted with sorting networks.
+const SMALL: usize = 2 * LANES;
+
+type Keys = Simd<u32, LANES>;
+
+mod sealed {
+    pub trait Sealed {}
+}
+use sealed::Sealed;
+
+/// An element type that can be sorted with [`simd_sort`].
+///
+/// This trait is sealed and implemented for `u32`, `i32` and `f32`.
+pub trait SortElement: Sealed + Copy {
+    /// Maps the bits of an element to a key whose unsigned order matches the element order.
+    #[doc(hidden)]
+    fn to_key(bits: u32) -> u32;
+
+    /// The inverse of `to_key`.
+    #[doc(hidden)]
+    fn from_key(key: u32) -> u32;
+}
+
+impl Sealed for u32 {}
+
+impl SortElement for u32 {
+    #[inline]
+    fn to_key(bits: u32) -> u32 {
+        bits
+    }
+
+    #[inline]
+    fn from_key(key: u32) -> u32 {
+        key
+    }
+}
+
+impl Sealed for i32 {}
+
+impl SortElement for i32 {
+    #[inline]
+    fn to_key(bits: u32) -> u32 {
+        bits ^ (1 << 31)
+    }
+
+    #[inline]
+    fn from_key(key: u32) -> u32 {
+        key ^ (1 << 31)
+    }
+}
+
+impl Sealed for f32 {}
+
+impl SortElement for f32 {
+    // Negative values have all bits flipped, and positive values have their sign bit set, as in
+    // `lanes_total_lt`.
+    #[inline]
+    fn to_key(bits: u32) -> u32 {
+        if bits >> 31 == 1 {
+            !bits
+        } else {
+            bits | (1 << 31)
+        }
+    }
+
+    #[inline]
+    fn from_key(key: u32) -> u32 {
+        if key >> 31 == 1 {
+            key & !(1 << 31)
+        } else {
+            !key
+        }
+    }
+}
+
+/// Sorts a slice in ascending order.
+///
+/// The sort isn't stable, and doesn't allocate.  Floats are ordered by the IEEE 754 totalOrder
+/// predicate, as with [`f32::total_cmp`]: `-0.0` sorts before `+0.0`, and `NaN`s sort to the
+/// ends according to their sign.
+///
+/// The slice is partitioned as in quicksort, a vector of keys at a time, and short runs are sorted
+/// with the bitonic sorting network of [`Simd::sorted`].  If partitioning goes badly, the slice is sorted with
+/// [`sort_unstable`](slice::sort_unstable) instead, so the worst case is `O(n log n)`.
+///
+/// ```
+/// # use core_simd::simd_sort;
+/// let mut v = [3.5, -0.0, f32::NAN, 1.0, 0.0, -2.0];
+/// simd_sort(&mut v);
+/// assert_eq!(v[..5], [-2.0, -0.0, 0.0, 1.0, 3.5]);
+/// assert!(v[1].is_sign_negative() && v[5].is_nan());
+/// ```
+pub fn simd_sort<T: SortElement>(slice: &mut [T]) {
+    // Safety: every `SortElement` is a 32-bit plain-old-data type.
+    let keys =
+        unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast::<u32>(), slice.len()) };
+    for key in keys.iter_mut() {
+        *key = T::to_key(*key);
+    }
+    let depth_limit = 2 * (usize::BITS - keys.len().leading_zeros());
+    sort_keys(keys, None, depth_limit);
+    for key in keys.iter_mut() {
+        *key = T::from_key(*key);
+    }
+}
+
+/// Loads the vector of keys starting at `i`.
+#[inline]
+fn load(keys: &[u32], i: usize) -> Keys {
+    Keys::from_array(keys[i..i + LANES].try_into().unwrap())
+}
+
+/// Returns the lanewise minimum and maximum of two vectors.
+#[inline]
+fn min_max(a: Keys, b: Keys) -> (Keys, Keys) {
+    let lt = a.lanes_lt(b);
+    (lt.select(a, b), lt.select(b, a))
+}
+
+/// Sorts up to `SMALL` keys with sorting networks.
+///
+/// The keys are loaded into two vectors, padded with `u32::MAX`, which sorts after every key.
+/// After sorting both vectors, the first and the reverse of the second form a bitonic sequence,
+/// so their lanewise minimum holds the smallest half of the keys and their maximum the largest.
+/// Both halves are themselves bitonic, so one merge stage each finishes the sort.
+#[inline]
+fn small_sort(keys: &mut [u32]) {
+    debug_assert!(keys.len() <= SMALL);
+    let mut lanes = [u32::MAX; SMALL];
+    lanes[..keys.len()].copy_from_slice(keys);
+    let lo = load(&lanes, 0).sorted();
+    if keys.len() <= LANES {
+        keys.copy_from_slice(&lo.as_array()[..keys.len()]);
+        return;
+    }
+    let hi = load(&lanes, LANES).sorted().reverse();
+    let (lo, hi) = min_max(lo, hi);
+    lanes[..LANES].copy_from_slice(lo.bitonic_merge().as_array());
+    lanes[LANES..].copy_from_slice(hi.bitonic_merge().as_array());
+    keys.copy_from_slice(&lanes[..keys.len()]);
+}
+
+/// Moves the median of the first, middle and last keys to the front, to be used as the pivot.
+#[inline]
+fn choose_pivot(keys: &mut [u32]) {
+    let (a, b, c) = (0, keys.len() / 2, keys.len() - 1);
+    let median = if keys[a] < keys[b] {
+        if keys[b] < keys[c] {
+            b
+        } else if keys[a] < keys[c] {
+            c
+        } else {
+            a
+        }
+    } else if keys[a] < keys[c] {
+        a
+    } else if keys[b] < keys[c] {
+        c
+    } else {
+        b
+    };
+    keys.swap(0, median);
+}
+
+/// Moves the lanes of `v` set in `mask` to the start of the vector, and the rest to the end.
+/// Returns the vector and the number of lanes moved to the start.
+///
+/// As in [`Simd::compress`], the lanes are moved one at a time without branching on the mask.
+#[inline]
+fn split(v: Keys, mask: Mask<i32, LANES>) -> (Keys, usize) {
+    let lanes = v.to_array();
+    let mask = mask.to_array();
+    let mut split = [0; LANES];
+    let (mut low, mut high) = (0, 0);
+    for i in 0..LANES {
+        // Each lane is written to the next free position at both ends, and only the end it
+        // belongs to advances.  The lanes at the end are stored in reverse order.
+        split[low] = lanes[i];
+        split[LANES - 1 - high] = lanes[i];
+        low += mask[i] as usize;
+        high += !mask[i] as usize;
+    }
+    (Keys::from_array(split), low)
+}
+
+/// Partitions `keys` around its first key, and returns the final index of that key.
+///
+/// Keys less than the pivot are moved before it, along with keys equal to it if `equal_left` is
+/// set, and the rest after it.
+///
+/// Each vector of keys is compared with the pivot and [`split`], then stored whole at both ends
+/// of the space left by the keys already read, as in quicksorts that use compress-store
+/// instructions.  Only the split keys that belong at each end are kept: the next store overwrites
+/// the rest.  The first and last vectors are held back until the end, so that there is always
+/// room for a whole vector at both ends.
+fn partition(keys: &mut [u32], equal_left: bool) -> usize {
+    let n = keys.len();
+    debug_assert!(n > 2 * LANES);
+    let pivot = keys[0];
+    let goes_left = |v: Keys| {
+        if equal_left {
+            v.lanes_le(Keys::splat(pivot))
+        } else {
+            v.lanes_lt(Keys::splat(pivot))
+        }
+    };
+    let (first, last) = (load(keys, 1), load(keys, n - LANES));
+
+    // The keys before `left` and from `right` on are partitioned, and the keys from `read_left`
+    // to `read_right` haven't been read yet.  The space in between always holds two vectors.
+    let (mut left, mut right) = (1, n);
+    let (mut read_left, mut read_right) = (1 + LANES, n - LANES);
+    while read_right - read_left >= LANES {
+        // Reading from the end with less space leaves room for a vector at both ends.
+        let v = if read_left - left <= right - read_right {
+            read_left += LANES;
+            load(keys, read_left - LANES)
+        } else {
+            read_right -= LANES;
+            load(keys, read_right)
+        };
+        let (v, count) = split(v, goes_left(v));
+        keys[left..left + LANES].copy_from_slice(v.as_array());
+        keys[right - LANES..right].copy_from_slice(v.as_array());
+        left += count;
+        right -= LANES - count;
+    }
+
+    // Once the last few keys are read, the space in between is exactly the size of the keys
+    // left to store.
+    let mut rest = [0; LANES];
+    let rest_len = read_right - read_left;
+    rest[..rest_len].copy_from_slice(&keys[read_left..read_right]);
+    for &key in &rest[..rest_len] {
+        if key < pivot || (equal_left && key == pivot) {
+            keys[left] = key;
+            left += 1;
+        } else {
+            right -= 1;
+            keys[right] = key;
+        }
+    }
+    for &v in &[first, last] {
+        let (v, count) = split(v, goes_left(v));
+        keys[left..left + count].copy_from_slice(&v.as_array()[..count]);
+        keys[right - (LANES - count)..right].copy_from_slice(&v.as_array()[count..]);
+        left += count;
+        right -= LANES - count;
+    }
+    debug_assert_eq!(left, right);
+    keys.swap(0, left - 1);
+    left - 1
+}
+
+/// Sorts `keys`, none of which are less than `lower_bound`.
+fn sort_keys(mut keys: &mut [u32], mut lower_bound: Option<u32>, mut depth_limit: u32) {
+    loop {
+        if keys.len() <= SMALL {
+            small_sort(keys);
+            return;
+        }
+        if depth_limit == 0 {
+            keys.sort_unstable();
+            return;
+        }
+        depth_limit -= 1;
+        choose_pivot(keys);
+        let pivot = keys[0];
+        // As in pdqsort, if the pivot equals the lower bound, the keys equal to it are the
+        // smallest and are already in place once moved to the front, so runs of equal keys are
+        // only partitioned once.
+        if lower_bound == Some(pivot) {
+            let mid = partition(keys, true);
+            keys = &mut keys[mid + 1..];
+            continue;
+        }
+        let mid = partition(keys, false);
+        let (left, right) = keys.split_at_mut(mid);
+        let right = &mut right[1..];
+        // Recursing into the shorter side bounds the stack depth by `log2(len)`.
+        if left.len() < right.len() {
+            sort_keys(left, lower_bound, depth_limit);
+            keys = right;
+            lower_bound = Some(pivot);
+        } else {
+            sort_keys(right, Some(pivot), depth_limit);
+            keys = left;
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{LaneCount, Simd, SupportedLaneCount};
+
+mod sealed {
+    pub trait Sealed {}
+}
+use sealed::Sealed;
+
+impl<const LANES: usize> Sealed for Simd<f32, LANES> where LaneCount<LANES>: SupportedLaneCount {}
+impl<const LANES: usize> Sealed for Simd<f64, LANES> where LaneCount<LANES>: SupportedLaneCount {}
+
+/// Float methods that require the standard library, or a math library such as libm, to be
+/// available.
+///
+/// Each method is applied lane-wise and matches the scalar method of the same name.  These may
+/// be lowered to a call to the math library for each lane, rather than a single instruction.
+///
+/// Some of these methods, such as `exp` and `ln`, are also implemented as inherent methods that
+/// don't require the math library.  Method call syntax selects the inherent method, so use
+/// `StdFloat::exp(x)` to call the math library instead.
+///
+/// This trait is sealed and implemented for every float vector.
+pub trait StdFloat: Sealed + Sized {
+    /// Fused multiply-add.  Computes `(self * a) + b` with only one rounding error,
+    /// yielding a more accurate result than an unfused multiply-add.
+    ///
+    /// Using `mul_add` may be more performant than an unfused multiply-add if the target
+    /// architecture has a dedicated `fma` instruction.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn mul_add(self, a: Self, b: Self) -> Self;
+
+    /// Fused multiply-subtract.  Computes `(self * a) - b` with only one rounding error.
+    ///
+    /// This is the `fms`/`vfmsub` shape provided by most targets with `fma` instructions.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn mul_sub(self, a: Self, b: Self) -> Self;
+
+    /// Fused negated multiply-add.  Computes `-(self * a) + b` with only one rounding error.
+    ///
+    /// This is the `fnma`/`vfnmadd` shape provided by most targets with `fma` instructions.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn neg_mul_add(self, a: Self, b: Self) -> Self;
+
+    /// Fused negated multiply-subtract.  Computes `-(self * a) - b` with only one rounding
+    /// error.
+    ///
+    /// This is the `fnms`/`vfnmsub` shape provided by most targets with `fma` instructions.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn neg_mul_sub(self, a: Self, b: Self) -> Self;
+
+    /// Produces a vector where every lane has the square root value of the equivalently-indexed
+    /// lane in `self`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn sqrt(self) -> Self;
+
+    /// Computes the sine of each lane, in radians.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn sin(self) -> Self;
+
+    /// Computes the cosine of each lane, in radians.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn cos(self) -> Self;
+
+    /// Computes the tangent of each lane, in radians.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn tan(self) -> Self;
+
+    /// Raises each lane of `self` to the power of the corresponding lane of `n`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn powf(self, n: Self) -> Self;
+
+    /// Returns `e^(self)` for each lane.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn exp(self) -> Self;
+
+    /// Returns `2^(self)` for each lane.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn exp2(self) -> Self;
+
+    /// Returns the natural logarithm of each lane.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn ln(self) -> Self;
+
+    /// Returns the logarithm of each lane with respect to an arbitrary base.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn log(self, base: Self) -> Self;
+
+    /// Returns the base 2 logarithm of each lane.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn log2(self) -> Self;
+
+    /// Returns the base 10 logarithm of each lane.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn log10(self) -> Self;
+}
+
+macro_rules! impl_std_float {
+    { $($type:ty),* } => {
+        $(
+        impl<const LANES: usize> StdFloat for Simd<$type, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                unsafe { crate::intrinsics::simd_fma(self, a, b) }
+            }
+
+            // Negation is exact, so these are fused multiply-adds with negated operands, which
+            // LLVM selects as the corresponding instructions.
+            #[inline]
+            fn mul_sub(self, a: Self, b: Self) -> Self {
+                unsafe { crate::intrinsics::simd_fma(self, a, -b) }
+            }
+
+            #[inline]
+            fn neg_mul_add(self, a: Self, b: Self) -> Self {
+                unsafe { crate::intrinsics::simd_fma(-self, a, b) }
+            }
+
+            #[inline]
+            fn neg_mul_sub(self, a: Self, b: Self) -> Self {
+                unsafe { crate::intrinsics::simd_fma(-self, a, -b) }
+            }
+
+            #[inline]
+            fn sqrt(self) -> Self {
+                unsafe { crate::intrinsics::simd_fsqrt(self) }
+            }
+
+            #[inline]
+            fn sin(self) -> Self {
+                unsafe { crate::intrinsics::simd_fsin(self) }
+            }
+
+            #[inline]
+            fn cos(self) -> Self {
+                unsafe { crate::intrinsics::simd_fcos(self) }
+            }
+
+            // There is no vector intrinsic for `tan`, so each lane calls the scalar method, which
+            // is also how LLVM lowers the other intrinsics on most targets.
+            #[inline]
+            fn tan(self) -> Self {
+                let mut x = self.to_array();
+                for x in x.iter_mut() {
+                    *x = x.tan();
+                }
+                Self::from_array(x)
+            }
+
+            #[inline]
+            fn powf(self, n: Self) -> Self {
+                unsafe { crate::intrinsics::simd_fpow(self, n) }
+            }
+
+            #[inline]
+            fn exp(self) -> Self {
+                unsafe { crate::intrinsics::simd_fexp(self) }
+            }
+
+            #[inline]
+            fn exp2(self) -> Self {
+                unsafe { crate::intrinsics::simd_fexp2(self) }
+            }
+
+            #[inline]
+            fn ln(self) -> Self {
+                unsafe { crate::intrinsics::simd_flog(self) }
+            }
+
+            #[inline]
+            fn log(self, base: Self) -> Self {
+                StdFloat::ln(self) / StdFloat::ln(base)
+            }
+
+            #[inline]
+            fn log2(self) -> Self {
+                unsafe { crate::intrinsics::simd_flog2(self) }
+            }
+
+            #[inline]
+            fn log10(self) -> Self {
+                unsafe { crate::intrinsics::simd_flog10(self) }
+            }
+        }
+        )*
+    }
+}
+
+impl_std_float! { f32, f64 }
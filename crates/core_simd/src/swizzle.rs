@@ -0,0 +1,135 @@
+//! Ready-made lane patterns for [`Simd::swizzle`](crate::Simd::swizzle) and
+//! [`Simd::swizzle2`](crate::Simd::swizzle2).
+
+/// A lane pattern that can be applied to vectors with `LANES` lanes.
+///
+/// Implement this on a marker type to name a shuffle once and reuse it with
+/// [`Simd::swizzle`](crate::Simd::swizzle) and [`Simd::swizzle2`](crate::Simd::swizzle2).  The
+/// patterns in this module are implemented for every lane count.
+///
+/// ```
+/// # use core_simd::{Swizzle, SimdU32};
+/// struct SwapHalves;
+///
+/// impl Swizzle<4> for SwapHalves {
+///     const INDEX: [u32; 4] = [2, 3, 0, 1];
+/// }
+///
+/// let a = SimdU32::from_array([0, 1, 2, 3]);
+/// assert_eq!(a.swizzle::<SwapHalves>().to_array(), [2, 3, 0, 1]);
+/// ```
+pub trait Swizzle<const LANES: usize> {
+    /// The index of the lane each output lane is taken from.  Lanes of the first vector are
+    /// indexed from 0 and lanes of the second vector from `LANES`; a single-vector swizzle uses
+    /// the same vector for both.  Indices must be less than `2 * LANES`.
+    const INDEX: [u32; LANES];
+}
+
+/// Reverses the order of the lanes.
+#[derive(Copy, Clone, Debug)]
+pub struct Reverse;
+
+impl<const LANES: usize> Swizzle<LANES> for Reverse {
+    const INDEX: [u32; LANES] = reverse::<LANES>();
+}
+
+const fn reverse<const N: usize>() -> [u32; N] {
+    let mut idx = [0u32; N];
+    let mut i = 0;
+    while i < N {
+        idx[i] = (N - i - 1) as u32;
+        i += 1;
+    }
+    idx
+}
+
+/// Rotates the lanes toward the start of the vector by `OFFSET` lanes, so lane `OFFSET` becomes
+/// the first lane.  Offsets of at least `LANES` wrap around.
+#[derive(Copy, Clone, Debug)]
+pub struct RotateLanesLeft<const OFFSET: usize>;
+
+impl<const OFFSET: usize, const LANES: usize> Swizzle<LANES> for RotateLanesLeft<OFFSET> {
+    const INDEX: [u32; LANES] = rotate_left::<LANES>(OFFSET % LANES);
+}
+
+/// Rotates the lanes toward the end of the vector by `OFFSET` lanes, so the first lane becomes
+/// lane `OFFSET`.  Offsets of at least `LANES` wrap around.
+#[derive(Copy, Clone, Debug)]
+pub struct RotateLanesRight<const OFFSET: usize>;
+
+impl<const OFFSET: usize, const LANES: usize> Swizzle<LANES> for RotateLanesRight<OFFSET> {
+    const INDEX: [u32; LANES] = rotate_left::<LANES>((LANES - OFFSET % LANES) % LANES);
+}
+
+pub(crate) const fn rotate_left<const N: usize>(offset: usize) -> [u32; N] {
+    let mut idx = [0u32; N];
+    let mut i = 0;
+    while i < N {
+        idx[i] = ((i + offset) % N) as u32;
+        i += 1;
+    }
+    idx
+}
+
+/// Alternates the first `LANES / 2` lanes of two vectors, starting with the first lane of the
+/// first vector.
+#[derive(Copy, Clone, Debug)]
+pub struct InterleaveLo;
+
+impl<const LANES: usize> Swizzle<LANES> for InterleaveLo {
+    const INDEX: [u32; LANES] = interleave::<LANES>(0);
+}
+
+/// Alternates the last `LANES / 2` lanes of two vectors, starting with lane `LANES / 2` of the
+/// first vector.
+#[derive(Copy, Clone, Debug)]
+pub struct InterleaveHi;
+
+impl<const LANES: usize> Swizzle<LANES> for InterleaveHi {
+    const INDEX: [u32; LANES] = interleave::<LANES>(LANES / 2);
+}
+
+const fn interleave<const N: usize>(start: usize) -> [u32; N] {
+    let mut idx = [0u32; N];
+    let mut i = 0;
+    while i < N {
+        let offset = start + i / 2;
+        idx[i] = if i % 2 == 0 { offset } else { N + offset } as u32;
+        i += 1;
+    }
+    idx
+}
+
+/// Copies each even lane over the odd lane after it.
+#[derive(Copy, Clone, Debug)]
+pub struct DuplicateEven;
+
+impl<const LANES: usize> Swizzle<LANES> for DuplicateEven {
+    const INDEX: [u32; LANES] = duplicate::<LANES>(0);
+}
+
+/// Copies each odd lane over the even lane before it.
+#[derive(Copy, Clone, Debug)]
+pub struct DuplicateOdd;
+
+impl<const LANES: usize> Swizzle<LANES> for DuplicateOdd {
+    const INDEX: [u32; LANES] = duplicate::<LANES>(1);
+}
+
+const fn duplicate<const N: usize>(offset: usize) -> [u32; N] {
+    let mut idx = [0u32; N];
+    let mut i = 0;
+    while i < N {
+        idx[i] = (i - i % 2 + offset) as u32;
+        i += 1;
+    }
+    idx
+}
+
+/// Copies lane `LANE` to every lane.  `LANE` must be less than `LANES`.
+#[derive(Copy, Clone, Debug)]
+pub struct BroadcastLane<const LANE: usize>;
+
+impl<const LANE: usize, const LANES: usize> Swizzle<LANES> for BroadcastLane<LANE> {
+    const INDEX: [u32; LANES] = [LANE as u32; LANES];
+}
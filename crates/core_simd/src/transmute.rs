@@ -1,7 +1,22 @@
 /// Provides implementations of `From<$a> for $b` and `From<$b> for $a` that transmutes the value.
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    all(target_arch = "arm", target_feature = "v7"),
+    all(target_arch = "wasm32", target_feature = "simd128"),
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+))]
 macro_rules! from_transmute {
     { unsafe $a:ty => $b:ty } => {
+        // Fail to compile if the types differ in size or alignment, rather than relying on every
+        // invocation being correct.
+        const _: [(); 1] =
+            [(); (core::mem::size_of::<$a>() == core::mem::size_of::<$b>()) as usize];
+        const _: [(); 1] =
+            [(); (core::mem::align_of::<$a>() == core::mem::align_of::<$b>()) as usize];
+
         from_transmute!{ @impl $a => $b }
         from_transmute!{ @impl $b => $a }
     };
@@ -26,3 +41,38 @@ macro_rules! from_transmute_x86 {
         from_transmute! { unsafe $generic => core::arch::x86_64::$intel }
     }
 }
+
+/// Provides implementations of `From<$generic> for core::arch::{arm, aarch64}::$neon` and
+/// vice-versa that transmutes the value.
+///
+/// NEON types are only available on 32-bit ARM when targeting ARMv7 or later.
+macro_rules! from_transmute_neon {
+    { unsafe $generic:ty => $neon:ident } => {
+        #[cfg(all(target_arch = "arm", target_feature = "v7"))]
+        from_transmute! { unsafe $generic => core::arch::arm::$neon }
+
+        #[cfg(target_arch = "aarch64")]
+        from_transmute! { unsafe $generic => core::arch::aarch64::$neon }
+    }
+}
+
+/// Provides implementations of `From<$generic> for core::arch::wasm32::$wasm` and vice-versa
+/// that transmutes the value.
+macro_rules! from_transmute_wasm32 {
+    { unsafe $generic:ty => $wasm:ident } => {
+        #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+        from_transmute! { unsafe $generic => core::arch::wasm32::$wasm }
+    }
+}
+
+/// Provides implementations of `From<$generic> for core::arch::{powerpc, powerpc64}::$power` and
+/// vice-versa that transmutes the value.
+macro_rules! from_transmute_powerpc {
+    { unsafe $generic:ty => $power:ident } => {
+        #[cfg(target_arch = "powerpc")]
+        from_transmute! { unsafe $generic => core::arch::powerpc::$power }
+
+        #[cfg(target_arch = "powerpc64")]
+        from_transmute! { unsafe $generic => core::arch::powerpc64::$power }
+    }
+}
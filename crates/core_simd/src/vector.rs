@@ -1,7 +1,732 @@
+mod fixed;
 mod float;
 mod int;
+mod native;
+pub(crate) mod ptr;
 mod uint;
 
+#[cfg(feature = "bf16")]
+mod bf16;
+#[cfg(feature = "f16")]
+mod f16;
+
+pub use fixed::*;
 pub use float::*;
 pub use int::*;
+pub use native::*;
 pub use uint::*;
+
+#[cfg(feature = "bf16")]
+pub use bf16::*;
+#[cfg(feature = "f16")]
+pub use f16::*;
+
+use crate::{LaneCount, Mask, MaskElement, SupportedLaneCount};
+
+/// A SIMD vector of `LANES` elements of type `T`.
+///
+/// # Layout
+/// A vector has the same size as `[T; LANES]`, and is aligned to at least the alignment of `T`.
+/// The exact alignment depends on the target, but is typically the size of the vector; it is
+/// available as [`Simd::ALIGN`].
+#[repr(simd)]
+pub struct Simd<T, const LANES: usize>([T; LANES])
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount;
+
+impl<T, const LANES: usize> Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Number of lanes in this vector.
+    pub const LANES: usize = LANES;
+
+    /// The alignment of this vector type, in bytes.
+    ///
+    /// Pointers aligned to this value may be used for loads and stores of the whole vector.
+    pub const ALIGN: usize = core::mem::align_of::<Self>();
+
+    /// Get the number of lanes in this vector.
+    pub const fn lanes(&self) -> usize {
+        LANES
+    }
+
+    /// Construct a SIMD vector by setting all lanes to the given value.
+    pub const fn splat(value: T) -> Self {
+        Self([value; LANES])
+    }
+
+    /// Returns a slice containing the entire SIMD vector.
+    pub const fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Returns a mutable slice containing the entire SIMD vector.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+
+    /// Returns a reference to the array containing the entire SIMD vector.
+    ///
+    /// Unlike [`to_array`](Self::to_array), this doesn't copy the vector.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let v = u32x4::from_array([1, 2, 3, 4]);
+    /// let [first, .., last] = v.as_array();
+    /// assert_eq!((*first, *last), (1, 4));
+    /// ```
+    pub const fn as_array(&self) -> &[T; LANES] {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the array containing the entire SIMD vector.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mut v = u32x4::from_array([1, 2, 3, 4]);
+    /// v.as_mut_array().swap(0, 3);
+    /// assert_eq!(v.to_array(), [4, 2, 3, 1]);
+    /// ```
+    pub fn as_mut_array(&mut self) -> &mut [T; LANES] {
+        &mut self.0
+    }
+
+    /// Converts an array to a SIMD vector.
+    pub const fn from_array(array: [T; LANES]) -> Self {
+        Self(array)
+    }
+
+    /// Converts a SIMD vector to an array.
+    pub const fn to_array(self) -> [T; LANES] {
+        self.0
+    }
+
+    /// Returns a copy of the vector with lane `index` replaced by `value`.
+    ///
+    /// Unlike assigning through `IndexMut`, this works on temporaries and in const contexts.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// const V: u32x4 = u32x4::splat(0).with_lane(2, 7);
+    /// assert_eq!(V.to_array(), [0, 0, 7, 0]);
+    /// ```
+    pub const fn with_lane(self, index: usize, value: T) -> Self {
+        let mut array = self.to_array();
+        array[index] = value;
+        Self::from_array(array)
+    }
+
+    /// Returns a copy of the vector with lane `INDEX` replaced by `value`.
+    ///
+    /// This is [`with_lane`](Self::with_lane) with the index checked at compile time.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let v = f32x4::splat(1.).with_lane_const::<0>(-1.);
+    /// assert_eq!(v.to_array(), [-1., 1., 1., 1.]);
+    /// ```
+    ///
+    /// An out of bounds `INDEX` fails to compile:
+    /// ```compile_fail
+    /// # use core_simd::*;
+    /// let v = f32x4::splat(1.).with_lane_const::<4>(-1.);
+    /// ```
+    pub const fn with_lane_const<const INDEX: usize>(self, value: T) -> Self {
+        let _ = LaneIndex::<INDEX, LANES>::IN_BOUNDS;
+        self.with_lane(INDEX, value)
+    }
+
+    /// Reads the elements of `slice` at the indices in `idxs`, so lane `i` is
+    /// `slice[idxs[i]]`.  Lanes whose index is out of bounds are taken from `or` instead.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let vec = [10, 11, 12, 13, 14, 15, 16, 17, 18];
+    /// let idxs = SimdUsize::<4>::from_array([9, 3, 0, 5]);
+    /// let alt = SimdI32::from_array([-5, -4, -3, -2]);
+    /// let result = SimdI32::<4>::gather_or(&vec, idxs, alt);
+    /// assert_eq!(result.to_array(), [-5, 13, 10, 15]);
+    /// ```
+    #[inline]
+    pub fn gather_or(slice: &[T], idxs: Simd<usize, LANES>, or: Self) -> Self {
+        Self::gather_select(slice, Mask::splat(true), idxs, or)
+    }
+
+    /// Reads the elements of `slice` at the indices in `idxs`, like
+    /// [`gather_or`](Self::gather_or), with out of bounds lanes set to the default value.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let vec = [10, 11, 12, 13, 14, 15, 16, 17, 18];
+    /// let idxs = SimdUsize::<4>::from_array([9, 3, 0, 5]);
+    /// let result = SimdI32::<4>::gather_or_default(&vec, idxs);
+    /// assert_eq!(result.to_array(), [0, 13, 10, 15]);
+    /// ```
+    #[inline]
+    pub fn gather_or_default(slice: &[T], idxs: Simd<usize, LANES>) -> Self
+    where
+        T: Default,
+    {
+        Self::gather_or(slice, idxs, Self::splat(T::default()))
+    }
+
+    /// Reads the elements of `slice` at the indices in `idxs` for the lanes set in `enable`,
+    /// like [`gather_or`](Self::gather_or).  Lanes that aren't enabled, or whose index is out of
+    /// bounds, are taken from `or` without reading `slice`.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let vec = [10, 11, 12, 13, 14, 15, 16, 17, 18];
+    /// let idxs = SimdUsize::<4>::from_array([9, 3, 0, 5]);
+    /// let alt = SimdI32::from_array([-5, -4, -3, -2]);
+    /// let enable = MaskSize::from_array([true, true, true, false]);
+    /// let result = SimdI32::<4>::gather_select(&vec, enable, idxs, alt);
+    /// assert_eq!(result.to_array(), [-5, 13, 10, -2]);
+    /// ```
+    #[inline]
+    pub fn gather_select(
+        slice: &[T],
+        enable: Mask<isize, LANES>,
+        idxs: Simd<usize, LANES>,
+        or: Self,
+    ) -> Self {
+        let enable = enable & idxs.lanes_lt(Simd::splat(slice.len()));
+        let ptrs = ptr::SimdConstPtr::splat(slice.as_ptr()).wrapping_add(idxs);
+        // Safety: only the enabled lanes are read, and their indices are in bounds.
+        unsafe { crate::intrinsics::simd_gather(or, ptrs, enable.to_int()) }
+    }
+
+    /// Writes the lanes to the elements of `slice` at the indices in `idxs`, so lane `i` is
+    /// written to `slice[idxs[i]]`.  Lanes whose index is out of bounds aren't written.
+    ///
+    /// If several lanes have the same index, the last of them is written.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mut vec = [10, 11, 12, 13, 14, 15, 16, 17, 18];
+    /// let idxs = SimdUsize::<4>::from_array([9, 3, 0, 0]);
+    /// let vals = SimdI32::from_array([-27, 82, -41, 124]);
+    /// vals.scatter(&mut vec, idxs);
+    /// assert_eq!(vec, [124, 11, 12, 82, 14, 15, 16, 17, 18]);
+    /// ```
+    #[inline]
+    pub fn scatter(self, slice: &mut [T], idxs: Simd<usize, LANES>) {
+        self.scatter_select(slice, Mask::splat(true), idxs)
+    }
+
+    /// Writes the lanes set in `enable` to the elements of `slice` at the indices in `idxs`,
+    /// like [`scatter`](Self::scatter).  Lanes that aren't enabled, or whose index is out of
+    /// bounds, aren't written.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mut vec = [10, 11, 12, 13, 14, 15, 16, 17, 18];
+    /// let idxs = SimdUsize::<4>::from_array([9, 3, 0, 0]);
+    /// let vals = SimdI32::from_array([-27, 82, -41, 124]);
+    /// let enable = MaskSize::from_array([true, true, true, false]);
+    /// vals.scatter_select(&mut vec, enable, idxs);
+    /// assert_eq!(vec, [-41, 11, 12, 82, 14, 15, 16, 17, 18]);
+    /// ```
+    #[inline]
+    pub fn scatter_select(
+        self,
+        slice: &mut [T],
+        enable: Mask<isize, LANES>,
+        idxs: Simd<usize, LANES>,
+    ) {
+        let enable = enable & idxs.lanes_lt(Simd::splat(slice.len()));
+        let ptrs = ptr::SimdMutPtr::splat(slice.as_mut_ptr()).wrapping_add(idxs);
+        // Safety: only the enabled lanes are written, and their indices are in bounds.
+        unsafe { crate::intrinsics::simd_scatter(self, ptrs, enable.to_int()) }
+    }
+
+    /// Loads a SIMD vector from the first `LANES` elements of an aligned slice.
+    ///
+    /// The load is a single aligned move, rather than a move that must handle any alignment.
+    ///
+    /// # Safety
+    /// `slice` must contain at least `LANES` elements, and its start must be aligned to
+    /// [`Self::ALIGN`].  Both are checked with debug assertions.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let buffer = [f32x4::from_array([1., 2., 3., 4.]); 2];
+    /// let lanes = unsafe { core::slice::from_raw_parts(buffer.as_ptr().cast::<f32>(), 8) };
+    /// let v = unsafe { f32x4::from_slice_aligned_unchecked(&lanes[4..]) };
+    /// assert_eq!(v.to_array(), [1., 2., 3., 4.]);
+    /// ```
+    #[inline]
+    pub unsafe fn from_slice_aligned_unchecked(slice: &[T]) -> Self {
+        debug_assert!(
+            slice.len() >= LANES,
+            "slice must contain at least `LANES` elements"
+        );
+        debug_assert!(
+            slice.as_ptr().align_offset(Self::ALIGN) == 0,
+            "slice must be aligned to `Self::ALIGN`"
+        );
+        slice.as_ptr().cast::<Self>().read()
+    }
+
+    /// Stores a SIMD vector to the first `LANES` elements of an aligned slice.
+    ///
+    /// The store is a single aligned move, rather than a move that must handle any alignment.
+    ///
+    /// # Safety
+    /// `slice` must contain at least `LANES` elements, and its start must be aligned to
+    /// [`Self::ALIGN`].  Both are checked with debug assertions.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mut buffer = [f32x4::splat(0.); 2];
+    /// let lanes = unsafe { core::slice::from_raw_parts_mut(buffer.as_mut_ptr().cast::<f32>(), 8) };
+    /// unsafe { f32x4::from_array([1., 2., 3., 4.]).copy_to_slice_aligned_unchecked(&mut lanes[4..]) };
+    /// assert_eq!(buffer[1].to_array(), [1., 2., 3., 4.]);
+    /// ```
+    #[inline]
+    pub unsafe fn copy_to_slice_aligned_unchecked(self, slice: &mut [T]) {
+        debug_assert!(
+            slice.len() >= LANES,
+            "slice must contain at least `LANES` elements"
+        );
+        debug_assert!(
+            slice.as_ptr().align_offset(Self::ALIGN) == 0,
+            "slice must be aligned to `Self::ALIGN`"
+        );
+        slice.as_mut_ptr().cast::<Self>().write(self)
+    }
+
+    /// Reads a SIMD vector from `LANES` consecutive elements starting at `ptr`, which need not
+    /// be aligned.
+    ///
+    /// # Safety
+    /// `ptr` must be valid for reads of `LANES` elements, as for [`core::ptr::read_unaligned`].
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let array = [1, 2, 3, 4, 5];
+    /// let v = unsafe { i32x4::read_unaligned(array[1..].as_ptr()) };
+    /// assert_eq!(v.to_array(), [2, 3, 4, 5]);
+    /// ```
+    #[inline]
+    pub unsafe fn read_unaligned(ptr: *const T) -> Self {
+        ptr.cast::<Self>().read_unaligned()
+    }
+
+    /// Writes a SIMD vector to `LANES` consecutive elements starting at `ptr`, which need not
+    /// be aligned.
+    ///
+    /// # Safety
+    /// `ptr` must be valid for writes of `LANES` elements, as for
+    /// [`core::ptr::write_unaligned`].
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mut array = [0; 5];
+    /// unsafe { i32x4::from_array([2, 3, 4, 5]).write_unaligned(array[1..].as_mut_ptr()) };
+    /// assert_eq!(array, [0, 2, 3, 4, 5]);
+    /// ```
+    #[inline]
+    pub unsafe fn write_unaligned(self, ptr: *mut T) {
+        ptr.cast::<Self>().write_unaligned(self)
+    }
+
+    /// Performs a volatile read of a SIMD vector from `LANES` consecutive elements starting at
+    /// `ptr`.
+    ///
+    /// Volatile reads are never elided or merged with other accesses, which is needed for
+    /// memory-mapped I/O and memory shared with DMA.  Whether the vector is read with a single
+    /// access depends on the target.
+    ///
+    /// # Safety
+    /// `ptr` must be valid for reads of `LANES` elements, and aligned to [`Self::ALIGN`], as for
+    /// [`core::ptr::read_volatile`].
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let register = u32x4::from_array([1, 2, 3, 4]);
+    /// let v = unsafe { u32x4::read_volatile(register.as_slice().as_ptr()) };
+    /// assert_eq!(v, register);
+    /// ```
+    #[inline]
+    pub unsafe fn read_volatile(ptr: *const T) -> Self {
+        ptr.cast::<Self>().read_volatile()
+    }
+
+    /// Performs a volatile write of a SIMD vector to `LANES` consecutive elements starting at
+    /// `ptr`.
+    ///
+    /// Volatile writes are never elided or merged with other accesses.  See
+    /// [`read_volatile`](Self::read_volatile).
+    ///
+    /// # Safety
+    /// `ptr` must be valid for writes of `LANES` elements, and aligned to [`Self::ALIGN`], as
+    /// for [`core::ptr::write_volatile`].
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mut register = u32x4::splat(0);
+    /// unsafe { u32x4::from_array([1, 2, 3, 4]).write_volatile(register.as_mut_slice().as_mut_ptr()) };
+    /// assert_eq!(register.to_array(), [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub unsafe fn write_volatile(self, ptr: *mut T) {
+        ptr.cast::<Self>().write_volatile(self)
+    }
+
+    /// Views a slice of SIMD vectors as a slice of their lanes.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let vectors = [u32x4::from_array([1, 2, 3, 4]), u32x4::from_array([5, 6, 7, 8])];
+    /// assert_eq!(u32x4::flatten_slice(&vectors), [1, 2, 3, 4, 5, 6, 7, 8]);
+    /// ```
+    #[inline]
+    pub fn flatten_slice(vectors: &[Self]) -> &[T] {
+        // Safety: a vector has the same size as `[T; LANES]`, and is at least as aligned.
+        unsafe { core::slice::from_raw_parts(vectors.as_ptr().cast::<T>(), vectors.len() * LANES) }
+    }
+
+    /// Views a mutable slice of SIMD vectors as a mutable slice of their lanes.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mut vectors = [u32x4::splat(0); 2];
+    /// u32x4::flatten_slice_mut(&mut vectors)[4] = 1;
+    /// assert_eq!(vectors[1].to_array(), [1, 0, 0, 0]);
+    /// ```
+    #[inline]
+    pub fn flatten_slice_mut(vectors: &mut [Self]) -> &mut [T] {
+        // Safety: as in `flatten_slice`.
+        unsafe {
+            core::slice::from_raw_parts_mut(vectors.as_mut_ptr().cast::<T>(), vectors.len() * LANES)
+        }
+    }
+
+    /// Views a slice of elements as a slice of SIMD vectors.
+    ///
+    /// Returns `None` if the length of `slice` isn't a multiple of `LANES`, or if `slice` isn't
+    /// aligned to [`Self::ALIGN`].  This is the inverse of [`flatten_slice`](Self::flatten_slice).
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let vectors = [u32x4::from_array([1, 2, 3, 4]), u32x4::from_array([5, 6, 7, 8])];
+    /// let lanes = u32x4::flatten_slice(&vectors);
+    /// assert_eq!(u32x4::unflatten_slice(lanes), Some(&vectors[..]));
+    /// assert_eq!(u32x4::unflatten_slice(&lanes[..6]), None);
+    /// assert_eq!(u32x4::unflatten_slice(&lanes[1..5]), None);
+    /// ```
+    #[inline]
+    pub fn unflatten_slice(slice: &[T]) -> Option<&[Self]> {
+        let len = slice.len() / LANES;
+        if len * LANES != slice.len() || slice.as_ptr().align_offset(Self::ALIGN) != 0 {
+            return None;
+        }
+        // Safety: the slice is aligned and contains a whole number of vectors.
+        Some(unsafe { core::slice::from_raw_parts(slice.as_ptr().cast::<Self>(), len) })
+    }
+
+    /// Views a mutable slice of elements as a mutable slice of SIMD vectors.
+    ///
+    /// Returns `None` under the same conditions as [`unflatten_slice`](Self::unflatten_slice).
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let mut vectors = [u32x4::splat(0); 2];
+    /// let lanes = u32x4::flatten_slice_mut(&mut vectors);
+    /// u32x4::unflatten_slice_mut(lanes).unwrap()[1] = u32x4::splat(1);
+    /// assert_eq!(vectors[1].to_array(), [1; 4]);
+    /// ```
+    #[inline]
+    pub fn unflatten_slice_mut(slice: &mut [T]) -> Option<&mut [Self]> {
+        let len = slice.len() / LANES;
+        if len * LANES != slice.len() || slice.as_ptr().align_offset(Self::ALIGN) != 0 {
+            return None;
+        }
+        // Safety: as in `unflatten_slice`.
+        Some(unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast::<Self>(), len) })
+    }
+}
+
+/// A lane index known at compile time.
+struct LaneIndex<const INDEX: usize, const LANES: usize>;
+
+impl<const INDEX: usize, const LANES: usize> LaneIndex<INDEX, LANES> {
+    /// Fails to evaluate, and so to compile, if `INDEX` is out of bounds.
+    const IN_BOUNDS: usize = LANES - 1 - INDEX;
+}
+
+impl<T, const LANES: usize> Copy for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
+
+impl<T, const LANES: usize> Clone for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const LANES: usize> Default for Simd<T, LANES>
+where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::splat(T::default())
+    }
+}
+
+impl<T, const LANES: usize> PartialEq for Simd<T, LANES>
+where
+    T: SimdElement + PartialEq,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        // TODO use SIMD equality
+        self.to_array() == other.to_array()
+    }
+}
+
+impl<T, const LANES: usize> PartialOrd for Simd<T, LANES>
+where
+    T: SimdElement + PartialOrd,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        // TODO use SIMD equality
+        self.to_array().partial_cmp(other.as_ref())
+    }
+}
+
+impl<T, const LANES: usize> Eq for Simd<T, LANES>
+where
+    T: SimdElement + Eq,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
+
+impl<T, const LANES: usize> Ord for Simd<T, LANES>
+where
+    T: SimdElement + Ord,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        // TODO use SIMD cmp
+        self.to_array().cmp(other.as_ref())
+    }
+}
+
+impl<T, const LANES: usize> core::hash::Hash for Simd<T, LANES>
+where
+    T: SimdElement + core::hash::Hash,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: core::hash::Hasher,
+    {
+        // The lane count is part of the type, so write the lanes' bytes in a single call without
+        // a length prefix.
+        let lanes = self.as_array();
+        // Safety: the only hashable element types are the primitive integers, which have no
+        // padding, so every byte is initialized.
+        let bytes = unsafe {
+            core::slice::from_raw_parts(lanes.as_ptr().cast::<u8>(), core::mem::size_of_val(lanes))
+        };
+        state.write(bytes)
+    }
+}
+
+// array references
+impl<T, const LANES: usize> AsRef<[T; LANES]> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn as_ref(&self) -> &[T; LANES] {
+        &self.0
+    }
+}
+
+impl<T, const LANES: usize> AsMut<[T; LANES]> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T; LANES] {
+        &mut self.0
+    }
+}
+
+// slice references
+impl<T, const LANES: usize> AsRef<[T]> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const LANES: usize> AsMut<[T]> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+// vector/array conversion
+impl<T, const LANES: usize> From<[T; LANES]> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn from(array: [T; LANES]) -> Self {
+        Self(array)
+    }
+}
+
+impl<T, const LANES: usize> From<Simd<T, LANES>> for [T; LANES]
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn from(vector: Simd<T, LANES>) -> Self {
+        vector.to_array()
+    }
+}
+
+/// Converts a slice of exactly `LANES` elements to a SIMD vector, failing for any other length.
+///
+/// ```
+/// # use core_simd::*;
+/// # use core::convert::TryFrom;
+/// let v = u32x4::try_from(&[1, 2, 3, 4][..]).unwrap();
+/// assert_eq!(v.to_array(), [1, 2, 3, 4]);
+/// assert!(u32x4::try_from(&[1, 2, 3][..]).is_err());
+/// ```
+impl<T, const LANES: usize> core::convert::TryFrom<&[T]> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Error = core::array::TryFromSliceError;
+
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        <[T; LANES]>::try_from(slice).map(Self::from_array)
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+use sealed::Sealed;
+
+/// Marker trait for types that may be used as SIMD vector elements.
+///
+/// # Safety
+/// This trait, when implemented, asserts the compiler can monomorphize `#[repr(simd)]` structs
+/// with the marked type as an element.  Strictly, it is valid to impl if the vector will not be
+/// miscompiled.  Practically, it is user-unfriendly to impl it if the vector won't compile, even
+/// when no soundness guarantees are broken by allowing the user to try.
+pub unsafe trait SimdElement: Sealed + Copy {
+    /// The mask element type corresponding to this element type.
+    type Mask: MaskElement;
+}
+
+impl Sealed for u8 {}
+unsafe impl SimdElement for u8 {
+    type Mask = i8;
+}
+
+impl Sealed for u16 {}
+unsafe impl SimdElement for u16 {
+    type Mask = i16;
+}
+
+impl Sealed for u32 {}
+unsafe impl SimdElement for u32 {
+    type Mask = i32;
+}
+
+impl Sealed for u64 {}
+unsafe impl SimdElement for u64 {
+    type Mask = i64;
+}
+
+impl Sealed for u128 {}
+unsafe impl SimdElement for u128 {
+    type Mask = i128;
+}
+
+impl Sealed for usize {}
+unsafe impl SimdElement for usize {
+    type Mask = isize;
+}
+
+impl Sealed for i8 {}
+unsafe impl SimdElement for i8 {
+    type Mask = i8;
+}
+
+impl Sealed for i16 {}
+unsafe impl SimdElement for i16 {
+    type Mask = i16;
+}
+
+impl Sealed for i32 {}
+unsafe impl SimdElement for i32 {
+    type Mask = i32;
+}
+
+impl Sealed for i64 {}
+unsafe impl SimdElement for i64 {
+    type Mask = i64;
+}
+
+impl Sealed for i128 {}
+unsafe impl SimdElement for i128 {
+    type Mask = i128;
+}
+
+impl Sealed for isize {}
+unsafe impl SimdElement for isize {
+    type Mask = isize;
+}
+
+impl Sealed for f32 {}
+unsafe impl SimdElement for f32 {
+    type Mask = i32;
+}
+
+impl Sealed for f64 {}
+unsafe impl SimdElement for f64 {
+    type Mask = i64;
+}
@@ -0,0 +1,174 @@
+#![allow(non_camel_case_types)]
+
+use crate::{LaneCount, SimdF32, SimdU16, SimdU32, SupportedLaneCount};
+
+/// A SIMD vector of containing `LANES` brain floating point (bfloat16) values.
+///
+/// A bfloat16 is the upper half of an IEEE 754 single-precision value: it has the same range as
+/// `f32`, but only 8 bits of precision.  Rust has no primitive bfloat16 type, so each lane is
+/// stored as its raw `u16` bit pattern.
+#[repr(transparent)]
+pub struct SimdBf16<const LANES: usize>(SimdU16<LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount;
+
+impl<const LANES: usize> SimdBf16<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Number of lanes in this vector.
+    pub const LANES: usize = LANES;
+
+    /// Get the number of lanes in this vector.
+    pub const fn lanes(&self) -> usize {
+        LANES
+    }
+
+    /// Raw transmutation from an unsigned integer vector containing bfloat16 bit patterns.
+    #[inline]
+    pub fn from_bits(bits: SimdU16<LANES>) -> Self {
+        Self(bits)
+    }
+
+    /// Raw transmutation to an unsigned integer vector containing bfloat16 bit patterns.
+    #[inline]
+    pub fn to_bits(self) -> SimdU16<LANES> {
+        self.0
+    }
+
+    /// Construct a SIMD vector by setting all lanes to the given bfloat16 bit pattern.
+    #[inline]
+    pub fn splat_bits(bits: u16) -> Self {
+        Self(SimdU16::splat(bits))
+    }
+
+    /// Loads a vector from the first `LANES` bfloat16 bit patterns in `slice`.
+    ///
+    /// # Panics
+    /// Panics if the slice's length is less than the number of lanes in the vector.
+    #[inline]
+    pub fn from_slice(slice: &[u16]) -> Self {
+        assert!(
+            slice.len() >= LANES,
+            "slice length must be at least the number of lanes"
+        );
+        let mut bits = SimdU16::splat(0);
+        bits.as_mut_slice().copy_from_slice(&slice[..LANES]);
+        Self(bits)
+    }
+
+    /// Stores the bfloat16 bit patterns of the vector in the first `LANES` elements of `slice`.
+    ///
+    /// # Panics
+    /// Panics if the slice's length is less than the number of lanes in the vector.
+    #[inline]
+    pub fn copy_to_slice(self, slice: &mut [u16]) {
+        assert!(
+            slice.len() >= LANES,
+            "slice length must be at least the number of lanes"
+        );
+        slice[..LANES].copy_from_slice(self.0.as_slice());
+    }
+
+    /// Construct a SIMD vector by converting `value` to bfloat16 and setting all lanes to it.
+    #[inline]
+    pub fn splat(value: f32) -> Self {
+        Self::from_f32(SimdF32::splat(value))
+    }
+
+    /// Converts each lane to bfloat16, rounding to nearest with ties to even.
+    ///
+    /// Values too large to be represented become infinity, and `NaN`s remain `NaN`.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let x = SimdBf16::from_f32(SimdF32::from_array([1.0, -2.0, 1.00390625, f32::MAX]));
+    /// assert_eq!(x.to_bits().to_array(), [0x3f80, 0xc000, 0x3f80, 0x7f80]);
+    /// ```
+    #[inline]
+    pub fn from_f32(value: SimdF32<LANES>) -> Self {
+        let bits = value.to_bits();
+        let odd = (bits >> 16) & SimdU32::splat(1);
+        let rounded = bits.wrapping_add(SimdU32::splat(0x7fff)).wrapping_add(odd) >> 16;
+        // Truncating a NaN may clear all of its mantissa bits, so force it to stay quiet.
+        let nan = (bits >> 16) | SimdU32::splat(0x0040);
+        let result = value.is_nan().select(nan, rounded);
+        Self(unsafe { crate::intrinsics::simd_cast(result) })
+    }
+
+    /// Converts each lane to single precision.  This conversion is exact.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let x = SimdBf16::from_bits(SimdU16::from_array([0x3f80, 0xc000, 0x3f81, 0xff80]));
+    /// assert_eq!(x.to_f32().to_array(), [1.0, -2.0, 1.0078125, f32::NEG_INFINITY]);
+    /// ```
+    #[inline]
+    pub fn to_f32(self) -> SimdF32<LANES> {
+        let bits: SimdU32<LANES> = unsafe { crate::intrinsics::simd_cast(self.0) };
+        SimdF32::from_bits(bits << 16)
+    }
+}
+
+impl<const LANES: usize> Copy for SimdBf16<LANES> where LaneCount<LANES>: SupportedLaneCount {}
+
+impl<const LANES: usize> Clone for SimdBf16<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const LANES: usize> Default for SimdBf16<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::splat_bits(0)
+    }
+}
+
+impl<const LANES: usize> PartialEq for SimdBf16<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.to_f32() == other.to_f32()
+    }
+}
+
+impl<const LANES: usize> core::fmt::Debug for SimdBf16<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.to_f32(), f)
+    }
+}
+
+impl<const LANES: usize> From<SimdBf16<LANES>> for SimdF32<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn from(value: SimdBf16<LANES>) -> Self {
+        value.to_f32()
+    }
+}
+
+/// Vector of four `bf16` values
+pub type bf16x4 = SimdBf16<4>;
+
+/// Vector of eight `bf16` values
+pub type bf16x8 = SimdBf16<8>;
+
+/// Vector of 16 `bf16` values
+pub type bf16x16 = SimdBf16<16>;
+
+/// Vector of 32 `bf16` values
+pub type bf16x32 = SimdBf16<32>;
@@ -0,0 +1,229 @@
+#![allow(non_camel_case_types)]
+
+use crate::{LaneCount, SimdF32, SimdU16, SimdU32, SupportedLaneCount};
+
+/// A SIMD vector of containing `LANES` IEEE 754 half-precision (binary16) values.
+///
+/// Rust has no primitive half-precision type, so each lane is stored as its raw `u16` bit
+/// pattern.  Arithmetic is performed by widening to [`SimdF32`] and rounding the result back,
+/// which is correctly rounded for `+`, `-`, `*` and `/`.
+#[repr(transparent)]
+pub struct SimdF16<const LANES: usize>(SimdU16<LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount;
+
+impl<const LANES: usize> SimdF16<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Number of lanes in this vector.
+    pub const LANES: usize = LANES;
+
+    /// Get the number of lanes in this vector.
+    pub const fn lanes(&self) -> usize {
+        LANES
+    }
+
+    /// Raw transmutation from an unsigned integer vector containing binary16 bit patterns.
+    #[inline]
+    pub fn from_bits(bits: SimdU16<LANES>) -> Self {
+        Self(bits)
+    }
+
+    /// Raw transmutation to an unsigned integer vector containing binary16 bit patterns.
+    #[inline]
+    pub fn to_bits(self) -> SimdU16<LANES> {
+        self.0
+    }
+
+    /// Construct a SIMD vector by setting all lanes to the given binary16 bit pattern.
+    #[inline]
+    pub fn splat_bits(bits: u16) -> Self {
+        Self(SimdU16::splat(bits))
+    }
+
+    /// Converts each lane to half precision, rounding to nearest with ties to even.
+    ///
+    /// Values too large to be represented become infinity, and `NaN`s remain `NaN`.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let x = SimdF16::from_f32(SimdF32::from_array([1.0, -2.0, 65504.0, 1e6]));
+    /// assert_eq!(x.to_bits().to_array(), [0x3c00, 0xc000, 0x7bff, 0x7c00]);
+    /// ```
+    #[inline]
+    pub fn from_f32(value: SimdF32<LANES>) -> Self {
+        // Adapted from the branch-free round-to-nearest-even conversion by Fabian Giesen.
+        const F32_INFINITY: u32 = 0xff << 23;
+        // smallest f32 that overflows to infinity after rounding
+        const F16_OVERFLOW: u32 = (127 + 16) << 23;
+        // smallest f32 that is a normal f16
+        const F16_MIN_NORMAL: u32 = (127 - 14) << 23;
+        const DENORMAL_MAGIC: u32 = ((127 - 15) + (23 - 10) + 1) << 23;
+        const REBIAS: u32 = ((15 - 127) << 23) as u32;
+
+        let bits = value.to_bits();
+        let sign = bits & SimdU32::splat(0x8000_0000);
+        let magnitude = bits ^ sign;
+
+        // too large, infinite or NaN
+        let special = magnitude
+            .lanes_gt(SimdU32::splat(F32_INFINITY))
+            .select(SimdU32::splat(0x7e00), SimdU32::splat(0x7c00));
+
+        // subnormal or zero: let the FPU round by adding a magic number that aligns the
+        // mantissa at the bottom of the representation
+        let magic = SimdU32::splat(DENORMAL_MAGIC);
+        let subnormal = (SimdF32::from_bits(magnitude) + SimdF32::from_bits(magic))
+            .to_bits()
+            .wrapping_sub(magic);
+
+        // normal: rebias the exponent and round the mantissa
+        let odd = (magnitude >> 13) & SimdU32::splat(1);
+        let normal = magnitude
+            .wrapping_add(SimdU32::splat(REBIAS.wrapping_add(0xfff)))
+            .wrapping_add(odd)
+            >> 13;
+
+        let result = magnitude.lanes_ge(SimdU32::splat(F16_OVERFLOW)).select(
+            special,
+            magnitude
+                .lanes_lt(SimdU32::splat(F16_MIN_NORMAL))
+                .select(subnormal, normal),
+        ) | (sign >> 16);
+        Self(unsafe { crate::intrinsics::simd_cast(result) })
+    }
+
+    /// Converts each lane to single precision.  This conversion is exact.
+    ///
+    /// ```
+    /// # use core_simd::*;
+    /// let x = SimdF16::from_bits(SimdU16::from_array([0x3c00, 0xc000, 0x0001, 0xfc00]));
+    /// assert_eq!(x.to_f32().to_array(), [1.0, -2.0, 2f32.powi(-24), f32::NEG_INFINITY]);
+    /// ```
+    #[inline]
+    pub fn to_f32(self) -> SimdF32<LANES> {
+        // 2^(127 - 15), the difference between the exponent biases
+        const REBIAS: u32 = (127 + (127 - 15)) << 23;
+
+        let bits: SimdU32<LANES> = unsafe { crate::intrinsics::simd_cast(self.0) };
+        let shifted = (bits & SimdU32::splat(0x7fff)) << 13;
+
+        // Normal and subnormal values are both exactly rescaled by a multiplication.
+        let finite = SimdF32::from_bits(shifted) * SimdF32::from_bits(SimdU32::splat(REBIAS));
+        let special = SimdF32::from_bits(shifted | SimdU32::splat(0x7f80_0000));
+        let magnitude = shifted
+            .lanes_ge(SimdU32::splat(0x7c00 << 13))
+            .select(special, finite);
+        SimdF32::from_bits(magnitude.to_bits() | ((bits & SimdU32::splat(0x8000)) << 16))
+    }
+}
+
+impl<const LANES: usize> Copy for SimdF16<LANES> where LaneCount<LANES>: SupportedLaneCount {}
+
+impl<const LANES: usize> Clone for SimdF16<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const LANES: usize> Default for SimdF16<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::splat_bits(0)
+    }
+}
+
+impl<const LANES: usize> PartialEq for SimdF16<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.to_f32() == other.to_f32()
+    }
+}
+
+impl<const LANES: usize> core::fmt::Debug for SimdF16<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.to_f32(), f)
+    }
+}
+
+impl<const LANES: usize> From<SimdF16<LANES>> for SimdF32<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn from(value: SimdF16<LANES>) -> Self {
+        value.to_f32()
+    }
+}
+
+impl<const LANES: usize> core::ops::Neg for SimdF16<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(self.0 ^ SimdU16::splat(0x8000))
+    }
+}
+
+/// Implements an arithmetic operator by computing in single precision.
+macro_rules! impl_f16_op {
+    { $($trait:ident::$fn:ident, $assign_trait:ident::$assign_fn:ident;)* } => {
+        $(
+            impl<const LANES: usize> core::ops::$trait for SimdF16<LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                type Output = Self;
+                #[inline]
+                fn $fn(self, rhs: Self) -> Self {
+                    Self::from_f32(core::ops::$trait::$fn(self.to_f32(), rhs.to_f32()))
+                }
+            }
+
+            impl<const LANES: usize> core::ops::$assign_trait for SimdF16<LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                #[inline]
+                fn $assign_fn(&mut self, rhs: Self) {
+                    *self = core::ops::$trait::$fn(*self, rhs);
+                }
+            }
+        )*
+    }
+}
+
+impl_f16_op! {
+    Add::add, AddAssign::add_assign;
+    Sub::sub, SubAssign::sub_assign;
+    Mul::mul, MulAssign::mul_assign;
+    Div::div, DivAssign::div_assign;
+}
+
+/// Vector of four `f16` values
+pub type f16x4 = SimdF16<4>;
+
+/// Vector of eight `f16` values
+pub type f16x8 = SimdF16<8>;
+
+/// Vector of 16 `f16` values
+pub type f16x16 = SimdF16<16>;
+
+/// Vector of 32 `f16` values
+pub type f16x32 = SimdF16<32>;
@@ -0,0 +1,198 @@
+#![allow(non_camel_case_types)]
+
+use crate::{LaneCount, Simd, SupportedLaneCount};
+
+/// Implements a signed fixed-point vector `$name` with `$frac` fractional bits, stored as `$bits`
+/// and converted to and from `$float` through `$float_int`.
+macro_rules! impl_fixed_vector {
+    { $name:ident, $format:literal, $bits:ty, $frac:literal, $float:ty, $float_int:ty } => {
+        #[doc = concat!("A SIMD vector containing `LANES` ", $format, " fixed-point values.")]
+        ///
+        #[doc = concat!("Each lane is a `", stringify!($bits), "` scaled by `2^-", stringify!($frac), "`, representing a value in the")]
+        /// range `-1.0..1.0`.  Arithmetic uses only integer instructions, and saturates at the ends
+        /// of the range rather than wrapping, as is conventional for signal processing.
+        #[repr(transparent)]
+        pub struct $name<const LANES: usize>(Simd<$bits, LANES>)
+        where
+            LaneCount<LANES>: SupportedLaneCount;
+
+        impl<const LANES: usize> $name<LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            /// Number of lanes in this vector.
+            pub const LANES: usize = LANES;
+
+            #[doc = concat!("The value of the smallest increment, `2^-", stringify!($frac), "`.")]
+            pub const EPSILON: $float = 1. / (1u64 << $frac) as $float;
+
+            /// Get the number of lanes in this vector.
+            pub const fn lanes(&self) -> usize {
+                LANES
+            }
+
+            #[doc = concat!("Raw transmutation from an integer vector containing ", $format, " values.")]
+            #[inline]
+            pub fn from_bits(bits: Simd<$bits, LANES>) -> Self {
+                Self(bits)
+            }
+
+            #[doc = concat!("Raw transmutation to an integer vector containing ", $format, " values.")]
+            #[inline]
+            pub fn to_bits(self) -> Simd<$bits, LANES> {
+                self.0
+            }
+
+            #[doc = concat!("Construct a SIMD vector by setting all lanes to the given ", $format, " value.")]
+            #[inline]
+            pub fn splat_bits(bits: $bits) -> Self {
+                Self(Simd::splat(bits))
+            }
+
+            #[doc = concat!("Converts each lane to ", $format, ", rounding to nearest with ties away from zero.")]
+            ///
+            /// Values outside the representable range saturate, and `NaN` becomes zero.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::<4>::from_float(Simd::from_array([0.5, -1.0, 1.0, ", stringify!($float), "::NAN]));")]
+            #[doc = concat!("assert_eq!(x.to_bits().to_array(), [1 << (", stringify!($frac), " - 1), ", stringify!($bits), "::MIN, ", stringify!($bits), "::MAX, 0]);")]
+            /// ```
+            #[inline]
+            pub fn from_float(value: Simd<$float, LANES>) -> Self {
+                let scaled = value * Simd::splat((1u64 << $frac) as $float);
+                let (min, max) = (Simd::splat(<$bits>::MIN as $float), Simd::splat(<$bits>::MAX as $float));
+                let clamped = scaled.lanes_lt(min).select(min, scaled.lanes_gt(max).select(max, scaled));
+                let clamped = scaled.is_nan().select(Simd::splat(0.), clamped);
+                // Safety: the value is finite and in range.
+                let truncated: Simd<$float_int, LANES> = unsafe { clamped.to_int_unchecked() };
+                // The truncated value is exact, so the difference is the exact fractional part,
+                // with the sign of the value.  A fraction of at least one half rounds away from
+                // zero, which can't leave the range because the ends of the range are integers.
+                let fraction = clamped - Simd::<$float, LANES>::round_from_int(truncated);
+                let up = fraction.lanes_ge(Simd::splat(0.5)).to_int();
+                let down = fraction.lanes_le(Simd::splat(-0.5)).to_int();
+                // Mask lanes are -1 when set.
+                let bits = truncated - up + down;
+                Self(unsafe { crate::intrinsics::simd_cast(bits) })
+            }
+
+            #[doc = concat!("Converts each lane to `", stringify!($float), "`.  This conversion is exact.")]
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = ", stringify!($name), "::<4>::from_bits(Simd::from_array([", stringify!($bits), "::MIN, -1, 0, 1 << (", stringify!($frac), " - 1)]));")]
+            #[doc = concat!("assert_eq!(x.to_float().to_array(), [-1.0, -", stringify!($name), "::<4>::EPSILON, 0.0, 0.5]);")]
+            /// ```
+            #[inline]
+            pub fn to_float(self) -> Simd<$float, LANES> {
+                let wide: Simd<$float_int, LANES> = unsafe { crate::intrinsics::simd_cast(self.0) };
+                Simd::<$float, LANES>::round_from_int(wide) * Simd::splat(Self::EPSILON)
+            }
+
+            /// Lanewise saturating addition.
+            #[inline]
+            pub fn saturating_add(self, other: Self) -> Self {
+                Self(self.0.saturating_add(other.0))
+            }
+
+            /// Lanewise saturating subtraction.
+            #[inline]
+            pub fn saturating_sub(self, other: Self) -> Self {
+                Self(self.0.saturating_sub(other.0))
+            }
+
+            /// Lanewise saturating multiplication, rounding to nearest.
+            ///
+            /// The only product that saturates is `-1.0 * -1.0`.  See
+            /// [`Simd::saturating_rounding_doubling_mul_high`].
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = ", stringify!($name), "::<4>::from_float(Simd::from_array([0.5, -0.5, 0.25, -1.0]));")]
+            #[doc = concat!("let b = ", stringify!($name), "::<4>::from_float(Simd::from_array([0.5, 0.5, -0.5, -1.0]));")]
+            #[doc = concat!("assert_eq!(a.saturating_mul(b).to_float().to_array(), [0.25, -0.25, -0.125, 1.0 - ", stringify!($name), "::<4>::EPSILON]);")]
+            /// ```
+            #[inline]
+            pub fn saturating_mul(self, other: Self) -> Self {
+                Self(self.0.saturating_rounding_doubling_mul_high(other.0))
+            }
+
+            /// Lanewise saturating negation.  `-1.0` becomes the largest value.
+            #[inline]
+            pub fn saturating_neg(self) -> Self {
+                Self(self.0.saturating_neg())
+            }
+        }
+
+        impl<const LANES: usize> Copy for $name<LANES> where LaneCount<LANES>: SupportedLaneCount {}
+
+        impl<const LANES: usize> Clone for $name<LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<const LANES: usize> Default for $name<LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn default() -> Self {
+                Self::splat_bits(0)
+            }
+        }
+
+        impl<const LANES: usize> PartialEq for $name<LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<const LANES: usize> Eq for $name<LANES> where LaneCount<LANES>: SupportedLaneCount {}
+
+        impl<const LANES: usize> core::fmt::Debug for $name<LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                core::fmt::Debug::fmt(&self.to_float(), f)
+            }
+        }
+    }
+}
+
+impl_fixed_vector! { SimdQ15, "Q15", i16, 15, f32, i32 }
+impl_fixed_vector! { SimdQ31, "Q31", i32, 31, f64, i64 }
+
+/// Vector of four Q15 values
+pub type q15x4 = SimdQ15<4>;
+
+/// Vector of eight Q15 values
+pub type q15x8 = SimdQ15<8>;
+
+/// Vector of 16 Q15 values
+pub type q15x16 = SimdQ15<16>;
+
+/// Vector of 32 Q15 values
+pub type q15x32 = SimdQ15<32>;
+
+/// Vector of two Q31 values
+pub type q31x2 = SimdQ31<2>;
+
+/// Vector of four Q31 values
+pub type q31x4 = SimdQ31<4>;
+
+/// Vector of eight Q31 values
+pub type q31x8 = SimdQ31<8>;
+
+/// Vector of 16 Q31 values
+pub type q31x16 = SimdQ31<16>;
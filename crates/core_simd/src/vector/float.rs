@@ -1,31 +1,30 @@
 #![allow(non_camel_case_types)]
 
-/// Implements inherent methods for a float vector `$name` containing multiple
-/// `$lanes` of float `$type`, which uses `$bits_ty` as its binary
-/// representation. Called from `define_float_vector!`.
+use crate::{LaneCount, Mask, Simd, SupportedLaneCount};
+
+/// Implements inherent methods for a vector of float `$type`, which uses `$bits_ty` as its
+/// binary representation and `$mask_ty` as its mask element.
 macro_rules! impl_float_vector {
-    { $name:ident, $type:ty, $bits_ty:ident, $mask_ty:ident, $mask_impl_ty:ident } => {
-        impl_vector! { $name, $type }
-        impl_float_reductions! { $name, $type }
+    { $type:ty, $bits_ty:ty, $mask_ty:ty } => {
+        impl_float_reductions! { $type }
 
-        impl<const LANES: usize> $name<LANES>
+        impl<const LANES: usize> Simd<$type, LANES>
         where
-            Self: crate::LanesAtMost32,
-            crate::$bits_ty<LANES>: crate::LanesAtMost32,
+            LaneCount<LANES>: SupportedLaneCount,
         {
             /// Raw transmutation to an unsigned integer vector type with the
             /// same size and number of lanes.
             #[inline]
-            pub fn to_bits(self) -> crate::$bits_ty<LANES> {
-                assert_eq!(core::mem::size_of::<Self>(), core::mem::size_of::<crate::$bits_ty<LANES>>());
+            pub fn to_bits(self) -> Simd<$bits_ty, LANES> {
+                assert_eq!(core::mem::size_of::<Self>(), core::mem::size_of::<Simd<$bits_ty, LANES>>());
                 unsafe { core::mem::transmute_copy(&self) }
             }
 
             /// Raw transmutation from an unsigned integer vector type with the
             /// same size and number of lanes.
             #[inline]
-            pub fn from_bits(bits: crate::$bits_ty<LANES>) -> Self {
-                assert_eq!(core::mem::size_of::<Self>(), core::mem::size_of::<crate::$bits_ty<LANES>>());
+            pub fn from_bits(bits: Simd<$bits_ty, LANES>) -> Self {
+                assert_eq!(core::mem::size_of::<Self>(), core::mem::size_of::<Simd<$bits_ty, LANES>>());
                 unsafe { core::mem::transmute_copy(&bits) }
             }
 
@@ -35,87 +34,286 @@ macro_rules! impl_float_vector {
             pub fn abs(self) -> Self {
                 unsafe { crate::intrinsics::simd_fabs(self) }
             }
-        }
 
-        impl<const LANES: usize> $name<LANES>
-        where
-            Self: crate::LanesAtMost32,
-            crate::$bits_ty<LANES>: crate::LanesAtMost32,
-            crate::$mask_impl_ty<LANES>: crate::LanesAtMost32,
-            crate::$mask_ty<LANES>: crate::Mask,
-        {
+            /// Converts each lane from radians to degrees.
+            #[inline]
+            pub fn to_degrees(self) -> Self {
+                // The scalar method multiplies by a constant, so this matches it exactly.
+                self * Self::splat(<$type>::to_degrees(1.))
+            }
+
+            /// Converts each lane from degrees to radians.
+            #[inline]
+            pub fn to_radians(self) -> Self {
+                self * Self::splat(<$type>::to_radians(1.))
+            }
+
             /// Returns true for each lane if it has a positive sign, including
             /// `+0.0`, `NaN`s with positive sign bit and positive infinity.
             #[inline]
-            pub fn is_sign_positive(self) -> crate::$mask_ty<LANES> {
+            pub fn is_sign_positive(self) -> Mask<$mask_ty, LANES> {
                 !self.is_sign_negative()
             }
 
             /// Returns true for each lane if it has a negative sign, including
             /// `-0.0`, `NaN`s with negative sign bit and negative infinity.
             #[inline]
-            pub fn is_sign_negative(self) -> crate::$mask_ty<LANES> {
-                let sign_bits = self.to_bits() & crate::$bits_ty::splat((!0 >> 1) + 1);
-                sign_bits.lanes_gt(crate::$bits_ty::splat(0))
+            pub fn is_sign_negative(self) -> Mask<$mask_ty, LANES> {
+                let sign_bits = self.to_bits() & Simd::splat((!0 >> 1) + 1);
+                sign_bits.lanes_gt(Simd::splat(0))
             }
 
             /// Returns true for each lane if its value is `NaN`.
             #[inline]
-            pub fn is_nan(self) -> crate::$mask_ty<LANES> {
+            pub fn is_nan(self) -> Mask<$mask_ty, LANES> {
                 self.lanes_ne(self)
             }
 
             /// Returns true for each lane if its value is positive infinity or negative infinity.
             #[inline]
-            pub fn is_infinite(self) -> crate::$mask_ty<LANES> {
+            pub fn is_infinite(self) -> Mask<$mask_ty, LANES> {
                 self.abs().lanes_eq(Self::splat(<$type>::INFINITY))
             }
 
             /// Returns true for each lane if its value is neither infinite nor `NaN`.
             #[inline]
-            pub fn is_finite(self) -> crate::$mask_ty<LANES> {
+            pub fn is_finite(self) -> Mask<$mask_ty, LANES> {
                 self.abs().lanes_lt(Self::splat(<$type>::INFINITY))
             }
 
             /// Returns true for each lane if its value is subnormal.
             #[inline]
-            pub fn is_subnormal(self) -> crate::$mask_ty<LANES> {
-                self.abs().lanes_ne(Self::splat(0.0)) & (self.to_bits() & Self::splat(<$type>::INFINITY).to_bits()).lanes_eq(crate::$bits_ty::splat(0))
+            pub fn is_subnormal(self) -> Mask<$mask_ty, LANES> {
+                self.abs().lanes_ne(Self::splat(0.0)) & (self.to_bits() & Self::splat(<$type>::INFINITY).to_bits()).lanes_eq(Simd::splat(0))
             }
 
             /// Returns true for each lane if its value is neither neither zero, infinite,
             /// subnormal, or `NaN`.
             #[inline]
-            pub fn is_normal(self) -> crate::$mask_ty<LANES> {
+            pub fn is_normal(self) -> Mask<$mask_ty, LANES> {
                 !(self.abs().lanes_eq(Self::splat(0.0)) | self.is_nan() | self.is_subnormal() | self.is_infinite())
             }
+
+            /// Returns true for each lane if its value is `+0.0` or `-0.0`.
+            #[inline]
+            pub fn is_zero(self) -> Mask<$mask_ty, LANES> {
+                self.lanes_eq(Self::splat(0.0))
+            }
+
+            /// Returns the floating point category of each lane.
+            ///
+            /// The categories are computed with vector operations, and only converted to
+            /// [`FpCategory`](core::num::FpCategory) at the end.  If only some categories are
+            /// needed, the masks returned by [`is_nan`](Self::is_nan),
+            /// [`is_zero`](Self::is_zero), and so on are faster.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            /// use core::num::FpCategory;
+            #[doc = concat!("let x = Simd::<", stringify!($type), ", 4>::from_array([1.0, -0.0, ", stringify!($type), "::NAN, ", stringify!($type), "::MIN_POSITIVE / 2.0]);")]
+            /// assert_eq!(
+            ///     x.classify(),
+            ///     [FpCategory::Normal, FpCategory::Zero, FpCategory::Nan, FpCategory::Subnormal]
+            /// );
+            /// ```
+            #[inline]
+            pub fn classify(self) -> [core::num::FpCategory; LANES] {
+                use core::num::FpCategory;
+                let code = |x: $mask_ty| Simd::<$mask_ty, LANES>::splat(x);
+                let codes = self.is_nan().select(
+                    code(0),
+                    self.is_infinite().select(
+                        code(1),
+                        self.is_zero().select(code(2), self.is_subnormal().select(code(3), code(4))),
+                    ),
+                );
+                codes.to_array().map(|code| match code {
+                    0 => FpCategory::Nan,
+                    1 => FpCategory::Infinite,
+                    2 => FpCategory::Zero,
+                    3 => FpCategory::Subnormal,
+                    _ => FpCategory::Normal,
+                })
+            }
+
+            /// Returns the least number greater than each lane.
+            ///
+            /// This matches the scalar `next_up`: `NaN` and positive infinity are unchanged, both
+            /// zeros step to the smallest positive subnormal, and the largest finite value steps
+            /// to infinity.  It is computed by adding to or subtracting from the binary
+            /// representation, without branches.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = Simd::<", stringify!($type), ", 4>::from_array([1.0, -0.0, ", stringify!($type), "::MAX, ", stringify!($type), "::NEG_INFINITY]);")]
+            #[doc = concat!("assert_eq!(x.next_up().to_array(), [1.0 + ", stringify!($type), "::EPSILON, ", stringify!($type), "::from_bits(1), ", stringify!($type), "::INFINITY, ", stringify!($type), "::MIN]);")]
+            /// ```
+            #[inline]
+            pub fn next_up(self) -> Self {
+                let bits = self.to_bits();
+                let sign_bit = Simd::splat((!0 >> 1) + 1);
+                let magnitude = bits & !sign_bit;
+                let next = (bits & sign_bit).lanes_eq(Simd::splat(0)).select(bits.wrapping_add(Simd::splat(1)), bits.wrapping_sub(Simd::splat(1)));
+                let next = magnitude.lanes_eq(Simd::splat(0)).select(Simd::splat(1), next);
+                let unchanged = self.is_nan() | self.lanes_eq(Self::splat(<$type>::INFINITY));
+                unchanged.select(self, Self::from_bits(next))
+            }
+
+            /// Returns the greatest number less than each lane.
+            ///
+            /// This matches the scalar `next_down`, and is the reverse of
+            /// [`next_up`](Self::next_up).
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let x = Simd::<", stringify!($type), ", 4>::from_array([1.0, 0.0, ", stringify!($type), "::MIN, ", stringify!($type), "::INFINITY]);")]
+            #[doc = concat!("assert_eq!(x.next_down().to_array(), [1.0 - ", stringify!($type), "::EPSILON / 2.0, -", stringify!($type), "::from_bits(1), ", stringify!($type), "::NEG_INFINITY, ", stringify!($type), "::MAX]);")]
+            /// ```
+            #[inline]
+            pub fn next_down(self) -> Self {
+                -(-self).next_up()
+            }
+
+            /// Test if each lane is within `max_ulps` units in the last place of the
+            /// corresponding lane in `other`.
+            ///
+            /// The distance is the number of representable values between the lanes, so `+0.0`
+            /// and `-0.0` are equal, and the largest finite value is 1 ULP from infinity.  Lanes
+            /// where either value is `NaN` are never equal.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = Simd::<", stringify!($type), ", 4>::from_array([1.0, 0.0, 1.0, ", stringify!($type), "::NAN]);")]
+            #[doc = concat!("let b = Simd::<", stringify!($type), ", 4>::from_array([1.0 + ", stringify!($type), "::EPSILON, -0.0, 1.0 + 3.0 * ", stringify!($type), "::EPSILON, ", stringify!($type), "::NAN]);")]
+            /// assert_eq!(a.lanes_approx_eq(b, 2).to_array(), [true, true, false, false]);
+            /// ```
+            #[inline]
+            pub fn lanes_approx_eq(self, other: Self, max_ulps: $bits_ty) -> Mask<$mask_ty, LANES> {
+                let sign_bit = Simd::splat((!0 >> 1) + 1);
+                let (x, y) = (self.to_bits(), other.to_bits());
+                let (a, b) = (x & !sign_bit, y & !sign_bit);
+                // With the same sign, the distance is the difference of the magnitudes.  With
+                // different signs, it is their sum, which can't overflow.
+                let same_sign = ((x ^ y) & sign_bit).lanes_eq(Simd::splat(0));
+                let difference = a.lanes_gt(b).select(a.wrapping_sub(b), b.wrapping_sub(a));
+                let distance = same_sign.select(difference, a + b);
+                distance.lanes_le(Simd::splat(max_ulps)) & !(self.is_nan() | other.is_nan())
+            }
+
+            /// Test if each lane differs from the corresponding lane in `other` by at most
+            /// `max_abs`.
+            ///
+            /// Equal infinities are equal, and lanes where either value is `NaN` are never equal.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = Simd::<", stringify!($type), ", 4>::from_array([1.0, 1.0, ", stringify!($type), "::INFINITY, ", stringify!($type), "::INFINITY]);")]
+            #[doc = concat!("let b = Simd::<", stringify!($type), ", 4>::from_array([1.05, 1.2, ", stringify!($type), "::INFINITY, ", stringify!($type), "::MAX]);")]
+            /// assert_eq!(a.lanes_approx_eq_abs(b, 0.1).to_array(), [true, false, true, false]);
+            /// ```
+            #[inline]
+            pub fn lanes_approx_eq_abs(self, other: Self, max_abs: $type) -> Mask<$mask_ty, LANES> {
+                (self - other).abs().lanes_le(Self::splat(max_abs)) | self.lanes_eq(other)
+            }
+
+            /// Test if each lane differs from the corresponding lane in `other` by at most
+            /// `max_relative` times the larger of their magnitudes.
+            ///
+            /// Equal infinities are equal, and lanes where either value is `NaN` are never equal.
+            /// A value is only relatively close to zero if it is zero, so comparisons near zero
+            /// usually need [`lanes_approx_eq_abs`](Self::lanes_approx_eq_abs) as well.
+            ///
+            /// ```
+            /// # use core_simd::*;
+            #[doc = concat!("let a = Simd::<", stringify!($type), ", 4>::from_array([100.0, 100.0, 0.0, 1e-30]);")]
+            #[doc = concat!("let b = Simd::<", stringify!($type), ", 4>::from_array([101.0, 103.0, -0.0, 0.0]);")]
+            /// assert_eq!(a.lanes_approx_eq_rel(b, 0.02).to_array(), [true, false, true, false]);
+            /// ```
+            #[inline]
+            pub fn lanes_approx_eq_rel(self, other: Self, max_relative: $type) -> Mask<$mask_ty, LANES> {
+                let (a, b) = (self.abs(), other.abs());
+                let largest = a.lanes_gt(b).select(a, b);
+                (self - other).abs().lanes_le(largest * Self::splat(max_relative)) | self.lanes_eq(other)
+            }
+
+            /// Maps each lane to an unsigned integer whose ordering matches the IEEE 754
+            /// totalOrder predicate: negative values have all bits flipped, and positive
+            /// values have their sign bit set.
+            #[inline]
+            fn total_order_key(self) -> Simd<$bits_ty, LANES> {
+                let bits = self.to_bits();
+                let sign_bit = Simd::splat((!0 >> 1) + 1);
+                self.is_sign_negative().select(!bits, bits | sign_bit)
+            }
+
+            /// Test if each lane is less than the corresponding lane in `other`, using the
+            /// IEEE 754 totalOrder predicate.
+            ///
+            /// Unlike [`lanes_lt`](Self::lanes_lt), this orders every value, including `NaN`s:
+            /// negative `NaN` < negative infinity < ... < `-0.0` < `+0.0` < ... < positive
+            /// infinity < positive `NaN`.  This matches the scalar `total_cmp`.
+            #[inline]
+            pub fn lanes_total_lt(self, other: Self) -> Mask<$mask_ty, LANES> {
+                self.total_order_key().lanes_lt(other.total_order_key())
+            }
+
+            /// Test if each lane is less than or equal to the corresponding lane in `other`,
+            /// using the IEEE 754 totalOrder predicate.
+            #[inline]
+            pub fn lanes_total_le(self, other: Self) -> Mask<$mask_ty, LANES> {
+                self.total_order_key().lanes_le(other.total_order_key())
+            }
+
+            /// Test if each lane is greater than the corresponding lane in `other`, using the
+            /// IEEE 754 totalOrder predicate.
+            #[inline]
+            pub fn lanes_total_gt(self, other: Self) -> Mask<$mask_ty, LANES> {
+                self.total_order_key().lanes_gt(other.total_order_key())
+            }
+
+            /// Test if each lane is greater than or equal to the corresponding lane in `other`,
+            /// using the IEEE 754 totalOrder predicate.
+            #[inline]
+            pub fn lanes_total_ge(self, other: Self) -> Mask<$mask_ty, LANES> {
+                self.total_order_key().lanes_ge(other.total_order_key())
+            }
         }
     };
 }
 
 /// A SIMD vector of containing `LANES` `f32` values.
-#[repr(simd)]
-pub struct SimdF32<const LANES: usize>([f32; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdF32<const LANES: usize> = Simd<f32, LANES>;
 
-impl_float_vector! { SimdF32, f32, SimdU32, Mask32, SimdI32 }
+impl_float_vector! { f32, u32, i32 }
 
 from_transmute_x86! { unsafe f32x4 => __m128 }
 from_transmute_x86! { unsafe f32x8 => __m256 }
-//from_transmute_x86! { unsafe f32x16 => __m512 }
+from_transmute_x86! { unsafe f32x16 => __m512 }
+
+from_transmute_neon! { unsafe f32x2 => float32x2_t }
+from_transmute_neon! { unsafe f32x4 => float32x4_t }
+
+from_transmute_wasm32! { unsafe f32x4 => v128 }
+
+from_transmute_powerpc! { unsafe f32x4 => vector_float }
 
 /// A SIMD vector of containing `LANES` `f64` values.
-#[repr(simd)]
-pub struct SimdF64<const LANES: usize>([f64; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdF64<const LANES: usize> = Simd<f64, LANES>;
 
-impl_float_vector! { SimdF64, f64, SimdU64, Mask64, SimdI64 }
+impl_float_vector! { f64, u64, i64 }
 
 from_transmute_x86! { unsafe f64x2 => __m128d }
 from_transmute_x86! { unsafe f64x4 => __m256d }
-//from_transmute_x86! { unsafe f64x8 => __m512d }
+from_transmute_x86! { unsafe f64x8 => __m512d }
+
+#[cfg(target_arch = "aarch64")]
+from_transmute_neon! { unsafe SimdF64<1> => float64x1_t }
+#[cfg(target_arch = "aarch64")]
+from_transmute_neon! { unsafe f64x2 => float64x2_t }
+
+from_transmute_wasm32! { unsafe f64x2 => v128 }
+
+from_transmute_powerpc! { unsafe f64x2 => vector_double }
 
 /// Vector of two `f32` values
 pub type f32x2 = SimdF32<2>;
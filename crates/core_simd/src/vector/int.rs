@@ -1,44 +1,23 @@
 #![allow(non_camel_case_types)]
 
-/// Implements additional integer traits (Eq, Ord, Hash) on the specified vector `$name`, holding multiple `$lanes` of `$type`.
-macro_rules! impl_integer_vector {
-    { $name:ident, $type:ty, $mask_ty:ident, $mask_impl_ty:ident } => {
-        impl_vector! { $name, $type }
-        impl_integer_reductions! { $name, $type }
-
-        impl<const LANES: usize> Eq for $name<LANES> where Self: crate::LanesAtMost32 {}
-
-        impl<const LANES: usize> Ord for $name<LANES> where Self: crate::LanesAtMost32 {
-            #[inline]
-            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-                // TODO use SIMD cmp
-                self.to_array().cmp(other.as_ref())
-            }
-        }
+use crate::{LaneCount, Mask, Simd, SupportedLaneCount};
 
-        impl<const LANES: usize> core::hash::Hash for $name<LANES> where Self: crate::LanesAtMost32 {
-            #[inline]
-            fn hash<H>(&self, state: &mut H)
-            where
-                H: core::hash::Hasher
-            {
-                self.as_slice().hash(state)
-            }
-        }
+/// Implements inherent methods on a vector of signed integer `$type`.
+macro_rules! impl_integer_vector {
+    { $type:ty } => {
+        impl_integer_reductions! { $type }
 
-        impl<const LANES: usize> $name<LANES>
+        impl<const LANES: usize> Simd<$type, LANES>
         where
-            Self: crate::LanesAtMost32,
-            crate::$mask_impl_ty<LANES>: crate::LanesAtMost32,
-            crate::$mask_ty<LANES>: crate::Mask,
+            LaneCount<LANES>: SupportedLaneCount,
         {
             /// Returns true for each positive lane and false if it is zero or negative.
-            pub fn is_positive(self) -> crate::$mask_ty<LANES> {
+            pub fn is_positive(self) -> Mask<$type, LANES> {
                 self.lanes_gt(Self::splat(0))
             }
 
             /// Returns true for each negative lane and false if it is zero or positive.
-            pub fn is_negative(self) -> crate::$mask_ty<LANES> {
+            pub fn is_negative(self) -> Mask<$type, LANES> {
                 self.lanes_lt(Self::splat(0))
             }
         }
@@ -46,72 +25,103 @@ macro_rules! impl_integer_vector {
 }
 
 /// A SIMD vector of containing `LANES` `isize` values.
-#[repr(simd)]
-pub struct SimdIsize<const LANES: usize>([isize; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdIsize<const LANES: usize> = Simd<isize, LANES>;
 
-impl_integer_vector! { SimdIsize, isize, MaskSize, SimdIsize }
+impl_integer_vector! { isize }
 
 #[cfg(target_pointer_width = "32")]
 from_transmute_x86! { unsafe isizex4 => __m128i }
 #[cfg(target_pointer_width = "32")]
 from_transmute_x86! { unsafe isizex8 => __m256i }
+#[cfg(target_pointer_width = "32")]
+from_transmute_x86! { unsafe SimdIsize<16> => __m512i }
 
 #[cfg(target_pointer_width = "64")]
 from_transmute_x86! { unsafe isizex2 => __m128i }
 #[cfg(target_pointer_width = "64")]
 from_transmute_x86! { unsafe isizex4 => __m256i }
-//#[cfg(target_pointer_width = "64")]
-//from_transmute_x86! { unsafe isizex8 => __m512i }
+#[cfg(target_pointer_width = "64")]
+from_transmute_x86! { unsafe isizex8 => __m512i }
+
+#[cfg(target_pointer_width = "32")]
+from_transmute_neon! { unsafe isizex2 => int32x2_t }
+#[cfg(target_pointer_width = "32")]
+from_transmute_neon! { unsafe isizex4 => int32x4_t }
+
+#[cfg(target_pointer_width = "64")]
+from_transmute_neon! { unsafe isizex2 => int64x2_t }
+
+#[cfg(target_pointer_width = "32")]
+from_transmute_wasm32! { unsafe isizex4 => v128 }
 
 /// A SIMD vector of containing `LANES` `i16` values.
-#[repr(simd)]
-pub struct SimdI16<const LANES: usize>([i16; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdI16<const LANES: usize> = Simd<i16, LANES>;
 
-impl_integer_vector! { SimdI16, i16, Mask16, SimdI16 }
+impl_integer_vector! { i16 }
 
 from_transmute_x86! { unsafe i16x8 => __m128i }
 from_transmute_x86! { unsafe i16x16 => __m256i }
-//from_transmute_x86! { unsafe i16x32 => __m512i }
+from_transmute_x86! { unsafe i16x32 => __m512i }
+
+from_transmute_neon! { unsafe i16x4 => int16x4_t }
+from_transmute_neon! { unsafe i16x8 => int16x8_t }
+
+from_transmute_wasm32! { unsafe i16x8 => v128 }
+
+from_transmute_powerpc! { unsafe i16x8 => vector_signed_short }
 
 /// A SIMD vector of containing `LANES` `i32` values.
-#[repr(simd)]
-pub struct SimdI32<const LANES: usize>([i32; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdI32<const LANES: usize> = Simd<i32, LANES>;
 
-impl_integer_vector! { SimdI32, i32, Mask32, SimdI32 }
+impl_integer_vector! { i32 }
 
 from_transmute_x86! { unsafe i32x4 => __m128i }
 from_transmute_x86! { unsafe i32x8 => __m256i }
-//from_transmute_x86! { unsafe i32x16 => __m512i }
+from_transmute_x86! { unsafe i32x16 => __m512i }
+
+from_transmute_neon! { unsafe i32x2 => int32x2_t }
+from_transmute_neon! { unsafe i32x4 => int32x4_t }
+
+from_transmute_wasm32! { unsafe i32x4 => v128 }
+
+from_transmute_powerpc! { unsafe i32x4 => vector_signed_int }
 
 /// A SIMD vector of containing `LANES` `i64` values.
-#[repr(simd)]
-pub struct SimdI64<const LANES: usize>([i64; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdI64<const LANES: usize> = Simd<i64, LANES>;
 
-impl_integer_vector! { SimdI64, i64, Mask64, SimdI64 }
+impl_integer_vector! { i64 }
 
 from_transmute_x86! { unsafe i64x2 => __m128i }
 from_transmute_x86! { unsafe i64x4 => __m256i }
-//from_transmute_x86! { unsafe i64x8 => __m512i }
+from_transmute_x86! { unsafe i64x8 => __m512i }
+
+from_transmute_neon! { unsafe SimdI64<1> => int64x1_t }
+from_transmute_neon! { unsafe i64x2 => int64x2_t }
+
+from_transmute_wasm32! { unsafe i64x2 => v128 }
+
+from_transmute_powerpc! { unsafe i64x2 => vector_signed_long }
+
+/// A SIMD vector of containing `LANES` `i128` values.
+pub type SimdI128<const LANES: usize> = Simd<i128, LANES>;
+
+impl_integer_vector! { i128 }
 
 /// A SIMD vector of containing `LANES` `i8` values.
-#[repr(simd)]
-pub struct SimdI8<const LANES: usize>([i8; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdI8<const LANES: usize> = Simd<i8, LANES>;
 
-impl_integer_vector! { SimdI8, i8, Mask8, SimdI8 }
+impl_integer_vector! { i8 }
 
 from_transmute_x86! { unsafe i8x16 => __m128i }
 from_transmute_x86! { unsafe i8x32 => __m256i }
-//from_transmute_x86! { unsafe i8x64 => __m512i }
+from_transmute_x86! { unsafe i8x64 => __m512i }
+
+from_transmute_neon! { unsafe i8x8 => int8x8_t }
+from_transmute_neon! { unsafe i8x16 => int8x16_t }
+
+from_transmute_wasm32! { unsafe i8x16 => v128 }
+
+from_transmute_powerpc! { unsafe i8x16 => vector_signed_char }
 
 /// Vector of two `isize` values
 pub type isizex2 = SimdIsize<2>;
@@ -155,6 +165,12 @@ pub type i64x4 = SimdI64<4>;
 /// Vector of eight `i64` values
 pub type i64x8 = SimdI64<8>;
 
+/// Vector of two `i128` values
+pub type i128x2 = SimdI128<2>;
+
+/// Vector of four `i128` values
+pub type i128x4 = SimdI128<4>;
+
 /// Vector of eight `i8` values
 pub type i8x8 = SimdI8<8>;
 
@@ -166,3 +182,6 @@ pub type i8x32 = SimdI8<32>;
 
 /// Vector of 64 `i8` values
 pub type i8x64 = SimdI8<64>;
+
+/// Vector of 128 `i8` values
+pub type i8x128 = SimdI8<128>;
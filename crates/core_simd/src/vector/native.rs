@@ -0,0 +1,43 @@
+#![allow(non_camel_case_types)]
+
+use crate::Simd;
+
+/// The size in bytes of the widest vector register on the compilation target.
+///
+/// This depends on the target features enabled at compile time, not the CPU the program runs
+/// on: a build for `x86_64` without `-C target-feature=+avx` uses 16 bytes even on a machine
+/// with AVX.  Targets without SIMD registers use 16 bytes, so that every element type has at
+/// least one lane.
+pub const NATIVE_VECTOR_BYTES: usize = if cfg!(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx512f"
+)) {
+    64
+} else if cfg!(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx"
+)) {
+    32
+} else {
+    16
+};
+
+/// Defines an alias for a vector of `$type` filling [`NATIVE_VECTOR_BYTES`].
+macro_rules! native_alias {
+    { $($alias:ident = $type:ty),* } => {
+        $(
+        #[doc = concat!(
+            "Vector of `", stringify!($type), "` values as wide as the target's widest vector ",
+            "register.\n\nThe number of lanes is `NATIVE_VECTOR_BYTES / size_of::<",
+            stringify!($type), ">()`, so it varies between targets."
+        )]
+        pub type $alias = Simd<$type, { NATIVE_VECTOR_BYTES / core::mem::size_of::<$type>() }>;
+        )*
+    }
+}
+
+native_alias! {
+    u8xN = u8, u16xN = u16, u32xN = u32, u64xN = u64, u128xN = u128, usizexN = usize,
+    i8xN = i8, i16xN = i16, i32xN = i32, i64xN = i64, i128xN = i128, isizexN = isize,
+    f32xN = f32, f64xN = f64
+}
@@ -0,0 +1,61 @@
+//! Vectors of pointers, used to implement gathers and scatters.
+
+use crate::{LaneCount, Simd, SupportedLaneCount};
+use core::mem;
+
+/// A vector of `*const T`.
+#[derive(Copy, Clone)]
+#[repr(simd)]
+pub(crate) struct SimdConstPtr<T, const LANES: usize>([*const T; LANES]);
+
+impl<T, const LANES: usize> SimdConstPtr<T, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Constructs a vector with every lane set to `ptr`.
+    #[inline]
+    pub(crate) fn splat(ptr: *const T) -> Self {
+        Self([ptr; LANES])
+    }
+
+    /// Offsets each lane by the corresponding number of elements, with wrapping arithmetic as
+    /// in [`pointer::wrapping_add`].
+    #[inline]
+    pub(crate) fn wrapping_add(self, addend: Simd<usize, LANES>) -> Self {
+        // Pointers and `usize` have the same size, so the transmutes are between vectors of the
+        // same size.
+        unsafe {
+            let x: Simd<usize, LANES> = mem::transmute_copy(&self);
+            let offset = addend.wrapping_mul(Simd::splat(mem::size_of::<T>()));
+            mem::transmute_copy(&x.wrapping_add(offset))
+        }
+    }
+}
+
+/// A vector of `*mut T`.
+#[derive(Copy, Clone)]
+#[repr(simd)]
+pub(crate) struct SimdMutPtr<T, const LANES: usize>([*mut T; LANES]);
+
+impl<T, const LANES: usize> SimdMutPtr<T, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Constructs a vector with every lane set to `ptr`.
+    #[inline]
+    pub(crate) fn splat(ptr: *mut T) -> Self {
+        Self([ptr; LANES])
+    }
+
+    /// Offsets each lane by the corresponding number of elements, with wrapping arithmetic as
+    /// in [`pointer::wrapping_add`].
+    #[inline]
+    pub(crate) fn wrapping_add(self, addend: Simd<usize, LANES>) -> Self {
+        // As in `SimdConstPtr::wrapping_add`.
+        unsafe {
+            let x: Simd<usize, LANES> = mem::transmute_copy(&self);
+            let offset = addend.wrapping_mul(Simd::splat(mem::size_of::<T>()));
+            mem::transmute_copy(&x.wrapping_add(offset))
+        }
+    }
+}
@@ -1,100 +1,105 @@
 #![allow(non_camel_case_types)]
 
-/// Implements additional integer traits (Eq, Ord, Hash) on the specified vector `$name`, holding multiple `$lanes` of `$type`.
-macro_rules! impl_unsigned_vector {
-    { $name:ident, $type:ty } => {
-        impl_vector! { $name, $type }
-        impl_integer_reductions! { $name, $type }
-
-        impl<const LANES: usize> Eq for $name<LANES> where Self: crate::LanesAtMost32 {}
-
-        impl<const LANES: usize> Ord for $name<LANES> where Self: crate::LanesAtMost32 {
-            #[inline]
-            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-                // TODO use SIMD cmp
-                self.to_array().cmp(other.as_ref())
-            }
-        }
-
-        impl<const LANES: usize> core::hash::Hash for $name<LANES> where Self: crate::LanesAtMost32 {
-            #[inline]
-            fn hash<H>(&self, state: &mut H)
-            where
-                H: core::hash::Hasher
-            {
-                self.as_slice().hash(state)
-            }
-        }
-    }
-}
+use crate::Simd;
 
 /// A SIMD vector of containing `LANES` `usize` values.
-#[repr(simd)]
-pub struct SimdUsize<const LANES: usize>([usize; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdUsize<const LANES: usize> = Simd<usize, LANES>;
 
-impl_unsigned_vector! { SimdUsize, usize }
+impl_integer_reductions! { usize }
 
 #[cfg(target_pointer_width = "32")]
 from_transmute_x86! { unsafe usizex4 => __m128i }
 #[cfg(target_pointer_width = "32")]
 from_transmute_x86! { unsafe usizex8 => __m256i }
+#[cfg(target_pointer_width = "32")]
+from_transmute_x86! { unsafe SimdUsize<16> => __m512i }
 
 #[cfg(target_pointer_width = "64")]
 from_transmute_x86! { unsafe usizex2 => __m128i }
 #[cfg(target_pointer_width = "64")]
 from_transmute_x86! { unsafe usizex4 => __m256i }
-//#[cfg(target_pointer_width = "64")]
-//from_transmute_x86! { unsafe usizex8 => __m512i }
+#[cfg(target_pointer_width = "64")]
+from_transmute_x86! { unsafe usizex8 => __m512i }
+
+#[cfg(target_pointer_width = "32")]
+from_transmute_neon! { unsafe usizex2 => uint32x2_t }
+#[cfg(target_pointer_width = "32")]
+from_transmute_neon! { unsafe usizex4 => uint32x4_t }
+
+#[cfg(target_pointer_width = "64")]
+from_transmute_neon! { unsafe usizex2 => uint64x2_t }
+
+#[cfg(target_pointer_width = "32")]
+from_transmute_wasm32! { unsafe usizex4 => v128 }
 
 /// A SIMD vector of containing `LANES` `u16` values.
-#[repr(simd)]
-pub struct SimdU16<const LANES: usize>([u16; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdU16<const LANES: usize> = Simd<u16, LANES>;
 
-impl_unsigned_vector! { SimdU16, u16 }
+impl_integer_reductions! { u16 }
 
 from_transmute_x86! { unsafe u16x8 => __m128i }
 from_transmute_x86! { unsafe u16x16 => __m256i }
-//from_transmute_x86! { unsafe u16x32 => __m512i }
+from_transmute_x86! { unsafe u16x32 => __m512i }
+
+from_transmute_neon! { unsafe u16x4 => uint16x4_t }
+from_transmute_neon! { unsafe u16x8 => uint16x8_t }
+
+from_transmute_wasm32! { unsafe u16x8 => v128 }
+
+from_transmute_powerpc! { unsafe u16x8 => vector_unsigned_short }
 
 /// A SIMD vector of containing `LANES` `u32` values.
-#[repr(simd)]
-pub struct SimdU32<const LANES: usize>([u32; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdU32<const LANES: usize> = Simd<u32, LANES>;
 
-impl_unsigned_vector! { SimdU32, u32 }
+impl_integer_reductions! { u32 }
 
 from_transmute_x86! { unsafe u32x4 => __m128i }
 from_transmute_x86! { unsafe u32x8 => __m256i }
-//from_transmute_x86! { unsafe u32x16 => __m512i }
+from_transmute_x86! { unsafe u32x16 => __m512i }
+
+from_transmute_neon! { unsafe u32x2 => uint32x2_t }
+from_transmute_neon! { unsafe u32x4 => uint32x4_t }
+
+from_transmute_wasm32! { unsafe u32x4 => v128 }
+
+from_transmute_powerpc! { unsafe u32x4 => vector_unsigned_int }
 
 /// A SIMD vector of containing `LANES` `u64` values.
-#[repr(simd)]
-pub struct SimdU64<const LANES: usize>([u64; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdU64<const LANES: usize> = Simd<u64, LANES>;
 
-impl_unsigned_vector! { SimdU64, u64 }
+impl_integer_reductions! { u64 }
 
 from_transmute_x86! { unsafe u64x2 => __m128i }
 from_transmute_x86! { unsafe u64x4 => __m256i }
-//from_transmute_x86! { unsafe u64x8 => __m512i }
+from_transmute_x86! { unsafe u64x8 => __m512i }
+
+from_transmute_neon! { unsafe SimdU64<1> => uint64x1_t }
+from_transmute_neon! { unsafe u64x2 => uint64x2_t }
+
+from_transmute_wasm32! { unsafe u64x2 => v128 }
+
+from_transmute_powerpc! { unsafe u64x2 => vector_unsigned_long }
+
+/// A SIMD vector of containing `LANES` `u128` values.
+pub type SimdU128<const LANES: usize> = Simd<u128, LANES>;
+
+impl_integer_reductions! { u128 }
 
 /// A SIMD vector of containing `LANES` `u8` values.
-#[repr(simd)]
-pub struct SimdU8<const LANES: usize>([u8; LANES])
-where
-    Self: crate::LanesAtMost32;
+pub type SimdU8<const LANES: usize> = Simd<u8, LANES>;
 
-impl_unsigned_vector! { SimdU8, u8 }
+impl_integer_reductions! { u8 }
 
 from_transmute_x86! { unsafe u8x16 => __m128i }
 from_transmute_x86! { unsafe u8x32 => __m256i }
-//from_transmute_x86! { unsafe u8x64 => __m512i }
+from_transmute_x86! { unsafe u8x64 => __m512i }
+
+from_transmute_neon! { unsafe u8x8 => uint8x8_t }
+from_transmute_neon! { unsafe u8x16 => uint8x16_t }
+
+from_transmute_wasm32! { unsafe u8x16 => v128 }
+
+from_transmute_powerpc! { unsafe u8x16 => vector_unsigned_char }
 
 /// Vector of two `usize` values
 pub type usizex2 = SimdUsize<2>;
@@ -138,6 +143,12 @@ pub type u64x4 = SimdU64<4>;
 /// Vector of eight `u64` values
 pub type u64x8 = SimdU64<8>;
 
+/// Vector of two `u128` values
+pub type u128x2 = SimdU128<2>;
+
+/// Vector of four `u128` values
+pub type u128x4 = SimdU128<4>;
+
 /// Vector of eight `u8` values
 pub type u8x8 = SimdU8<8>;
 
@@ -149,3 +160,6 @@ pub type u8x32 = SimdU8<32>;
 
 /// Vector of 64 `u8` values
 pub type u8x64 = SimdU8<64>;
+
+/// Vector of 128 `u8` values
+pub type u8x128 = SimdU8<128>;
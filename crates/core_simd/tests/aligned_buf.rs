@@ -0,0 +1,80 @@
+#![cfg(feature = "alloc")]
+
+use core_simd::{AlignedBuf, Simd};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn check_aligned<const LANES: usize>(buf: &AlignedBuf<u32, LANES>)
+where
+    core_simd::LaneCount<LANES>: core_simd::SupportedLaneCount,
+{
+    let vectors = buf.as_vectors();
+    assert!(vectors.len() * LANES >= buf.len() && vectors.len() * LANES < buf.len() + LANES);
+    assert_eq!(buf.as_ptr().align_offset(Simd::<u32, LANES>::ALIGN), 0);
+    let lanes = Simd::flatten_slice(vectors);
+    assert_eq!(&lanes[..buf.len()], &buf[..]);
+    assert!(lanes[buf.len()..].iter().all(|&lane| lane == 0));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn push_and_extend() {
+    let mut buf = AlignedBuf::<u32, 8>::new();
+    let mut expected = Vec::new();
+    for i in 0..20 {
+        buf.push(i + 1);
+        expected.push(i + 1);
+        check_aligned(&buf);
+        assert_eq!(&buf[..], &expected[..]);
+    }
+    buf.extend_from_slice(&[100, 200, 300]);
+    buf.extend(1000..1010);
+    expected.extend_from_slice(&[100, 200, 300]);
+    expected.extend(1000..1010);
+    check_aligned(&buf);
+    assert_eq!(&buf[..], &expected[..]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn truncate_clears_padding() {
+    let mut buf: AlignedBuf<u32, 4> = (1..=10).collect();
+    buf.as_mut_vectors()[2] = Simd::splat(7);
+    buf.truncate(5);
+    check_aligned(&buf);
+    assert_eq!(&buf[..], &[1, 2, 3, 4, 5]);
+    buf.clear();
+    check_aligned(&buf);
+    assert!(buf.is_empty());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn splat_and_from_slice() {
+    let buf = AlignedBuf::<u32, 16>::splat(3, 17);
+    check_aligned(&buf);
+    assert!(buf.iter().all(|&x| x == 3));
+    let copy = AlignedBuf::<u32, 16>::from(&buf[..]);
+    check_aligned(&copy);
+    assert_eq!(copy, buf);
+    check_aligned(&AlignedBuf::<u32, 1>::from_slice(&[]));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn growing_clears_padding() {
+    let mut buf: AlignedBuf<u32, 4> = (1..=5).collect();
+    let expected = buf.clone();
+    buf.as_mut_vectors()[1] = Simd::from_array([5, 7, 7, 7]);
+    assert_eq!(buf, expected);
+    buf.push(6);
+    check_aligned(&buf);
+    buf.as_mut_vectors()[1] = Simd::from_array([5, 6, 7, 7]);
+    buf.extend_from_slice(&[]);
+    check_aligned(&buf);
+    assert_eq!(&buf[..], &[1, 2, 3, 4, 5, 6]);
+}
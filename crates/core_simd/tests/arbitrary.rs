@@ -0,0 +1,31 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use core_simd::{Mask, SimdI32, SimdU8};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn matches_lanes() {
+    let data: Vec<u8> = (0..64u8).map(|x| x.wrapping_mul(37)).collect();
+    let v = SimdI32::<8>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+    let lanes = <[i32; 8]>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+    assert_eq!(v.to_array(), lanes);
+
+    let m = Mask::<i64, 4>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+    let lanes = <[bool; 4]>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+    assert_eq!(m.to_array(), lanes);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn size_hint() {
+    assert_eq!(SimdU8::<16>::size_hint(0), (16, Some(16)));
+    assert_eq!(SimdI32::<4>::size_hint(0), (16, Some(16)));
+    assert_eq!(Mask::<i8, 32>::size_hint(0), (32, Some(32)));
+}
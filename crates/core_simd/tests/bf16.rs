@@ -0,0 +1,69 @@
+#![cfg(feature = "bf16")]
+
+use core_simd::{SimdBf16, SimdF32, SimdU16};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn round_to_nearest_even() {
+    let x = SimdF32::from_bits(core_simd::SimdU32::from_array([
+        0x3f80_8000, // tie, rounds down to even
+        0x3f81_8000, // tie, rounds up to even
+        0x3f80_8001, // above the tie, rounds up
+        0x7f7f_ffff, // f32::MAX rounds up to infinity
+        0x8000_0001, // smallest negative subnormal rounds to -0.0
+        0x7f80_0001, // NaN whose payload would be truncated away
+        0xff80_0000, // negative infinity
+        0x0000_0000,
+    ]));
+    assert_eq!(
+        SimdBf16::from_f32(x).to_bits().to_array(),
+        [0x3f80, 0x3f82, 0x3f81, 0x7f80, 0x8000, 0x7fc0, 0xff80, 0x0000]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn roundtrip_all_values() {
+    for bits in (0..=u16::MAX).step_by(4) {
+        let bits = SimdU16::<4>::from_array([bits, bits + 1, bits + 2, bits + 3]);
+        let x = SimdBf16::from_bits(bits);
+        let roundtrip = SimdBf16::from_f32(x.to_f32()).to_bits();
+        for lane in 0..4 {
+            if x.to_f32()[lane].is_nan() {
+                assert_eq!(roundtrip[lane], bits[lane] | 0x0040);
+            } else {
+                assert_eq!(roundtrip[lane], bits[lane]);
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn slice_load_store() {
+    let data = [0x3f80, 0x4000, 0x4040, 0x4080, 0x40a0];
+    let x = SimdBf16::<4>::from_slice(&data);
+    assert_eq!(x.to_f32().to_array(), [1.0, 2.0, 3.0, 4.0]);
+
+    let mut out = [0u16; 5];
+    SimdBf16::<4>::splat(-1.0).copy_to_slice(&mut out);
+    assert_eq!(out, [0xbf80, 0xbf80, 0xbf80, 0xbf80, 0]);
+}
+
+#[test]
+#[should_panic]
+fn from_short_slice() {
+    let _ = SimdBf16::<4>::from_slice(&[0; 3]);
+}
+
+#[test]
+fn lanes() {
+    assert_eq!(SimdBf16::<4>::LANES, 4);
+    assert_eq!(SimdBf16::<8>::splat_bits(0).lanes(), 8);
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "bytemuck")]
+
+use core_simd::{Mask, SimdF32, SimdU32, SimdU8};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn cast_to_bytes() {
+    let vectors = [
+        SimdU32::from_array([1, 2, 3, 4]),
+        SimdU32::from_array([5, 6, 7, 8]),
+    ];
+    let bytes: &[u8] = bytemuck::cast_slice(&vectors);
+    assert_eq!(bytes.len(), 32);
+    let lanes: &[u32] = bytemuck::cast_slice(&vectors);
+    assert_eq!(lanes, [1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn cast_between_vectors() {
+    let x = SimdF32::from_array([1.0, -2.0, 0.5, 0.0]);
+    let bits: SimdU32<4> = bytemuck::cast(x);
+    assert_eq!(bits, x.to_bits());
+    let bytes: SimdU8<16> = bytemuck::cast(x);
+    assert_eq!(bytemuck::cast::<_, SimdF32<4>>(bytes), x);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn zeroed() {
+    assert_eq!(
+        <SimdU32<8> as bytemuck::Zeroable>::zeroed(),
+        SimdU32::splat(0)
+    );
+    assert_eq!(
+        <Mask<i16, 8> as bytemuck::Zeroable>::zeroed(),
+        Mask::splat(false)
+    );
+}
+
+#[cfg(feature = "f16")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn f16_wrapper() {
+    use bytemuck::TransparentWrapper;
+    use core_simd::{SimdF16, SimdU16};
+    let bits = SimdU16::from_array([0x3c00, 0xc000, 0, 0x7c00]);
+    assert_eq!(SimdF16::wrap(bits).to_bits(), bits);
+    assert_eq!(SimdF16::wrap_ref(&bits).to_bits(), bits);
+}
@@ -0,0 +1,60 @@
+use core_simd::{Mask, Simd};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! test_compress {
+    { $($name:ident: $type:ty, $mask:ty, $lanes:literal;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            test_helpers::test_2(&|x: [$type; $lanes], m: [$mask; $lanes]| {
+                let selected = m.map(|m| m % 2 == 0);
+                let (vector, mask) = (Simd::from_array(x), Mask::<$mask, $lanes>::from_array(selected));
+
+                let (packed, count) = vector.compress(mask);
+                let mut expected = [0 as $type; $lanes];
+                let mut n = 0;
+                for i in 0..$lanes {
+                    if selected[i] {
+                        expected[n] = x[i];
+                        n += 1;
+                    }
+                }
+                proptest::prop_assert_eq!(count, n);
+                proptest::prop_assert_eq!(packed.to_array(), expected);
+
+                let expanded = packed.expand(mask).to_array();
+                for i in 0..$lanes {
+                    let expected = if selected[i] { x[i] } else { 0 as $type };
+                    proptest::prop_assert_eq!(expanded[i], expected);
+                }
+                Ok(())
+            });
+        }
+        )*
+    }
+}
+
+test_compress! {
+    compress_i32_1: i32, i32, 1;
+    compress_i32_8: i32, i32, 8;
+    compress_u8_64: u8, i8, 64;
+    compress_u16_32: u16, i16, 32;
+    compress_i64_4: i64, i64, 4;
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn compress_float() {
+    let a = Simd::from_array([1.5f32, -0., f32::INFINITY, 4.]);
+    let mask = Mask::from_array([true, false, true, false]);
+    let (packed, count) = a.compress(mask);
+    assert_eq!(packed.to_array(), [1.5, f32::INFINITY, 0., 0.]);
+    assert_eq!(count, 2);
+    assert_eq!(packed.expand(mask).to_array(), [1.5, 0., f32::INFINITY, 0.]);
+}
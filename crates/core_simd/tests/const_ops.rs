@@ -0,0 +1,39 @@
+use core_simd::{SimdF32, SimdU32};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+const fn powers(base: SimdU32<4>) -> [SimdU32<4>; 4] {
+    let mut table = [SimdU32::from_array([1; 4]); 4];
+    let mut i = 1;
+    while i < 4 {
+        table[i] = table[i - 1].const_mul(base);
+        i += 1;
+    }
+    table
+}
+
+const POWERS: [SimdU32<4>; 4] = powers(SimdU32::from_array([1, 2, 3, u32::MAX]));
+
+const MASKED: SimdU32<4> = POWERS[3]
+    .const_xor(SimdU32::from_array([1; 4]))
+    .const_and(SimdU32::from_array([0xff; 4]))
+    .const_or(SimdU32::from_array([0x100; 4]));
+
+const SCALED: SimdF32<2> = SimdF32::from_array([1.5, -2.0])
+    .const_mul(SimdF32::from_array([2.0, 0.25]))
+    .const_add(SimdF32::from_array([0.5; 2]))
+    .const_sub(SimdF32::from_array([1.0; 2]))
+    .const_div(SimdF32::from_array([2.0; 2]));
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn evaluated_in_constants() {
+    assert_eq!(POWERS[2].to_array(), [1, 4, 9, 1]);
+    assert_eq!(POWERS[3].to_array(), [1, 8, 27, u32::MAX]);
+    assert_eq!(MASKED.to_array(), [0x100, 0x109, 0x11a, 0x1fe]);
+    assert_eq!(SCALED.to_array(), [1.25, -0.5]);
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "std")]
+#![cfg_attr(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature(avx512_target_feature)
+)]
+
+use core_simd::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+core_simd::dispatch! {
+    /// Computes the dot product of two slices.
+    fn dot(a: &[f32], b: &[f32]) -> f32 {
+        let mut sum = f32x8::splat(0.);
+        let mut a_chunks = a.chunks_exact(8);
+        let mut b_chunks = b.chunks_exact(8);
+        for (a, b) in (&mut a_chunks).zip(&mut b_chunks) {
+            let mut x = [0.; 8];
+            let mut y = [0.; 8];
+            x.copy_from_slice(a);
+            y.copy_from_slice(b);
+            sum += f32x8::from_array(x) * f32x8::from_array(y);
+        }
+        let tail = a_chunks.remainder().iter().zip(b_chunks.remainder()).map(|(a, b)| a * b);
+        sum.horizontal_sum() + tail.sum::<f32>()
+    }
+}
+
+core_simd::dispatch! {
+    pub(crate) fn negate_all(values: &mut [i32x4],) {
+        for value in values {
+            *value = -*value;
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn dispatch_with_return_value() {
+    let a: Vec<f32> = (0..19).map(|x| x as f32).collect();
+    let b = vec![2.; 19];
+    assert_eq!(dot(&a, &b), 342.);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn dispatch_without_return_value() {
+    let mut values = [i32x4::from_array([1, -2, 3, i32::MAX]); 3];
+    negate_all(&mut values);
+    assert_eq!(values, [i32x4::from_array([-1, 2, -3, -i32::MAX]); 3]);
+}
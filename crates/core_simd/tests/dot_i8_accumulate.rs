@@ -0,0 +1,38 @@
+use core_simd::Simd;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! test_dot {
+    { $($name:ident: $method:ident, $a:ty, $lanes:literal => $bytes:literal;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            test_helpers::test_3(&|acc: [i32; $lanes], a: [$a; $bytes], b: [i8; $bytes]| {
+                let result = Simd::from_array(acc).$method(Simd::from_array(a), Simd::from_array(b));
+                for i in 0..$lanes {
+                    let mut expected = acc[i];
+                    for j in 4 * i..4 * (i + 1) {
+                        expected = expected.wrapping_add(a[j] as i32 * b[j] as i32);
+                    }
+                    proptest::prop_assert_eq!(result[i], expected);
+                }
+                Ok(())
+            });
+        }
+        )*
+    }
+}
+
+test_dot! {
+    i8_1: dot_i8_accumulate, i8, 1 => 4;
+    i8_4: dot_i8_accumulate, i8, 4 => 16;
+    i8_32: dot_i8_accumulate, i8, 32 => 128;
+    u8i8_1: dot_u8i8_accumulate, u8, 1 => 4;
+    u8i8_4: dot_u8i8_accumulate, u8, 4 => 16;
+    u8i8_32: dot_u8i8_accumulate, u8, 32 => 128;
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "f16")]
+
+use core_simd::{SimdF16, SimdF32, SimdU16};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn round_to_nearest_even() {
+    let x = SimdF32::from_array([
+        1.0 + 2f32.powi(-11),       // tie, rounds down to even
+        1.0 + 3.0 * 2f32.powi(-11), // tie, rounds up to even
+        65519.0,                    // rounds down to f16::MAX
+        65520.0,                    // rounds up to infinity
+        2f32.powi(-25),             // tie between zero and the smallest subnormal
+        3.0 * 2f32.powi(-25),       // tie between subnormals, rounds up to even
+        -0.0,
+        f32::NAN,
+    ]);
+    assert_eq!(
+        SimdF16::from_f32(x).to_bits().to_array(),
+        [0x3c00, 0x3c02, 0x7bff, 0x7c00, 0x0000, 0x0002, 0x8000, 0x7e00]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn roundtrip_all_values() {
+    for bits in (0..=u16::MAX).step_by(8) {
+        let bits = SimdU16::<8>::from_array([
+            bits,
+            bits + 1,
+            bits + 2,
+            bits + 3,
+            bits + 4,
+            bits + 5,
+            bits + 6,
+            bits + 7,
+        ]);
+        let x = SimdF16::from_bits(bits);
+        let roundtrip = SimdF16::from_f32(x.to_f32()).to_bits();
+        for lane in 0..8 {
+            let exponent = bits[lane] & 0x7c00;
+            let mantissa = bits[lane] & 0x03ff;
+            if exponent == 0x7c00 && mantissa != 0 {
+                assert!(x.to_f32()[lane].is_nan());
+                assert_eq!(roundtrip[lane] & 0x7fff, 0x7e00);
+            } else {
+                assert_eq!(roundtrip[lane], bits[lane]);
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn arithmetic() {
+    let a = SimdF16::from_f32(SimdF32::from_array([1.0, 2.5, -3.0, 0.1]));
+    let b = SimdF16::from_f32(SimdF32::from_array([2.0, 0.5, 3.0, 0.2]));
+    assert_eq!((a + b).to_f32().to_array(), [3.0, 3.0, 0.0, 0.2998047]);
+    assert_eq!((a * b).to_f32().to_array(), [2.0, 1.25, -9.0, 0.019989014]);
+    assert_eq!((-a).to_f32().to_array(), [-1.0, -2.5, 3.0, -0.099975586]);
+}
+
+#[test]
+fn lanes() {
+    assert_eq!(SimdF16::<4>::LANES, 4);
+    assert_eq!(SimdF16::<8>::splat_bits(0).lanes(), 8);
+}
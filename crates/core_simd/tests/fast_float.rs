@@ -0,0 +1,94 @@
+macro_rules! fast_float_test {
+    { $vector:ident, $scalar:tt } => {
+        mod $scalar {
+            use core_simd::FastFloat;
+            use test_helpers::ulps::Ulps;
+
+            type Vector<const LANES: usize> = core_simd::$vector<LANES>;
+            type Scalar = $scalar;
+
+            /// Returns true if `x` is within `LANES` rounding errors of `expected`, where each
+            /// error is bounded relative to `magnitude`.
+            fn close<const LANES: usize>(x: Scalar, expected: Scalar, magnitude: Scalar) -> bool {
+                !expected.is_finite()
+                    || !magnitude.is_finite()
+                    || (x - expected).abs() <= magnitude * Scalar::EPSILON * LANES as Scalar + Scalar::MIN_POSITIVE
+            }
+
+            test_helpers::test_lanes! {
+                fn fast_sum<const LANES: usize>() {
+                    test_helpers::test_1(&|x: [Scalar; LANES]| {
+                        let result = Vector::<LANES>::from_array(x).fast_sum();
+                        let expected = x.iter().sum::<Scalar>();
+                        let magnitude = x.iter().map(|x| x.abs()).sum::<Scalar>();
+                        proptest::prop_assert!(close::<LANES>(result, expected, magnitude));
+                        Ok(())
+                    });
+                }
+
+                fn fast_product<const LANES: usize>() {
+                    test_helpers::test_1(&|x: [Scalar; LANES]| {
+                        // Keep the lanes near 1 so that products don't underflow.
+                        let x = x.map(|x| 1.0 + (x % 1.0) / 2.0);
+                        let result = Vector::<LANES>::from_array(x).fast_product();
+                        let expected = x.iter().product::<Scalar>();
+                        proptest::prop_assert!(close::<LANES>(result, expected, expected.abs()));
+                        Ok(())
+                    });
+                }
+
+                fn fast_dot<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [Scalar; LANES], y: [Scalar; LANES]| {
+                        let result = Vector::<LANES>::from_array(x).fast_dot(Vector::from_array(y));
+                        let expected = x.iter().zip(&y).map(|(x, y)| x * y).sum::<Scalar>();
+                        let magnitude = x.iter().zip(&y).map(|(x, y)| (x * y).abs()).sum::<Scalar>();
+                        proptest::prop_assert!(close::<LANES>(result, expected, magnitude));
+                        Ok(())
+                    });
+                }
+
+                fn fast_dot_overflow<const LANES: usize>() {
+                    // Products that overflow must sum to an infinity, not `NaN`, with or without
+                    // fused multiply-add.
+                    let big = Vector::<LANES>::splat(Scalar::MAX);
+                    let two = Vector::<LANES>::splat(2.);
+                    assert_eq!(big.fast_dot(two), Scalar::INFINITY);
+                    assert_eq!((-big).fast_dot(two), Scalar::NEG_INFINITY);
+                }
+
+                fn fast_mul_add<const LANES: usize>() {
+                    test_helpers::test_3(&|x: [Scalar; LANES], a: [Scalar; LANES], b: [Scalar; LANES]| {
+                        let result = Vector::<LANES>::from_array(x)
+                            .fast_mul_add(Vector::from_array(a), Vector::from_array(b))
+                            .to_array();
+                        for i in 0..LANES {
+                            let expected = x[i] * a[i] + b[i];
+                            let magnitude = (x[i] * a[i]).abs() + b[i].abs();
+                            proptest::prop_assert!(close::<2>(result[i], expected, magnitude));
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn fast_div<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [Scalar; LANES], y: [Scalar; LANES]| {
+                        let result = Vector::<LANES>::from_array(x)
+                            .fast_div(Vector::from_array(y))
+                            .to_array();
+                        for i in 0..LANES {
+                            let expected = x[i] / y[i];
+                            let reciprocal = 1.0 / y[i];
+                            if expected.is_normal() && reciprocal.is_normal() {
+                                proptest::prop_assert!(result[i].ulps(expected) <= 2);
+                            }
+                        }
+                        Ok(())
+                    });
+                }
+            }
+        }
+    }
+}
+
+fast_float_test! { SimdF32, f32 }
+fast_float_test! { SimdF64, f64 }
@@ -0,0 +1,92 @@
+use core_simd::{filter_bitmap, filter_indices, Simd};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! test_filter {
+    { $($name:ident: $ty:ty, $len:literal, $lanes:literal;)* } => {
+        $(
+        mod $name {
+            use super::*;
+
+            fn predicate(v: Simd<$ty, $lanes>) -> core_simd::Mask<<$ty as core_simd::SimdElement>::Mask, $lanes> {
+                v.lanes_lt(Simd::splat(0 as $ty))
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn bitmap() {
+                test_helpers::test_1(&|values: [$ty; $len]| {
+                    let mut bitmap = [0xff; $len / 8 + 2];
+                    let count = filter_bitmap(&values, &mut bitmap, predicate);
+                    let mut expected = [0u8; $len / 8 + 2];
+                    for (i, value) in values.iter().enumerate() {
+                        expected[i / 8] |= ((*value < 0 as $ty) as u8) << (i % 8);
+                    }
+                    // The bytes after the bitmap are left untouched.
+                    for (i, byte) in expected.iter_mut().enumerate() {
+                        if i * 8 >= $len {
+                            *byte = 0xff;
+                        }
+                    }
+                    proptest::prop_assert_eq!(bitmap, expected);
+                    proptest::prop_assert_eq!(count, values.iter().filter(|x| **x < 0 as $ty).count());
+                    Ok(())
+                });
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn indices() {
+                test_helpers::test_1(&|values: [$ty; $len]| {
+                    let mut indices = [u32::MAX; $len];
+                    let count = filter_indices(&values, &mut indices, predicate);
+                    let expected: Vec<u32> = (0..$len as u32).filter(|i| values[*i as usize] < 0 as $ty).collect();
+                    proptest::prop_assert_eq!(&indices[..count], &expected[..]);
+                    Ok(())
+                });
+            }
+        }
+        )*
+    }
+}
+
+test_filter! {
+    i8_exact_16: i8, 64, 16;
+    i8_tail_16: i8, 45, 16;
+    i32_tail_4: i32, 13, 4;
+    i32_tail_2: i32, 7, 2;
+    f32_tail_8: f32, 21, 8;
+    i64_tail_1: i64, 3, 1;
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn empty() {
+    let mut bitmap = [0xff; 1];
+    let mut indices = [0; 0];
+    assert_eq!(
+        filter_bitmap::<u32, 4>(&[], &mut bitmap, |v| v.lanes_eq(v)),
+        0
+    );
+    assert_eq!(bitmap, [0xff]);
+    assert_eq!(
+        filter_indices::<u32, 4>(&[], &mut indices, |v| v.lanes_eq(v)),
+        0
+    );
+}
+
+#[test]
+#[should_panic]
+fn bitmap_too_short() {
+    filter_bitmap::<u32, 4>(&[0; 9], &mut [0; 1], |v| v.lanes_eq(v));
+}
+
+#[test]
+#[should_panic]
+fn indices_too_short() {
+    filter_indices::<u32, 4>(&[0; 9], &mut [0; 8], |v| v.lanes_eq(v));
+}
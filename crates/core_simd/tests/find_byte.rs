@@ -0,0 +1,29 @@
+use core_simd::find_byte;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn find_byte_every_position() {
+    // Covers needles in whole vectors, in the remainder, and at the boundary between them.
+    let mut haystack = [0u8; 300];
+    for (i, byte) in haystack.iter_mut().enumerate() {
+        *byte = (i % 251) as u8;
+    }
+    for len in [0, 1, 15, 16, 63, 64, 65, 130, 300] {
+        let haystack = &haystack[..len];
+        for needle in 0..=255u8 {
+            assert_eq!(
+                find_byte(haystack, needle),
+                haystack.iter().position(|&b| b == needle),
+                "needle {} in {} bytes",
+                needle,
+                len
+            );
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use core_simd::{Simd, SimdQ15, SimdQ31};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! test_fixed {
+    { $($mod:ident: $name:ident, $bits:ident, $frac:literal, $float:ident;)* } => {
+        $(
+        mod $mod {
+            use super::*;
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn float_roundtrip() {
+                test_helpers::test_1(&|x: [$bits; 4]| {
+                    let fixed = $name::<4>::from_bits(Simd::from_array(x));
+                    proptest::prop_assert_eq!($name::from_float(fixed.to_float()), fixed);
+                    Ok(())
+                });
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn from_float_matches_scalar_round() {
+                test_helpers::test_1(&|x: [$float; 4]| {
+                    let scale = (1u64 << $frac) as $float;
+                    let result = $name::<4>::from_float(Simd::from_array(x)).to_bits().to_array();
+                    for i in 0..4 {
+                        // Scalar `round` ties away from zero, and the cast saturates.
+                        proptest::prop_assert_eq!(result[i], (x[i] * scale).round() as $bits);
+                    }
+                    Ok(())
+                });
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn from_float_below_tie() {
+                // The largest value below one half must not round up when one half is added to
+                // it in floating point.
+                let eps = $name::<4>::EPSILON;
+                let below = 0.5 - $float::EPSILON / 4.;
+                let x = Simd::from_array([below * eps, -below * eps, 0.5 * eps, 1.5 * eps]);
+                assert_eq!($name::from_float(x).to_bits().to_array(), [0, 0, 1, 2]);
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn from_float_rounds_and_saturates() {
+                let eps = $name::<8>::EPSILON;
+                let x = Simd::from_array([
+                    0.4 * eps,
+                    0.5 * eps,
+                    -0.5 * eps,
+                    1.0 - 0.4 * eps,
+                    2.0,
+                    -2.0,
+                    $float::INFINITY,
+                    $float::NEG_INFINITY,
+                ]);
+                assert_eq!(
+                    $name::from_float(x).to_bits().to_array(),
+                    [0, 1, -1, $bits::MAX, $bits::MAX, $bits::MIN, $bits::MAX, $bits::MIN]
+                );
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn saturating_arithmetic() {
+                let a = $name::<4>::from_bits(Simd::from_array([$bits::MAX, $bits::MIN, $bits::MIN, 1]));
+                let b = $name::<4>::from_bits(Simd::from_array([1, -1, $bits::MIN, $bits::MIN]));
+                assert_eq!(
+                    a.saturating_add(b).to_bits().to_array(),
+                    [$bits::MAX, $bits::MIN, $bits::MIN, $bits::MIN + 1]
+                );
+                assert_eq!(
+                    a.saturating_sub(b).to_bits().to_array(),
+                    [$bits::MAX - 1, $bits::MIN + 1, 0, $bits::MAX]
+                );
+                assert_eq!(
+                    a.saturating_mul(b).to_bits().to_array(),
+                    [1, 1, $bits::MAX, -1]
+                );
+                assert_eq!(
+                    a.saturating_neg().to_bits().to_array(),
+                    [$bits::MIN + 1, $bits::MAX, $bits::MAX, -1]
+                );
+            }
+        }
+        )*
+    }
+}
+
+test_fixed! {
+    q15: SimdQ15, i16, 15, f32;
+    q31: SimdQ31, i32, 31, f64;
+}
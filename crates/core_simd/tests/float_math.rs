@@ -0,0 +1,344 @@
+/// Test a unary function against the scalar math library, within `max_ulps`, both for arbitrary
+/// inputs and for inputs uniformly distributed over `range`.
+macro_rules! test_ulps {
+    { $fn:ident, $max_ulps:literal, $range:expr } => {
+        test_helpers::test_lanes! {
+            fn $fn<const LANES: usize>() {
+                test_helpers::test_unary_elementwise_ulps(
+                    &Vector::<LANES>::$fn,
+                    &Scalar::$fn,
+                    &|_| true,
+                    $max_ulps,
+                );
+
+                let range: core::ops::Range<Scalar> = $range;
+                let mut runner = proptest::test_runner::TestRunner::default();
+                runner.run(
+                    &test_helpers::array::UniformArrayStrategy::new(range),
+                    |x: [Scalar; LANES]| {
+                        let result = Vector::from_array(x).$fn().to_array();
+                        for (i, r) in x.iter().zip(result.iter()) {
+                            let expected = i.$fn();
+                            proptest::prop_assert!(
+                                r.ulps(expected) <= $max_ulps,
+                                "input: {:?}, result: {:?}, expected: {:?}",
+                                i,
+                                r,
+                                expected,
+                            );
+                        }
+                        Ok(())
+                    },
+                ).unwrap();
+            }
+        }
+    }
+}
+
+macro_rules! float_math_test {
+    { $vector:ident, $scalar:tt, exp_range: $exp_range:expr, tan_ulps: $tan_ulps:literal, asin_ulps: $asin_ulps:literal } => {
+        mod $scalar {
+            use test_helpers::ulps::Ulps;
+
+            type Vector<const LANES: usize> = core_simd::$vector<LANES>;
+            type Scalar = $scalar;
+
+            test_ulps! { exp, 2, $exp_range }
+            test_ulps! { ln, 2, 0.0..1e6 }
+            test_ulps! { log2, 2, 0.0..1e6 }
+            test_ulps! { log10, 2, 0.0..1e6 }
+            test_ulps! { exp_m1, 2, $exp_range }
+            test_ulps! { ln_1p, 2, -1.0..1.0 }
+            test_ulps! { sin, 2, -100.0..100.0 }
+            test_ulps! { cos, 2, -100.0..100.0 }
+            test_ulps! { tan, $tan_ulps, -100.0..100.0 }
+            test_ulps! { asin, $asin_ulps, -1.0..1.0 }
+            test_ulps! { acos, 2, -1.0..1.0 }
+            test_ulps! { atan, 2, -100.0..100.0 }
+            test_ulps! { cbrt, 1, -100.0..100.0 }
+            test_ulps! { sinh, 3, -100.0..100.0 }
+            test_ulps! { cosh, 3, -100.0..100.0 }
+            test_ulps! { tanh, 2, -10.0..10.0 }
+
+            test_helpers::test_lanes! {
+                fn exp_special<const LANES: usize>() {
+                    let special = |x: Scalar| Vector::<LANES>::splat(x).exp().to_array()[0];
+                    assert_eq!(special(0.0), 1.0);
+                    assert_eq!(special(-0.0), 1.0);
+                    assert_eq!(special(Scalar::INFINITY), Scalar::INFINITY);
+                    assert_eq!(special(Scalar::NEG_INFINITY), 0.0);
+                    assert!(special(Scalar::NAN).is_nan());
+                }
+
+                fn trig_special<const LANES: usize>() {
+                    let sin = |x: Scalar| Vector::<LANES>::splat(x).sin().to_array()[0];
+                    let cos = |x: Scalar| Vector::<LANES>::splat(x).cos().to_array()[0];
+                    let tan = |x: Scalar| Vector::<LANES>::splat(x).tan().to_array()[0];
+                    assert_eq!(sin(0.0).to_bits(), (0.0 as Scalar).to_bits());
+                    assert_eq!(sin(-0.0).to_bits(), (-0.0 as Scalar).to_bits());
+                    assert_eq!(tan(-0.0).to_bits(), (-0.0 as Scalar).to_bits());
+                    assert_eq!(cos(0.0), 1.0);
+                    for x in [Scalar::INFINITY, Scalar::NEG_INFINITY, Scalar::NAN] {
+                        assert!(sin(x).is_nan());
+                        assert!(cos(x).is_nan());
+                        assert!(tan(x).is_nan());
+                    }
+                }
+
+                fn atan2<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise_ulps(
+                        &Vector::<LANES>::atan2,
+                        &Scalar::atan2,
+                        &|_, _| true,
+                        2,
+                    );
+                }
+
+                fn hypot<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise_ulps(
+                        &Vector::<LANES>::hypot,
+                        &Scalar::hypot,
+                        &|_, _| true,
+                        2,
+                    );
+                }
+
+                fn rsqrt<const LANES: usize>() {
+                    let mut runner = proptest::test_runner::TestRunner::default();
+                    for range in [Scalar::MIN_POSITIVE..4.0, 1.0..1e30, Scalar::MIN_POSITIVE..Scalar::MAX] {
+                        runner.run(
+                            &test_helpers::array::UniformArrayStrategy::new(range),
+                            |x: [Scalar; LANES]| {
+                                let v = Vector::from_array(x);
+                                let (approx, refined) = (v.rsqrt_approx().to_array(), v.rsqrt_refined().to_array());
+                                for i in 0..LANES {
+                                    let expected = 1.0 / (x[i] as f64).sqrt();
+                                    let error = |y: Scalar| ((y as f64 - expected) / expected).abs();
+                                    proptest::prop_assert!(error(approx[i]) < 2f64.powi(-9), "approx of {:?}", x[i]);
+                                    proptest::prop_assert!(error(refined[i]) < 2f64.powi(-17), "refined of {:?}", x[i]);
+                                }
+                                Ok(())
+                            },
+                        ).unwrap();
+                    }
+                }
+
+                fn ldexp<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [Scalar; LANES], n: [i32; LANES]| {
+                        let n = n.map(|n| n % 1200);
+                        let result = Vector::<LANES>::from_array(x).ldexp(core_simd::Simd::from_array(n));
+                        for i in 0..LANES {
+                            // Scale in steps that are exact until the last one.
+                            let mut expected = x[i];
+                            let mut remaining = n[i];
+                            while remaining.abs() > 64 {
+                                let step = remaining.signum() * 64;
+                                let next = expected * (2.0 as Scalar).powi(step);
+                                if !next.is_normal() {
+                                    break;
+                                }
+                                expected = next;
+                                remaining -= step;
+                            }
+                            let expected = if x[i] == 0.0 || !x[i].is_finite() {
+                                x[i]
+                            } else {
+                                expected * (2.0 as Scalar).powi(remaining)
+                            };
+                            test_helpers::prop_assert_biteq!(result[i], expected);
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn ldexp_special<const LANES: usize>() {
+                    let ldexp = |x: Scalar, n: i32| {
+                        Vector::<LANES>::splat(x).ldexp(core_simd::Simd::splat(n)).to_array()[0]
+                    };
+                    let subnormal = Scalar::from_bits(1);
+                    assert_eq!(ldexp(1.0, Scalar::MIN_EXP - Scalar::MANTISSA_DIGITS as i32), subnormal);
+                    assert_eq!(ldexp(subnormal, Scalar::MANTISSA_DIGITS as i32 - Scalar::MIN_EXP), 1.0);
+                    // Rounds to even once, rather than rounding twice.
+                    assert_eq!(ldexp(Scalar::MAX, -2 * (Scalar::MAX_EXP - 1)), Scalar::MIN_POSITIVE);
+                    assert_eq!(ldexp(1.5 * subnormal, 0), 1.5 * subnormal);
+                    assert_eq!(ldexp(-1.0, i32::MAX), Scalar::NEG_INFINITY);
+                    assert_eq!(ldexp(-1.0, i32::MIN).to_bits(), (-0.0 as Scalar).to_bits());
+                    assert_eq!(ldexp(Scalar::INFINITY, i32::MIN), Scalar::INFINITY);
+                    assert!(ldexp(Scalar::NAN, 1).is_nan());
+                }
+
+                fn frexp<const LANES: usize>() {
+                    test_helpers::test_1(&|x: [Scalar; LANES]| {
+                        let (significand, exponent) = Vector::<LANES>::from_array(x).frexp();
+                        let roundtrip = significand.ldexp(exponent).to_array();
+                        for i in 0..LANES {
+                            let (m, e) = (significand[i], exponent[i]);
+                            if x[i] == 0.0 || !x[i].is_finite() {
+                                test_helpers::prop_assert_biteq!(m, x[i]);
+                                proptest::prop_assert_eq!(e, 0);
+                            } else {
+                                proptest::prop_assert!((0.5..1.0).contains(&m.abs()));
+                                proptest::prop_assert_eq!(m.is_sign_negative(), x[i].is_sign_negative());
+                                test_helpers::prop_assert_biteq!(roundtrip[i], x[i]);
+                            }
+                        }
+                        Ok(())
+                    });
+                    let subnormal = Scalar::from_bits(1);
+                    let (m, e) = Vector::<LANES>::splat(subnormal).frexp();
+                    assert_eq!(m[0], 0.5);
+                    assert_eq!(e[0], Scalar::MIN_EXP - Scalar::MANTISSA_DIGITS as i32 + 1);
+                }
+
+                fn cbrt_special<const LANES: usize>() {
+                    let cbrt = |x: Scalar| Vector::<LANES>::splat(x).cbrt().to_array()[0];
+                    assert_eq!(cbrt(27.0), 3.0);
+                    assert_eq!(cbrt(-8.0), -2.0);
+                    assert_eq!(cbrt(-0.0).to_bits(), (-0.0 as Scalar).to_bits());
+                    assert_eq!(cbrt(Scalar::NEG_INFINITY), Scalar::NEG_INFINITY);
+                    let subnormal = Scalar::from_bits(1);
+                    assert!(cbrt(subnormal).ulps(subnormal.cbrt()) <= 1);
+                    assert!(cbrt(Scalar::NAN).is_nan());
+                }
+
+                fn hypot_special<const LANES: usize>() {
+                    let hypot = |x: Scalar, y: Scalar| {
+                        Vector::<LANES>::splat(x).hypot(Vector::splat(y)).to_array()[0]
+                    };
+                    assert_eq!(hypot(3.0, -4.0), 5.0);
+                    assert_eq!(hypot(-0.0, 0.0).to_bits(), (0.0 as Scalar).to_bits());
+                    assert_eq!(hypot(Scalar::MAX / 2.0, 0.0), Scalar::MAX / 2.0);
+                    let big = (2.0 as Scalar).powi(Scalar::MAX_EXP - 4);
+                    assert_eq!(hypot(3.0 * big, 4.0 * big), 5.0 * big);
+                    let subnormal = |x| Scalar::from_bits(x);
+                    assert_eq!(hypot(subnormal(3), subnormal(4)), subnormal(5));
+                    assert_eq!(hypot(Scalar::MAX, Scalar::MAX), Scalar::INFINITY);
+                    assert_eq!(hypot(Scalar::NEG_INFINITY, Scalar::NAN), Scalar::INFINITY);
+                    assert_eq!(hypot(Scalar::NAN, Scalar::INFINITY), Scalar::INFINITY);
+                    assert!(hypot(Scalar::NAN, 1.0).is_nan());
+                }
+
+                fn powf<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise_ulps(
+                        &Vector::<LANES>::powf,
+                        &Scalar::powf,
+                        &|_, _| true,
+                        2,
+                    );
+                }
+
+                fn powf_special<const LANES: usize>() {
+                    let powf = |x: Scalar, n: Scalar| {
+                        Vector::<LANES>::splat(x).powf(Vector::splat(n)).to_array()[0]
+                    };
+                    assert_eq!(powf(2.0, 10.0), 1024.0);
+                    assert_eq!(powf(-2.0, 3.0), -8.0);
+                    assert!(powf(0.5, 120.0).ulps(0.5f64.powi(120) as Scalar) <= 2);
+                    assert!(powf(-2.0, 0.5).is_nan());
+                    let special = [
+                        0.0,
+                        -0.0,
+                        0.5,
+                        -0.5,
+                        1.0,
+                        -1.0,
+                        2.0,
+                        -2.0,
+                        3.0,
+                        -3.0,
+                        Scalar::INFINITY,
+                        Scalar::NEG_INFINITY,
+                        Scalar::NAN,
+                    ];
+                    for x in special {
+                        for n in special {
+                            let (result, expected) = (powf(x, n), x.powf(n));
+                            let same_sign = expected.is_nan()
+                                || result.is_sign_negative() == expected.is_sign_negative();
+                            assert!(
+                                result.ulps(expected) <= 2 && same_sign,
+                                "{}^{}: {} != {}",
+                                x,
+                                n,
+                                result,
+                                expected,
+                            );
+                        }
+                    }
+                }
+
+                fn inverse_trig_special<const LANES: usize>() {
+                    let asin = |x: Scalar| Vector::<LANES>::splat(x).asin().to_array()[0];
+                    let acos = |x: Scalar| Vector::<LANES>::splat(x).acos().to_array()[0];
+                    let atan2 = |y: Scalar, x: Scalar| {
+                        Vector::<LANES>::splat(y).atan2(Vector::splat(x)).to_array()[0]
+                    };
+                    assert_eq!(asin(-0.0).to_bits(), (-0.0 as Scalar).to_bits());
+                    assert_eq!(asin(1.0), Scalar::asin(1.0));
+                    assert_eq!(acos(1.0), 0.0);
+                    assert_eq!(acos(-1.0), Scalar::acos(-1.0));
+                    assert!(asin(1.5).is_nan());
+                    assert!(acos(-1.5).is_nan());
+                    let special = [0.0, -0.0, 1.0, -1.0, Scalar::INFINITY, Scalar::NEG_INFINITY];
+                    for y in special {
+                        for x in special {
+                            assert_eq!(atan2(y, x).to_bits(), y.atan2(x).to_bits(), "{} {}", y, x);
+                        }
+                        assert!(atan2(y, Scalar::NAN).is_nan());
+                        assert!(atan2(Scalar::NAN, y).is_nan());
+                    }
+                }
+
+                fn m1_1p_special<const LANES: usize>() {
+                    let exp_m1 = |x: Scalar| Vector::<LANES>::splat(x).exp_m1().to_array()[0];
+                    let ln_1p = |x: Scalar| Vector::<LANES>::splat(x).ln_1p().to_array()[0];
+                    assert_eq!(exp_m1(-0.0).to_bits(), (-0.0 as Scalar).to_bits());
+                    assert_eq!(ln_1p(-0.0).to_bits(), (-0.0 as Scalar).to_bits());
+                    assert_eq!(exp_m1(1e-30), 1e-30);
+                    assert_eq!(ln_1p(1e-30), 1e-30);
+                    assert_eq!(exp_m1(Scalar::INFINITY), Scalar::INFINITY);
+                    assert_eq!(exp_m1(Scalar::NEG_INFINITY), -1.0);
+                    assert_eq!(ln_1p(-1.0), Scalar::NEG_INFINITY);
+                    assert_eq!(ln_1p(Scalar::INFINITY), Scalar::INFINITY);
+                    assert!(ln_1p(-2.0).is_nan());
+                    assert!(exp_m1(Scalar::NAN).is_nan());
+                    assert!(ln_1p(Scalar::NAN).is_nan());
+                }
+
+                fn hyperbolic_special<const LANES: usize>() {
+                    let sinh = |x: Scalar| Vector::<LANES>::splat(x).sinh().to_array()[0];
+                    let cosh = |x: Scalar| Vector::<LANES>::splat(x).cosh().to_array()[0];
+                    let tanh = |x: Scalar| Vector::<LANES>::splat(x).tanh().to_array()[0];
+                    assert_eq!(sinh(-0.0).to_bits(), (-0.0 as Scalar).to_bits());
+                    assert_eq!(tanh(-0.0).to_bits(), (-0.0 as Scalar).to_bits());
+                    assert_eq!(cosh(0.0), 1.0);
+                    assert_eq!(sinh(Scalar::NEG_INFINITY), Scalar::NEG_INFINITY);
+                    assert_eq!(cosh(Scalar::NEG_INFINITY), Scalar::INFINITY);
+                    assert_eq!(tanh(Scalar::INFINITY), 1.0);
+                    assert_eq!(tanh(Scalar::NEG_INFINITY), -1.0);
+                    for x in [Scalar::MAX.ln() + 0.5, Scalar::MAX.ln() + 1.0] {
+                        assert!(sinh(x).ulps(x.sinh()) <= 3);
+                        assert!(cosh(-x).ulps(x.cosh()) <= 3);
+                    }
+                    assert!(sinh(Scalar::NAN).is_nan());
+                    assert!(cosh(Scalar::NAN).is_nan());
+                    assert!(tanh(Scalar::NAN).is_nan());
+                }
+
+                fn ln_special<const LANES: usize>() {
+                    let special = |x: Scalar| Vector::<LANES>::splat(x).ln().to_array()[0];
+                    assert_eq!(special(1.0), 0.0);
+                    assert_eq!(special(0.0), Scalar::NEG_INFINITY);
+                    assert_eq!(special(-0.0), Scalar::NEG_INFINITY);
+                    assert_eq!(special(Scalar::INFINITY), Scalar::INFINITY);
+                    assert!(special(-1.0).is_nan());
+                    assert!(special(Scalar::NEG_INFINITY).is_nan());
+                    assert!(special(Scalar::NAN).is_nan());
+                }
+            }
+        }
+    }
+}
+
+float_math_test! { SimdF32, f32, exp_range: -110.0..90.0, tan_ulps: 3, asin_ulps: 3 }
+float_math_test! { SimdF64, f64, exp_range: -750.0..710.0, tan_ulps: 4, asin_ulps: 2 }
@@ -0,0 +1,44 @@
+use core_simd::Simd;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// The length of the slice gathered from and scattered to.  Indices up to 255 are generated, so
+/// most are out of bounds.
+const LEN: usize = 100;
+
+test_helpers::test_lanes! {
+    fn gather_or<const LANES: usize>() {
+        test_helpers::test_2(&|idxs: [u8; LANES], or: [i32; LANES]| {
+            let mut slice = [0; LEN];
+            for (i, x) in slice.iter_mut().enumerate() {
+                *x = i as i32;
+            }
+            let result = Simd::gather_or(&slice, Simd::from_array(idxs.map(usize::from)), Simd::from_array(or));
+            for i in 0..LANES {
+                let expected = slice.get(usize::from(idxs[i])).copied().unwrap_or(or[i]);
+                proptest::prop_assert_eq!(result[i], expected);
+            }
+            Ok(())
+        });
+    }
+
+    fn scatter<const LANES: usize>() {
+        test_helpers::test_2(&|idxs: [u8; LANES], values: [i32; LANES]| {
+            let mut slice = [0; LEN];
+            Simd::from_array(values).scatter(&mut slice, Simd::from_array(idxs.map(usize::from)));
+            // Later lanes overwrite earlier lanes with the same index.
+            let mut expected = [0; LEN];
+            for i in 0..LANES {
+                if let Some(x) = expected.get_mut(usize::from(idxs[i])) {
+                    *x = values[i];
+                }
+            }
+            proptest::prop_assert_eq!(&slice[..], &expected[..]);
+            Ok(())
+        });
+    }
+}
@@ -0,0 +1,3 @@
+#[macro_use]
+mod ops_macros;
+impl_signed_tests! { SimdI128, i128 }
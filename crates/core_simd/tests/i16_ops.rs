@@ -1,3 +1,4 @@
 #[macro_use]
 mod ops_macros;
 impl_signed_tests! { SimdI16, i16 }
+impl_fixed_point_tests! { SimdI16, i16 }
@@ -1,3 +1,4 @@
 #[macro_use]
 mod ops_macros;
 impl_signed_tests! { SimdI32, i32 }
+impl_fixed_point_tests! { SimdI32, i32 }
@@ -0,0 +1,120 @@
+use core_simd::Simd;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! test_interleaved {
+    { $($name:ident: $scalar:ty, $lanes:literal, $streams:literal;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            let mut data = [0 as $scalar; $lanes * $streams + 1];
+            for (i, x) in data.iter_mut().enumerate() {
+                *x = i as $scalar;
+            }
+            let streams = Simd::<$scalar, $lanes>::load_interleaved::<$streams>(&data);
+            for s in 0..$streams {
+                for i in 0..$lanes {
+                    assert_eq!(streams[s][i], data[i * $streams + s]);
+                }
+            }
+
+            let mut stored = [0 as $scalar; $lanes * $streams + 1];
+            Simd::<$scalar, $lanes>::store_interleaved(streams, &mut stored);
+            assert_eq!(stored[..$lanes * $streams], data[..$lanes * $streams]);
+            assert_eq!(stored[$lanes * $streams], 0 as $scalar);
+        }
+        )*
+    }
+}
+
+test_interleaved! {
+    interleaved_u32_4x1: u32, 4, 1;
+    interleaved_u8_16x2: u8, 16, 2;
+    interleaved_u8_16x3: u8, 16, 3;
+    interleaved_u8_8x4: u8, 8, 4;
+    interleaved_f32_4x2: f32, 4, 2;
+    interleaved_i16_32x3: i16, 32, 3;
+    interleaved_u64_1x4: u64, 1, 4;
+    interleaved_u16_2x3: u16, 2, 3;
+    interleaved_f64_4x3: f64, 4, 3;
+    interleaved_u8_128x2: u8, 128, 2;
+    interleaved_u16_64x3: u16, 64, 3;
+    interleaved_u8_64x4: u8, 64, 4;
+    interleaved_u32_8x5: u32, 8, 5;
+}
+
+#[test]
+#[should_panic]
+fn load_interleaved_short_slice() {
+    let _ = Simd::<u8, 4>::load_interleaved::<3>(&[0; 11]);
+}
+
+#[test]
+#[should_panic]
+fn store_interleaved_short_slice() {
+    Simd::<u8, 4>::store_interleaved([Simd::splat(0); 2], &mut [0; 7]);
+}
+
+macro_rules! test_stride {
+    { $($name:ident: $scalar:ty, $lanes:literal;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            let mut data = [0 as $scalar; 5 * $lanes];
+            for (i, x) in data.iter_mut().enumerate() {
+                *x = i as $scalar;
+            }
+            for stride in 0..=5 {
+                // The shortest slice for the stride is gathered, and a slice of `stride * LANES`
+                // elements uses the interleaved shuffles for small strides.
+                let shortest = ($lanes - 1) * stride + 1;
+                for &len in &[shortest, shortest.max(stride * $lanes)] {
+                    let v = Simd::<$scalar, $lanes>::gather_stride(&data[..len], stride);
+                    for i in 0..$lanes {
+                        assert_eq!(v[i], data[i * stride]);
+                    }
+
+                    let mut stored = [<$scalar>::MAX; 5 * $lanes];
+                    (v + Simd::splat(1 as $scalar)).scatter_stride(&mut stored[..len], stride);
+                    for (i, x) in stored.iter().enumerate() {
+                        let expected = if stride == 0 {
+                            if i == 0 { data[0] + 1 as $scalar } else { <$scalar>::MAX }
+                        } else if i % stride == 0 && i / stride < $lanes {
+                            data[i] + 1 as $scalar
+                        } else {
+                            <$scalar>::MAX
+                        };
+                        assert_eq!(*x, expected);
+                    }
+                }
+            }
+        }
+        )*
+    }
+}
+
+test_stride! {
+    stride_u8_16: u8, 16;
+    stride_f32_4: f32, 4;
+    stride_i64_1: i64, 1;
+    stride_u16_8: u16, 8;
+    stride_u32_64: u32, 64;
+}
+
+#[test]
+#[should_panic]
+fn gather_stride_short_slice() {
+    let _ = Simd::<u8, 4>::gather_stride(&[0; 9], 3);
+}
+
+#[test]
+#[should_panic]
+fn scatter_stride_overflow() {
+    Simd::<u8, 4>::splat(0).scatter_stride(&mut [0; 4], usize::MAX);
+}
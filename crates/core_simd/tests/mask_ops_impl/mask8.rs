@@ -1,3 +1,5 @@
 mask_tests! { mask8x8, 8 }
 mask_tests! { mask8x16, 16 }
 mask_tests! { mask8x32, 32 }
+mask_tests! { mask8x64, 64 }
+mask_tests! { mask8x128, 128 }
@@ -3,6 +3,7 @@ mod mask_macros;
 
 #[rustfmt::skip]
 mod mask8;
+mod mask128;
 mod mask16;
 mod mask32;
 mod mask64;
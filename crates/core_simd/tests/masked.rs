@@ -0,0 +1,163 @@
+use core_simd::{Mask, MaskedSimd, Simd};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Converts the low bits of `bits` to a mask.
+fn mask_from_bits<T: core_simd::MaskElement>(bits: u8) -> Mask<T, 4> {
+    Mask::from_array([bits & 1 != 0, bits & 2 != 0, bits & 4 != 0, bits & 8 != 0])
+}
+
+macro_rules! test_binary_op {
+    { $($name:ident: $ty:ty, $op:tt, $scalar_fn:expr;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            test_helpers::test_3(&|values: [[$ty; 4]; 2], a_bits: u8, b_bits: u8| {
+                let a = MaskedSimd::new(Simd::from_array(values[0]), mask_from_bits(a_bits));
+                let b = MaskedSimd::new(Simd::from_array(values[1]), mask_from_bits(b_bits));
+                let result = a $op b;
+                let (a, b) = (a.to_options(), b.to_options());
+                for (i, lane) in result.to_options().iter().enumerate() {
+                    let expected = match (a[i], b[i]) {
+                        (Some(x), Some(y)) => Some($scalar_fn(x, y)),
+                        _ => None,
+                    };
+                    proptest::prop_assert_eq!(lane.map(<$ty>::to_bits), expected.map(<$ty>::to_bits));
+                }
+                Ok(())
+            });
+        }
+        )*
+    }
+}
+
+trait ToBits: Copy {
+    fn to_bits(self) -> u32;
+}
+
+impl ToBits for u32 {
+    fn to_bits(self) -> u32 {
+        self
+    }
+}
+
+test_binary_op! {
+    add: f32, +, |x: f32, y: f32| x + y;
+    sub: f32, -, |x: f32, y: f32| x - y;
+    mul: f32, *, |x: f32, y: f32| x * y;
+    bitand: u32, &, |x: u32, y: u32| x & y;
+    bitor: u32, |, |x: u32, y: u32| x | y;
+    bitxor: u32, ^, |x: u32, y: u32| x ^ y;
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn new_discards_null_lanes() {
+    let valid = Mask::from_array([true, false, true, false]);
+    let a = MaskedSimd::new(Simd::from_array([1, 2, 3, 4]), valid);
+    assert_eq!(a, MaskedSimd::from_options([Some(1), None, Some(3), None]));
+    assert_eq!(a.value().to_array(), [1, 0, 3, 0]);
+    assert_eq!(a.validity(), valid);
+    assert_eq!(a.get(0), Some(1));
+    assert_eq!(a.get(1), None);
+    assert_eq!(a.unwrap_or(Simd::splat(-1)).to_array(), [1, -1, 3, -1]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn constructors() {
+    let x = Simd::from_array([1u8, 2, 3, 4]);
+    assert_eq!(
+        MaskedSimd::from(x).to_options(),
+        [Some(1), Some(2), Some(3), Some(4)]
+    );
+    assert_eq!(MaskedSimd::<u8, 4>::null().to_options(), [None; 4]);
+    assert_eq!(MaskedSimd::<u8, 4>::default(), MaskedSimd::null());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn null_lanes_do_not_overflow() {
+    let a = MaskedSimd::from_options([None, Some(i32::MAX), Some(-1), None]);
+    let b = MaskedSimd::from_options([Some(i32::MIN), None, Some(2), None]);
+    assert_eq!((a - b).to_options(), [None, None, Some(-3), None]);
+    assert_eq!((b - a).to_options(), [None, None, Some(3), None]);
+    assert_eq!((a * b).to_options(), [None, None, Some(-2), None]);
+    assert_eq!((-a).to_options(), [None, Some(-i32::MAX), Some(1), None]);
+
+    let mut c = a;
+    c += b;
+    assert_eq!(c.to_options(), [None, None, Some(1), None]);
+}
+
+/// Converts the low bits of `bits` and `valid_bits` to boolean lanes, with `None` for null lanes.
+fn kleene_from_bits(bits: u8, valid_bits: u8) -> MaskedSimd<i32, 4> {
+    MaskedSimd::new(mask_from_bits(bits).to_int(), mask_from_bits(valid_bits))
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn kleene_logic() {
+    let t = MaskedSimd::<i32, 4>::from_value(Simd::splat(-1));
+    let f = MaskedSimd::<i32, 4>::from_value(Simd::splat(0));
+    let null = MaskedSimd::<i32, 4>::null();
+    assert_eq!(f.kleene_and(null), f);
+    assert_eq!(null.kleene_and(f), f);
+    assert_eq!(t.kleene_and(null), null);
+    assert_eq!(t.kleene_or(null), t);
+    assert_eq!(null.kleene_or(t), t);
+    assert_eq!(f.kleene_or(null), null);
+    assert_eq!(null.kleene_and(null), null);
+    assert_eq!(null.kleene_or(null), null);
+
+    // Strict propagation, for comparison.
+    assert_eq!(f & null, null);
+    assert_eq!(t | null, null);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn kleene_truth_tables() {
+    test_helpers::test_2(&|bits: [u8; 2], valid_bits: [u8; 2]| {
+        let a = kleene_from_bits(bits[0], valid_bits[0]);
+        let b = kleene_from_bits(bits[1], valid_bits[1]);
+        let (and, or) = (a.kleene_and(b).to_options(), a.kleene_or(b).to_options());
+        let (a, b) = (a.to_options(), b.to_options());
+        for i in 0..4 {
+            let (x, y) = (a[i].map(|x| x != 0), b[i].map(|y| y != 0));
+            let expected_and = match (x, y) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            };
+            let expected_or = match (x, y) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            };
+            proptest::prop_assert_eq!(and[i].map(|x| x != 0), expected_and);
+            proptest::prop_assert_eq!(or[i].map(|x| x != 0), expected_or);
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn kleene_integers() {
+    let a = MaskedSimd::from_options([Some(0b1100u8), Some(0), Some(0xff), None]);
+    let b = MaskedSimd::from_options([Some(0b1010), None, None, None]);
+    assert_eq!(
+        a.kleene_and(b).to_options(),
+        [Some(0b1000), Some(0), None, None]
+    );
+    assert_eq!(
+        a.kleene_or(b).to_options(),
+        [Some(0b1110), None, Some(0xff), None]
+    );
+}
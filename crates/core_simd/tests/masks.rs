@@ -24,6 +24,13 @@ macro_rules! test_mask_api {
                 }
             }
 
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn lanes() {
+                assert_eq!(core_simd::$name::<8>::LANES, 8);
+                assert_eq!(core_simd::$name::<16>::splat(false).lanes(), 16);
+            }
+
             #[test]
             #[should_panic]
             fn set_invalid_lane() {
@@ -75,6 +82,19 @@ macro_rules! test_mask_api {
                 let mask = core_simd::$name::<16>::from_array(values);
                 assert_eq!(mask.to_bitmask(), [0b01001001, 0b10000011]);
             }
+
+            #[test]
+            fn to_bitmask_128_lanes() {
+                let mut mask = core_simd::$name::<128>::splat(false);
+                mask.set(0, true);
+                mask.set(65, true);
+                mask.set(127, true);
+                let mut expected = [0u8; 16];
+                expected[0] = 0b00000001;
+                expected[8] = 0b00000010;
+                expected[15] = 0b10000000;
+                assert_eq!(mask.to_bitmask(), expected);
+            }
         }
     }
 }
@@ -0,0 +1,84 @@
+use core_simd::{f32x4, Mat4};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn scalar_rows(m: Mat4) -> [[f32; 4]; 4] {
+    let rows = m.to_rows();
+    [
+        rows[0].to_array(),
+        rows[1].to_array(),
+        rows[2].to_array(),
+        rows[3].to_array(),
+    ]
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rows_and_cols() {
+    let rows = [
+        f32x4::from_array([0., 1., 2., 3.]),
+        f32x4::from_array([4., 5., 6., 7.]),
+        f32x4::from_array([8., 9., 10., 11.]),
+        f32x4::from_array([12., 13., 14., 15.]),
+    ];
+    let m = Mat4::from_rows(rows);
+    assert_eq!(m.to_rows(), rows);
+    assert_eq!(m.to_cols()[1].to_array(), [1., 5., 9., 13.]);
+    assert_eq!(m.transpose(), Mat4::from_cols(rows));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn transform() {
+    test_helpers::test_2(&|m: [f32; 16], v: [f32; 4]| {
+        // Small integers keep every product and sum exact.
+        let m = m.map(|x| (x % 8.).trunc());
+        let v = v.map(|x| (x % 8.).trunc());
+        let mut rows = [f32x4::splat(0.); 4];
+        for i in 0..4 {
+            rows[i] = f32x4::from_array([m[4 * i], m[4 * i + 1], m[4 * i + 2], m[4 * i + 3]]);
+        }
+        let result = (Mat4::from_rows(rows) * f32x4::from_array(v)).to_array();
+        for i in 0..4 {
+            let expected = (0..4).map(|j| m[4 * i + j] * v[j]).sum::<f32>();
+            if expected.is_finite() {
+                proptest::prop_assert_eq!(result[i], expected);
+            }
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn multiply() {
+    let a = Mat4::from_rows([
+        f32x4::from_array([1., 2., 3., 4.]),
+        f32x4::from_array([5., 6., 7., 8.]),
+        f32x4::from_array([9., 10., 11., 12.]),
+        f32x4::from_array([13., 14., 15., 16.]),
+    ]);
+    let b = Mat4::from_rows([
+        f32x4::from_array([2., 0., 0., 1.]),
+        f32x4::from_array([0., 1., 0., 0.]),
+        f32x4::from_array([1., 0., 3., 0.]),
+        f32x4::from_array([0., 0., 0., 1.]),
+    ]);
+    let (a_rows, b_rows) = (scalar_rows(a), scalar_rows(b));
+    let mut expected = [[0.; 4]; 4];
+    for i in 0..4 {
+        for j in 0..4 {
+            expected[i][j] = (0..4).map(|k| a_rows[i][k] * b_rows[k][j]).sum();
+        }
+    }
+    assert_eq!(scalar_rows(a * b), expected);
+    assert_eq!(a * Mat4::IDENTITY, a);
+    assert_eq!(Mat4::IDENTITY * b, b);
+
+    let v = f32x4::from_array([1., -2., 3., 1.]);
+    assert_eq!((a * b) * v, a * (b * v));
+}
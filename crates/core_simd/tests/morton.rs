@@ -0,0 +1,59 @@
+use core_simd::Simd;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! test_morton {
+    { $($name:ident: $code:ty => $coord:ty, $bits_3d:literal, $lanes:literal;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            test_helpers::test_3(&|x: [$coord; $lanes], y: [$coord; $lanes], z: [$code; $lanes]| {
+                let (xv, yv) = (Simd::from_array(x), Simd::from_array(y));
+                let code_2d = Simd::<$code, $lanes>::morton_encode_2d(xv, yv);
+                for i in 0..$lanes {
+                    let mut expected: $code = 0;
+                    for bit in 0..<$coord>::BITS {
+                        expected |= ((x[i] >> bit & 1) as $code) << (2 * bit);
+                        expected |= ((y[i] >> bit & 1) as $code) << (2 * bit + 1);
+                    }
+                    proptest::prop_assert_eq!(code_2d[i], expected);
+                }
+                proptest::prop_assert_eq!(code_2d.morton_decode_2d(), (xv, yv));
+
+                // Reuse the 2D codes as full-width coordinates, to check that the high bits
+                // are ignored.
+                let (x3, y3, z3) = (code_2d, Simd::<$code, $lanes>::from_array(z), !code_2d);
+                let code_3d = Simd::<$code, $lanes>::morton_encode_3d(x3, y3, z3);
+                let (x3, y3, z3) = (x3.to_array(), y3.to_array(), z3.to_array());
+                for i in 0..$lanes {
+                    let mut expected: $code = 0;
+                    for bit in 0..$bits_3d {
+                        expected |= (x3[i] >> bit & 1) << (3 * bit);
+                        expected |= (y3[i] >> bit & 1) << (3 * bit + 1);
+                        expected |= (z3[i] >> bit & 1) << (3 * bit + 2);
+                    }
+                    proptest::prop_assert_eq!(code_3d[i], expected);
+                }
+                let mask = Simd::splat((1 << $bits_3d) - 1);
+                let (dx, dy, dz) = code_3d.morton_decode_3d();
+                proptest::prop_assert_eq!(dx.to_array(), (Simd::from_array(x3) & mask).to_array());
+                proptest::prop_assert_eq!(dy.to_array(), (Simd::from_array(y3) & mask).to_array());
+                proptest::prop_assert_eq!(dz.to_array(), (Simd::from_array(z3) & mask).to_array());
+                Ok(())
+            });
+        }
+        )*
+    }
+}
+
+test_morton! {
+    morton_u16_1: u16 => u8, 5, 1;
+    morton_u16_16: u16 => u8, 5, 16;
+    morton_u32_4: u32 => u16, 10, 4;
+    morton_u32_64: u32 => u16, 10, 64;
+}
@@ -0,0 +1,27 @@
+use core_simd::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! test_native_width {
+    { $($alias:ident: $scalar:ty),* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        #[allow(non_snake_case)]
+        fn $alias() {
+            assert_eq!($alias::LANES * core::mem::size_of::<$scalar>(), NATIVE_VECTOR_BYTES);
+            assert_eq!(core::mem::size_of::<$alias>(), NATIVE_VECTOR_BYTES);
+        }
+        )*
+    }
+}
+
+test_native_width! {
+    u8xN: u8, u16xN: u16, u32xN: u32, u64xN: u64, u128xN: u128, usizexN: usize,
+    i8xN: i8, i16xN: i16, i32xN: i32, i64xN: i64, i128xN: i128, isizexN: isize,
+    f32xN: f32, f64xN: f64
+}
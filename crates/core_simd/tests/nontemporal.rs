@@ -0,0 +1,48 @@
+use core_simd::Simd;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! test_store_nontemporal {
+    { $($name:ident: $type:ty, $lanes:literal;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            test_helpers::test_1(&|x: [$type; $lanes]| {
+                // Try both aligned and misaligned destinations.
+                let mut buffer = [Simd::<$type, $lanes>::default(); 3];
+                let lanes = unsafe {
+                    core::slice::from_raw_parts_mut(buffer.as_mut_ptr().cast::<$type>(), 3 * $lanes)
+                };
+                for offset in [0, 1, $lanes] {
+                    lanes.fill(0 as $type);
+                    Simd::from_array(x).store_nontemporal(&mut lanes[offset..]);
+                    proptest::prop_assert_eq!(&lanes[offset..offset + $lanes], &x[..]);
+                    proptest::prop_assert!(lanes[..offset].iter().all(|&lane| lane == 0 as $type));
+                    proptest::prop_assert!(lanes[offset + $lanes..].iter().all(|&lane| lane == 0 as $type));
+                }
+                Ok(())
+            });
+        }
+        )*
+    }
+}
+
+test_store_nontemporal! {
+    store_nontemporal_u8_1: u8, 1;
+    store_nontemporal_u8_16: u8, 16;
+    store_nontemporal_i32_2: i32, 2;
+    store_nontemporal_i32_8: i32, 8;
+    store_nontemporal_u64_32: u64, 32;
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic]
+fn store_nontemporal_short_slice() {
+    Simd::<i32, 4>::splat(1).store_nontemporal(&mut [0; 3]);
+}
@@ -0,0 +1,74 @@
+use core_simd::{Simd, SimdFloat, SimdInt, SimdInteger, SimdNum, SimdUint};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Clamps each lane to `lo..=hi`, using only the trait.
+fn clamp<V: SimdNum>(x: V, lo: V::Scalar, hi: V::Scalar) -> V {
+    let (lo, hi) = (V::splat(lo), V::splat(hi));
+    V::select(x.lanes_lt(lo), lo, V::select(x.lanes_gt(hi), hi, x))
+}
+
+/// Replaces non-finite lanes with zero, for a vector named by its element type and lane count.
+fn finite_or_zero<T, const LANES: usize>(x: Simd<T, LANES>) -> Simd<T, LANES>
+where
+    T: core_simd::SimdElement + Default,
+    Simd<T, LANES>: SimdFloat<Scalar = T>,
+    core_simd::LaneCount<LANES>: core_simd::SupportedLaneCount,
+{
+    SimdNum::select(x.is_finite(), x, SimdNum::splat(T::default()))
+}
+
+fn sum_abs<V: SimdInt>(x: V) -> V::Scalar {
+    SimdInt::abs(x).horizontal_sum()
+}
+
+fn distance<V: SimdUint>(a: V, b: V) -> V::Scalar {
+    SimdUint::abs_diff(a, b).horizontal_max()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn num() {
+    assert_eq!(<Simd<u8, 4> as SimdNum>::LANES, 4);
+    let x = Simd::from_array([-3, 0, 7, 12]);
+    assert_eq!(clamp(x, 0, 10).to_array(), [0, 0, 7, 10]);
+    let x = Simd::from_array([-3.5, 0.5, 7.5, 12.5]);
+    assert_eq!(clamp(x, 0., 10.).to_array(), [0., 0.5, 7.5, 10.]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn float() {
+    let x = Simd::from_array([1., f32::NAN, -f32::INFINITY, -2.]);
+    assert_eq!(finite_or_zero(x).to_array(), [1., 0., 0., -2.]);
+    let x = Simd::from_array([f64::INFINITY, -0.5]);
+    assert_eq!(finite_or_zero(x).to_array(), [0., -0.5]);
+    assert_eq!(SimdFloat::to_bits(x), x.to_bits());
+    assert_eq!(<Simd<f64, 2> as SimdFloat>::from_bits(x.to_bits()), x);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn int() {
+    test_helpers::test_1(&|x: [i16; 8]| {
+        let x = Simd::from_array(x);
+        proptest::prop_assert_eq!(sum_abs(x), x.abs().horizontal_sum());
+        proptest::prop_assert_eq!(SimdInt::abs_diff(x, x.reverse()), x.abs_diff(x.reverse()));
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn uint() {
+    test_helpers::test_2(&|a: [u32; 4], b: [u32; 4]| {
+        let (a, b) = (Simd::from_array(a), Simd::from_array(b));
+        proptest::prop_assert_eq!(distance(a, b), a.abs_diff(b).horizontal_max());
+        proptest::prop_assert_eq!(SimdInteger::wrapping_add(a, b), a.wrapping_add(b));
+        Ok(())
+    });
+}
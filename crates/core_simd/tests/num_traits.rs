@@ -0,0 +1,74 @@
+#![cfg(feature = "num-traits")]
+
+use core_simd::{SimdF32, SimdI16, SimdU8};
+use num_traits::float::FloatConst;
+use num_traits::ops::inv::Inv;
+use num_traits::ops::saturating::{SaturatingAdd, SaturatingSub};
+use num_traits::ops::wrapping::{WrappingAdd, WrappingNeg};
+use num_traits::{Bounded, One, Zero};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Sums of squares written against `num-traits`, to check that vectors fit generic code.
+fn sum_of_squares<T: Zero + Copy + core::ops::Mul<Output = T>>(values: &[T]) -> T {
+    values.iter().fold(T::zero(), |acc, &x| acc + x * x)
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn identities() {
+    assert_eq!(SimdF32::<4>::zero(), SimdF32::splat(0.));
+    assert_eq!(SimdI16::<8>::one(), SimdI16::splat(1));
+    assert!(SimdU8::<16>::zero().is_zero());
+    assert!(!SimdU8::<4>::from_array([0, 0, 1, 0]).is_zero());
+    assert_eq!(SimdI16::<4>::max_value(), SimdI16::splat(i16::MAX));
+    assert_eq!(SimdF32::<2>::min_value(), SimdF32::splat(f32::MIN));
+
+    let values = [
+        SimdF32::from_array([1., 2.]),
+        SimdF32::from_array([3., -4.]),
+    ];
+    assert_eq!(sum_of_squares(&values), SimdF32::from_array([10., 20.]));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn integer_ops() {
+    let x = SimdU8::from_array([250, 3, 0, 128]);
+    let y = SimdU8::from_array([10, 4, 1, 128]);
+    assert_eq!(
+        WrappingAdd::wrapping_add(&x, &y),
+        SimdU8::from_array([4, 7, 1, 0])
+    );
+    assert_eq!(
+        SaturatingAdd::saturating_add(&x, &y),
+        SimdU8::from_array([255, 7, 1, 255])
+    );
+    assert_eq!(
+        SaturatingSub::saturating_sub(&x, &y),
+        SimdU8::from_array([240, 0, 0, 0])
+    );
+    assert_eq!(
+        WrappingNeg::wrapping_neg(&x),
+        SimdU8::from_array([6, 253, 0, 128])
+    );
+    assert_eq!(
+        WrappingNeg::wrapping_neg(&SimdI16::from_array([i16::MIN, 5])),
+        SimdI16::from_array([i16::MIN, -5])
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn float_ops() {
+    assert_eq!(
+        SimdF32::from_array([2., -0.5]).inv(),
+        SimdF32::from_array([0.5, -2.])
+    );
+    assert_eq!(SimdF32::<4>::PI(), SimdF32::splat(core::f32::consts::PI));
+    assert_eq!(SimdF32::<4>::LOG2_10(), SimdF32::splat(f32::LOG2_10()));
+}
@@ -136,10 +136,118 @@ macro_rules! impl_binary_checked_op_test {
     };
 }
 
+/// Implements a test on an integer arithmetic operation using proptest.
+///
+/// Like `impl_binary_op_test`, but the operator panics on overflow when debug assertions are
+/// enabled, so the left- and right-hand sides are first mapped into ranges that can't overflow
+/// with `$lhs_fn` and `$rhs_fn`.
+///
+/// Compares the vector operation to the equivalent scalar operation.
+#[macro_export]
+macro_rules! impl_binary_overflow_op_test {
+    { $vector:ty, $scalar:ty, $trait:ident :: $fn:ident, $trait_assign:ident :: $fn_assign:ident, $scalar_fn:expr, $lhs_fn:expr, $rhs_fn:expr } => {
+        mod $fn {
+            use super::*;
+
+            test_helpers::test_lanes! {
+                fn normal<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &|a: $vector, b: $vector| {
+                            let (a, b) = (a.to_array().map($lhs_fn), b.to_array().map($rhs_fn));
+                            <$vector as core::ops::$trait>::$fn(a.into(), b.into())
+                        },
+                        &|x, y| $scalar_fn($lhs_fn(x), $rhs_fn(y)),
+                        &|_, _| true,
+                    );
+                }
+
+                fn scalar_rhs<const LANES: usize>() {
+                    test_helpers::test_binary_scalar_rhs_elementwise(
+                        &|a: $vector, y: $scalar| {
+                            <$vector as core::ops::$trait<$scalar>>::$fn(a.to_array().map($lhs_fn).into(), $rhs_fn(y))
+                        },
+                        &|x, y| $scalar_fn($lhs_fn(x), $rhs_fn(y)),
+                        &|_, _| true,
+                    );
+                }
+
+                fn scalar_lhs<const LANES: usize>() {
+                    test_helpers::test_binary_scalar_lhs_elementwise(
+                        &|x: $scalar, b: $vector| {
+                            <$scalar as core::ops::$trait<$vector>>::$fn($lhs_fn(x), b.to_array().map($rhs_fn).into())
+                        },
+                        &|x, y| $scalar_fn($lhs_fn(x), $rhs_fn(y)),
+                        &|_, _| true,
+                    );
+                }
+
+                fn assign<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &|a: $vector, b: $vector| {
+                            let mut a: $vector = a.to_array().map($lhs_fn).into();
+                            <$vector as core::ops::$trait_assign>::$fn_assign(&mut a, b.to_array().map($rhs_fn).into());
+                            a
+                        },
+                        &|x, y| $scalar_fn($lhs_fn(x), $rhs_fn(y)),
+                        &|_, _| true,
+                    );
+                }
+
+                fn assign_scalar_rhs<const LANES: usize>() {
+                    test_helpers::test_binary_scalar_rhs_elementwise(
+                        &|a: $vector, y: $scalar| {
+                            let mut a: $vector = a.to_array().map($lhs_fn).into();
+                            <$vector as core::ops::$trait_assign<$scalar>>::$fn_assign(&mut a, $rhs_fn(y));
+                            a
+                        },
+                        &|x, y| $scalar_fn($lhs_fn(x), $rhs_fn(y)),
+                        &|_, _| true,
+                    );
+                }
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! impl_common_integer_tests {
     { $vector:ident, $scalar:ident } => {
         test_helpers::test_lanes! {
+            fn const_ops<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let (a, b) = ($vector::<LANES>::from_array(x), $vector::<LANES>::from_array(y));
+                    test_helpers::prop_assert_biteq!(a.const_add(b).to_array(), a.wrapping_add(b).to_array());
+                    test_helpers::prop_assert_biteq!(a.const_sub(b).to_array(), a.wrapping_sub(b).to_array());
+                    test_helpers::prop_assert_biteq!(a.const_mul(b).to_array(), a.wrapping_mul(b).to_array());
+                    test_helpers::prop_assert_biteq!(a.const_and(b).to_array(), (a & b).to_array());
+                    test_helpers::prop_assert_biteq!(a.const_or(b).to_array(), (a | b).to_array());
+                    test_helpers::prop_assert_biteq!(a.const_xor(b).to_array(), (a ^ b).to_array());
+                    Ok(())
+                });
+            }
+
+            fn constant_time<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    // Make roughly half of the lanes equal
+                    let mut z = y;
+                    for i in 0..LANES {
+                        if y[i] & 1 == 0 {
+                            z[i] = x[i];
+                        }
+                    }
+                    let (a, b) = ($vector::<LANES>::from_array(x), $vector::<LANES>::from_array(z));
+                    let eq = a.ct_eq(b);
+                    proptest::prop_assert_eq!(eq.to_array(), a.lanes_eq(b).to_array());
+                    let (ne, expected_ne) = (a.ct_ne_bitmask(b), a.lanes_ne(b).to_bitmask());
+                    proptest::prop_assert_eq!(ne.as_ref(), expected_ne.as_ref());
+                    test_helpers::prop_assert_biteq!(
+                        $vector::<LANES>::ct_select(eq, a, b).to_array(),
+                        eq.select(a, b).to_array(),
+                    );
+                    Ok(())
+                });
+            }
+
             fn horizontal_sum<const LANES: usize>() {
                 test_helpers::test_1(&|x| {
                     test_helpers::prop_assert_biteq! (
@@ -160,6 +268,181 @@ macro_rules! impl_common_integer_tests {
                 });
             }
 
+            fn dot<const LANES: usize>() {
+                test_helpers::test_2(&|x, y| {
+                    test_helpers::prop_assert_biteq! (
+                        $vector::<LANES>::from_array(x).dot($vector::<LANES>::from_array(y)),
+                        x.iter().zip(y.iter()).fold(0 as $scalar, |acc, (x, y)| acc.wrapping_add(x.wrapping_mul(*y))),
+                    );
+                    Ok(())
+                });
+            }
+
+            fn widening_mul<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let (lo, hi) = $vector::<LANES>::from_array(x).widening_mul($vector::<LANES>::from_array(y));
+                    for i in 0..LANES {
+                        let (expected_lo, expected_hi) = test_helpers::wide::WideningMul::widening_mul(x[i], y[i]);
+                        test_helpers::prop_assert_biteq!(lo[i], expected_lo);
+                        test_helpers::prop_assert_biteq!(hi[i], expected_hi);
+                    }
+                    Ok(())
+                });
+            }
+
+            fn abs_diff<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let diff = $vector::<LANES>::from_array(x).abs_diff($vector::<LANES>::from_array(y));
+                    for i in 0..LANES {
+                        test_helpers::prop_assert_biteq!(diff[i] as $scalar, x[i].max(y[i]).wrapping_sub(x[i].min(y[i])));
+                    }
+                    Ok(())
+                });
+            }
+
+            fn wrapping_add<const LANES: usize>() {
+                test_helpers::test_binary_elementwise(
+                    &$vector::<LANES>::wrapping_add,
+                    &$scalar::wrapping_add,
+                    &|_, _| true,
+                );
+            }
+
+            fn wrapping_sub<const LANES: usize>() {
+                test_helpers::test_binary_elementwise(
+                    &$vector::<LANES>::wrapping_sub,
+                    &$scalar::wrapping_sub,
+                    &|_, _| true,
+                );
+            }
+
+            fn wrapping_mul<const LANES: usize>() {
+                test_helpers::test_binary_elementwise(
+                    &$vector::<LANES>::wrapping_mul,
+                    &$scalar::wrapping_mul,
+                    &|_, _| true,
+                );
+            }
+
+            fn strict_add<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let (x, y) = (x.map(|x| x / 2), y.map(|y| y / 2));
+                    let result = $vector::<LANES>::from_array(x).strict_add($vector::<LANES>::from_array(y));
+                    for i in 0..LANES {
+                        proptest::prop_assert_eq!(result[i], x[i] + y[i]);
+                    }
+                    Ok(())
+                });
+            }
+
+            fn strict_sub<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let (mut x, mut y) = (x, y);
+                    for i in 0..LANES {
+                        (x[i], y[i]) = (x[i].max(y[i]) / 2, x[i].min(y[i]) / 2);
+                    }
+                    let result = $vector::<LANES>::from_array(x).strict_sub($vector::<LANES>::from_array(y));
+                    for i in 0..LANES {
+                        proptest::prop_assert_eq!(result[i], x[i] - y[i]);
+                    }
+                    Ok(())
+                });
+            }
+
+            fn strict_mul<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let (x, y) = (x.map(|x| x >> ($scalar::BITS / 2)), y.map(|y| y >> ($scalar::BITS / 2)));
+                    let result = $vector::<LANES>::from_array(x).strict_mul($vector::<LANES>::from_array(y));
+                    for i in 0..LANES {
+                        proptest::prop_assert_eq!(result[i], x[i] * y[i]);
+                    }
+                    Ok(())
+                });
+            }
+
+            fn strict_shl<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let y = y.map(|y| y & ($scalar::BITS - 1) as $scalar);
+                    let result = $vector::<LANES>::from_array(x).strict_shl($vector::<LANES>::from_array(y));
+                    for i in 0..LANES {
+                        proptest::prop_assert_eq!(result[i], x[i] << y[i]);
+                    }
+                    Ok(())
+                });
+            }
+
+            fn strict_shr<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let y = y.map(|y| y & ($scalar::BITS - 1) as $scalar);
+                    let result = $vector::<LANES>::from_array(x).strict_shr($vector::<LANES>::from_array(y));
+                    for i in 0..LANES {
+                        proptest::prop_assert_eq!(result[i], x[i] >> y[i]);
+                    }
+                    Ok(())
+                });
+            }
+
+            fn unchecked_arith<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let (half_x, half_y) = ($vector::<LANES>::from_array(x.map(|x| x >> ($scalar::BITS / 2))), $vector::<LANES>::from_array(y.map(|y| y >> ($scalar::BITS / 2))));
+                    unsafe {
+                        proptest::prop_assert_eq!(half_x.unchecked_add(half_y), half_x.strict_add(half_y));
+                        proptest::prop_assert_eq!(half_x.unchecked_mul(half_y), half_x.strict_mul(half_y));
+                        let gt = half_x.lanes_gt(half_y);
+                        let (hi, lo) = (gt.select(half_x, half_y), gt.select(half_y, half_x));
+                        proptest::prop_assert_eq!(hi.unchecked_sub(lo), hi.strict_sub(lo));
+                    }
+                    Ok(())
+                });
+            }
+
+            fn unchecked_shift<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let x = $vector::<LANES>::from_array(x);
+                    let y = $vector::<LANES>::from_array(y.map(|y| y & ($scalar::BITS - 1) as $scalar));
+                    unsafe {
+                        proptest::prop_assert_eq!(x.unchecked_shl(y), x << y);
+                        proptest::prop_assert_eq!(x.unchecked_shr(y), x >> y);
+                    }
+                    Ok(())
+                });
+            }
+
+            fn any<const LANES: usize>() {
+                test_helpers::test_1(&|x: [$scalar; LANES]| {
+                    proptest::prop_assert_eq!($vector::<LANES>::from_array(x).any(), x.iter().any(|&lane| lane != 0));
+                    Ok(())
+                });
+                assert!(!$vector::<LANES>::splat(0).any());
+            }
+
+            fn all<const LANES: usize>() {
+                test_helpers::test_1(&|x: [$scalar; LANES]| {
+                    proptest::prop_assert_eq!($vector::<LANES>::from_array(x).all(), x.iter().all(|&lane| lane != 0));
+                    Ok(())
+                });
+                assert!($vector::<LANES>::splat(1).all());
+                let mut x = [1; LANES];
+                x[LANES - 1] = 0;
+                assert!(!$vector::<LANES>::from_array(x).all());
+            }
+
+            fn bitselect<const LANES: usize>() {
+                test_helpers::test_ternary_elementwise(
+                    &$vector::<LANES>::bitselect,
+                    &|x: $scalar, y: $scalar, bits: $scalar| (x & bits) | (y & !bits),
+                    &|_, _, _| true,
+                );
+            }
+
+            fn mul_high<const LANES: usize>() {
+                test_helpers::test_binary_elementwise(
+                    &$vector::<LANES>::mul_high,
+                    &|x, y| test_helpers::wide::WideningMul::widening_mul(x, y).1,
+                    &|_, _| true,
+                );
+            }
+
             fn horizontal_and<const LANES: usize>() {
                 test_helpers::test_1(&|x| {
                     test_helpers::prop_assert_biteq! (
@@ -209,6 +492,256 @@ macro_rules! impl_common_integer_tests {
                     Ok(())
                 });
             }
+
+            fn horizontal_minmax<const LANES: usize>() {
+                test_helpers::test_1(&|x: [$scalar; LANES]| {
+                    proptest::prop_assert_eq!(
+                        $vector::<LANES>::from_array(x).horizontal_minmax(),
+                        (x.iter().copied().min().unwrap(), x.iter().copied().max().unwrap())
+                    );
+                    Ok(())
+                });
+            }
+
+            fn horizontal_argmax<const LANES: usize>() {
+                test_helpers::test_1(&|x: [$scalar; LANES]| {
+                    let max = x.iter().copied().max().unwrap();
+                    proptest::prop_assert_eq!(
+                        $vector::<LANES>::from_array(x).horizontal_argmax(),
+                        x.iter().position(|&v| v == max).unwrap()
+                    );
+                    Ok(())
+                });
+            }
+
+            fn horizontal_argmin<const LANES: usize>() {
+                test_helpers::test_1(&|x: [$scalar; LANES]| {
+                    let min = x.iter().copied().min().unwrap();
+                    proptest::prop_assert_eq!(
+                        $vector::<LANES>::from_array(x).horizontal_argmin(),
+                        x.iter().position(|&v| v == min).unwrap()
+                    );
+                    Ok(())
+                });
+            }
+
+            fn position_eq<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], value: $scalar| {
+                    let v = $vector::<LANES>::from_array(x);
+                    proptest::prop_assert_eq!(v.position_eq(value), x.iter().position(|&lane| lane == value));
+                    proptest::prop_assert_eq!(
+                        v.position_eq(x[LANES - 1]),
+                        x.iter().position(|&lane| lane == x[LANES - 1])
+                    );
+                    Ok(())
+                });
+            }
+
+            fn hash<const LANES: usize>() {
+                use core::hash::{Hash, Hasher};
+
+                /// Records each call to `write`.
+                #[derive(Default)]
+                struct WriteLog(Vec<Vec<u8>>);
+
+                impl Hasher for WriteLog {
+                    fn finish(&self) -> u64 {
+                        unimplemented!()
+                    }
+
+                    fn write(&mut self, bytes: &[u8]) {
+                        self.0.push(bytes.to_vec());
+                    }
+                }
+
+                test_helpers::test_1(&|x: [$scalar; LANES]| {
+                    let mut log = WriteLog::default();
+                    $vector::<LANES>::from_array(x).hash(&mut log);
+                    let bytes: Vec<u8> = x.iter().flat_map(|lane| lane.to_ne_bytes().to_vec()).collect();
+                    proptest::prop_assert_eq!(log.0, vec![bytes]);
+                    Ok(())
+                });
+            }
+
+            fn lanes_and_align<const LANES: usize>() {
+                let v = $vector::<LANES>::default();
+                assert_eq!($vector::<LANES>::LANES, LANES);
+                assert_eq!(v.lanes(), LANES);
+                let align = $vector::<LANES>::ALIGN;
+                assert!(align.is_power_of_two());
+                assert!(align >= core::mem::align_of::<$scalar>());
+                assert_eq!(core::mem::align_of_val(&v), align);
+                assert_eq!(core::mem::size_of_val(&v), LANES * core::mem::size_of::<$scalar>());
+            }
+
+            fn aligned_load_store<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    // A buffer of vectors is aligned for each of its vectors.
+                    let mut buffer = [$vector::<LANES>::from_array(x), $vector::<LANES>::default()];
+                    let lanes = unsafe {
+                        core::slice::from_raw_parts_mut(buffer.as_mut_ptr().cast::<$scalar>(), 2 * LANES)
+                    };
+                    let loaded = unsafe { $vector::<LANES>::from_slice_aligned_unchecked(lanes) };
+                    proptest::prop_assert_eq!(loaded.to_array(), x);
+                    unsafe { $vector::<LANES>::from_array(y).copy_to_slice_aligned_unchecked(&mut lanes[LANES..]) };
+                    proptest::prop_assert_eq!(buffer[1].to_array(), y);
+                    Ok(())
+                });
+            }
+
+            fn unaligned_read_write<const LANES: usize>() {
+                test_helpers::test_1(&|x: [$scalar; LANES]| {
+                    // Offset by one element, so the pointer is misaligned for the vector
+                    // whenever the vector has more than one lane.
+                    let mut buffer = [$vector::<LANES>::default(); 2];
+                    let lanes = unsafe {
+                        core::slice::from_raw_parts_mut(buffer.as_mut_ptr().cast::<$scalar>(), 2 * LANES)
+                    };
+                    unsafe { $vector::<LANES>::from_array(x).write_unaligned(lanes[1..].as_mut_ptr()) };
+                    proptest::prop_assert_eq!(&lanes[1..=LANES], &x[..]);
+                    let read = unsafe { $vector::<LANES>::read_unaligned(lanes[1..].as_ptr()) };
+                    proptest::prop_assert_eq!(read.to_array(), x);
+                    Ok(())
+                });
+            }
+
+            fn as_array<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let mut v = $vector::<LANES>::from_array(x);
+                    proptest::prop_assert_eq!(v.as_array(), &x);
+                    *v.as_mut_array() = y;
+                    proptest::prop_assert_eq!(v.to_array(), y);
+                    Ok(())
+                });
+            }
+
+            fn with_lane<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let v = $vector::<LANES>::from_array(x);
+                    let mut expected = x;
+                    expected[LANES - 1] = y[0];
+                    proptest::prop_assert_eq!(v.with_lane(LANES - 1, y[0]).to_array(), expected);
+                    expected[0] = y[1 % LANES];
+                    proptest::prop_assert_eq!(
+                        v.with_lane(LANES - 1, y[0]).with_lane_const::<0>(y[1 % LANES]).to_array(),
+                        expected
+                    );
+                    Ok(())
+                });
+            }
+
+            fn try_from_slice<const LANES: usize>() {
+                test_helpers::test_1(&|x: [$scalar; LANES]| {
+                    use core::convert::TryFrom;
+                    proptest::prop_assert_eq!($vector::<LANES>::try_from(&x[..]).ok(), Some($vector::from_array(x)));
+                    proptest::prop_assert!($vector::<LANES>::try_from(&x[1..]).is_err());
+                    let longer = [x, x];
+                    proptest::prop_assert!($vector::<LANES>::try_from(longer.concat().as_slice()).is_err());
+                    Ok(())
+                });
+            }
+
+            fn flatten_slice<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let mut vectors = [$vector::<LANES>::from_array(x), $vector::<LANES>::from_array(y)];
+                    let lanes = $vector::<LANES>::flatten_slice(&vectors);
+                    proptest::prop_assert_eq!(&lanes[..LANES], &x[..]);
+                    proptest::prop_assert_eq!(&lanes[LANES..], &y[..]);
+                    proptest::prop_assert_eq!($vector::<LANES>::unflatten_slice(lanes), Some(&vectors[..]));
+                    proptest::prop_assert_eq!($vector::<LANES>::unflatten_slice(&lanes[..LANES]), Some(&vectors[..1]));
+                    if LANES > 1 {
+                        // Wrong length, and misaligned.
+                        proptest::prop_assert_eq!($vector::<LANES>::unflatten_slice(&lanes[..LANES + 1]), None);
+                        proptest::prop_assert_eq!($vector::<LANES>::unflatten_slice(&lanes[1..=LANES]), None);
+                    }
+                    let lanes = $vector::<LANES>::flatten_slice_mut(&mut vectors);
+                    lanes.swap(0, LANES);
+                    let vectors = $vector::<LANES>::unflatten_slice_mut(lanes).unwrap();
+                    proptest::prop_assert_eq!(vectors[0][0], y[0]);
+                    proptest::prop_assert_eq!(vectors[1][0], x[0]);
+                    Ok(())
+                });
+            }
+
+            fn volatile_read_write<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let mut v = $vector::<LANES>::from_array(x);
+                    let read = unsafe { $vector::<LANES>::read_volatile(v.as_slice().as_ptr()) };
+                    proptest::prop_assert_eq!(read.to_array(), x);
+                    unsafe { $vector::<LANES>::from_array(y).write_volatile(v.as_mut_slice().as_mut_ptr()) };
+                    proptest::prop_assert_eq!(v.to_array(), y);
+                    Ok(())
+                });
+            }
+        }
+
+        #[cfg(debug_assertions)]
+        mod overflow {
+            use super::*;
+
+            test_helpers::test_lanes_panic! {
+                fn add_overflow_panics<const LANES: usize>() {
+                    let mut a = $vector::<LANES>::splat(1);
+                    a[LANES - 1] = $scalar::MAX;
+                    let _ = a + $vector::<LANES>::splat(1);
+                }
+
+                fn sub_overflow_panics<const LANES: usize>() {
+                    let mut a = $vector::<LANES>::splat(1);
+                    a[LANES - 1] = $scalar::MIN;
+                    let _ = a - 1;
+                }
+
+                fn mul_overflow_panics<const LANES: usize>() {
+                    let mut a = $vector::<LANES>::splat(1);
+                    a[LANES - 1] = $scalar::MAX;
+                    let _ = 2 * a;
+                }
+
+                fn add_assign_overflow_panics<const LANES: usize>() {
+                    let mut a = $vector::<LANES>::splat($scalar::MAX);
+                    a += 1;
+                }
+
+                fn unchecked_add_overflow_panics<const LANES: usize>() {
+                    let mut a = $vector::<LANES>::splat(1);
+                    a[LANES - 1] = $scalar::MAX;
+                    let _ = unsafe { a.unchecked_add($vector::<LANES>::splat(1)) };
+                }
+            }
+        }
+
+        // The strict operations panic in every build profile.
+        test_helpers::test_lanes_panic! {
+            fn strict_add_overflow_panics<const LANES: usize>() {
+                let mut a = $vector::<LANES>::splat(1);
+                a[LANES - 1] = $scalar::MAX;
+                let _ = a.strict_add($vector::<LANES>::splat(1));
+            }
+
+            fn strict_sub_overflow_panics<const LANES: usize>() {
+                let mut a = $vector::<LANES>::splat(1);
+                a[LANES - 1] = $scalar::MIN;
+                let _ = a.strict_sub($vector::<LANES>::splat(1));
+            }
+
+            fn strict_mul_overflow_panics<const LANES: usize>() {
+                let mut a = $vector::<LANES>::splat(1);
+                a[LANES - 1] = $scalar::MAX;
+                let _ = a.strict_mul($vector::<LANES>::splat(2));
+            }
+
+            fn strict_shl_overflow_panics<const LANES: usize>() {
+                let mut shift = $vector::<LANES>::splat(0);
+                shift[LANES - 1] = $scalar::BITS as $scalar;
+                let _ = $vector::<LANES>::splat(1).strict_shl(shift);
+            }
+
+            fn strict_shr_overflow_panics<const LANES: usize>() {
+                let mut shift = $vector::<LANES>::splat(0);
+                shift[LANES - 1] = $scalar::BITS as $scalar;
+                let _ = $vector::<LANES>::splat(1).strict_shr(shift);
+            }
         }
     }
 }
@@ -227,7 +760,31 @@ macro_rules! impl_signed_tests {
                 fn neg<const LANES: usize>() {
                     test_helpers::test_unary_elementwise(
                         &<Vector::<LANES> as core::ops::Neg>::neg,
-                        &<Scalar as core::ops::Neg>::neg,
+                        &Scalar::wrapping_neg,
+                        &|x| !cfg!(debug_assertions) || !x.contains(&Scalar::MIN),
+                    );
+                }
+
+                fn wrapping_neg<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::wrapping_neg,
+                        &Scalar::wrapping_neg,
+                        &|_| true,
+                    );
+                }
+
+                fn saturating_neg<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::saturating_neg,
+                        &Scalar::saturating_neg,
+                        &|_| true,
+                    );
+                }
+
+                fn strict_neg<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::strict_neg,
+                        &Scalar::wrapping_neg,
                         &|x| !x.contains(&Scalar::MIN),
                     );
                 }
@@ -247,9 +804,48 @@ macro_rules! impl_signed_tests {
                         &|_| true,
                     );
                 }
+
+                fn midpoint<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::midpoint,
+                        &Scalar::midpoint,
+                        &|_, _| true,
+                    );
+                }
+
+                fn div_euclid<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::div_euclid,
+                        &Scalar::div_euclid,
+                        &|x, y| x.iter().zip(y.iter()).all(|(x, y)| *y != 0 && !(*x == Scalar::MIN && *y == -1)),
+                    );
+                }
+
+                fn rem_euclid<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::rem_euclid,
+                        &Scalar::rem_euclid,
+                        &|x, y| x.iter().zip(y.iter()).all(|(x, y)| *y != 0 && !(*x == Scalar::MIN && *y == -1)),
+                    );
+                }
             }
 
+            #[cfg(debug_assertions)]
             test_helpers::test_lanes_panic! {
+                fn neg_min_overflow_panics<const LANES: usize>() {
+                    let mut a = Vector::<LANES>::splat(1);
+                    a[LANES - 1] = Scalar::MIN;
+                    let _ = -a;
+                }
+            }
+
+            test_helpers::test_lanes_panic! {
+                fn strict_neg_min_panics<const LANES: usize>() {
+                    let mut a = Vector::<LANES>::splat(1);
+                    a[LANES - 1] = Scalar::MIN;
+                    let _ = a.strict_neg();
+                }
+
                 fn div_min_overflow_panics<const LANES: usize>() {
                     let a = Vector::<LANES>::splat(Scalar::MIN);
                     let b = Vector::<LANES>::splat(-1);
@@ -296,9 +892,11 @@ macro_rules! impl_signed_tests {
                 }
             }
 
-            impl_binary_op_test!(Vector<LANES>, Scalar, Add::add, AddAssign::add_assign, Scalar::wrapping_add);
-            impl_binary_op_test!(Vector<LANES>, Scalar, Sub::sub, SubAssign::sub_assign, Scalar::wrapping_sub);
-            impl_binary_op_test!(Vector<LANES>, Scalar, Mul::mul, MulAssign::mul_assign, Scalar::wrapping_mul);
+            // Halve both sides of sums and differences, and keep half of the bits of products, so
+            // that they don't overflow.
+            impl_binary_overflow_op_test!(Vector<LANES>, Scalar, Add::add, AddAssign::add_assign, Scalar::wrapping_add, |x: Scalar| x / 2, |y: Scalar| y / 2);
+            impl_binary_overflow_op_test!(Vector<LANES>, Scalar, Sub::sub, SubAssign::sub_assign, Scalar::wrapping_sub, |x: Scalar| x / 2, |y: Scalar| y / 2);
+            impl_binary_overflow_op_test!(Vector<LANES>, Scalar, Mul::mul, MulAssign::mul_assign, Scalar::wrapping_mul, |x: Scalar| x >> (Scalar::BITS / 2), |y: Scalar| y >> (Scalar::BITS / 2));
 
             // Exclude Div and Rem panicking cases
             impl_binary_checked_op_test!(Vector<LANES>, Scalar, Div::div, DivAssign::div_assign, Scalar::wrapping_div, |x, y| y != 0 && !(x == Scalar::MIN && y == -1));
@@ -330,9 +928,81 @@ macro_rules! impl_unsigned_tests {
                 }
             }
 
-            impl_binary_op_test!(Vector<LANES>, Scalar, Add::add, AddAssign::add_assign, Scalar::wrapping_add);
-            impl_binary_op_test!(Vector<LANES>, Scalar, Sub::sub, SubAssign::sub_assign, Scalar::wrapping_sub);
-            impl_binary_op_test!(Vector<LANES>, Scalar, Mul::mul, MulAssign::mul_assign, Scalar::wrapping_mul);
+            test_helpers::test_lanes! {
+                fn midpoint<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::midpoint,
+                        &Scalar::midpoint,
+                        &|_, _| true,
+                    );
+                }
+
+                fn avg_round<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::avg_round,
+                        &|x, y| (x >> 1) + (y >> 1) + ((x | y) & 1),
+                        &|_, _| true,
+                    );
+                }
+
+                fn div_euclid<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::div_euclid,
+                        &Scalar::div_euclid,
+                        &|_, y| !y.contains(&0),
+                    );
+                }
+
+                fn rem_euclid<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::rem_euclid,
+                        &Scalar::rem_euclid,
+                        &|_, y| !y.contains(&0),
+                    );
+                }
+
+                fn carrying_add<const LANES: usize>() {
+                    test_helpers::test_3(&|x: [Scalar; LANES], y: [Scalar; LANES], c: [Scalar; LANES]| {
+                        let mut carry = [false; LANES];
+                        for i in 0..LANES {
+                            carry[i] = c[i] & 1 == 1;
+                        }
+                        let (sum, carry_out) = Vector::<LANES>::from_array(x)
+                            .carrying_add(Vector::<LANES>::from_array(y), core_simd::Mask::from_array(carry));
+                        for i in 0..LANES {
+                            let (a, c1) = x[i].overflowing_add(y[i]);
+                            let (b, c2) = a.overflowing_add(carry[i] as Scalar);
+                            test_helpers::prop_assert_biteq!(sum[i], b);
+                            proptest::prop_assert_eq!(carry_out.test(i), c1 || c2);
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn borrowing_sub<const LANES: usize>() {
+                    test_helpers::test_3(&|x: [Scalar; LANES], y: [Scalar; LANES], c: [Scalar; LANES]| {
+                        let mut borrow = [false; LANES];
+                        for i in 0..LANES {
+                            borrow[i] = c[i] & 1 == 1;
+                        }
+                        let (diff, borrow_out) = Vector::<LANES>::from_array(x)
+                            .borrowing_sub(Vector::<LANES>::from_array(y), core_simd::Mask::from_array(borrow));
+                        for i in 0..LANES {
+                            let (a, b1) = x[i].overflowing_sub(y[i]);
+                            let (b, b2) = a.overflowing_sub(borrow[i] as Scalar);
+                            test_helpers::prop_assert_biteq!(diff[i], b);
+                            proptest::prop_assert_eq!(borrow_out.test(i), b1 || b2);
+                        }
+                        Ok(())
+                    });
+                }
+            }
+
+            // Halve both sides of sums, subtract from the upper half of the range only the lower
+            // half, and keep half of the bits of products, so that they don't overflow.
+            impl_binary_overflow_op_test!(Vector<LANES>, Scalar, Add::add, AddAssign::add_assign, Scalar::wrapping_add, |x: Scalar| x / 2, |y: Scalar| y / 2);
+            impl_binary_overflow_op_test!(Vector<LANES>, Scalar, Sub::sub, SubAssign::sub_assign, Scalar::wrapping_sub, |x: Scalar| x | (1 << (Scalar::BITS - 1)), |y: Scalar| y >> 1);
+            impl_binary_overflow_op_test!(Vector<LANES>, Scalar, Mul::mul, MulAssign::mul_assign, Scalar::wrapping_mul, |x: Scalar| x >> (Scalar::BITS / 2), |y: Scalar| y >> (Scalar::BITS / 2));
 
             // Exclude Div and Rem panicking cases
             impl_binary_checked_op_test!(Vector<LANES>, Scalar, Div::div, DivAssign::div_assign, Scalar::wrapping_div, |_, y| y != 0);
@@ -353,6 +1023,25 @@ macro_rules! impl_float_tests {
         mod $scalar {
             type Vector<const LANES: usize> = core_simd::$vector<LANES>;
             type Scalar = $scalar;
+            type IntScalar = $int_scalar;
+
+            /// Scalar reference for the IEEE 754 totalOrder predicate, as in `total_cmp`.
+            fn total_order_key(x: Scalar) -> IntScalar {
+                let bits = x.to_bits() as IntScalar;
+                bits ^ ((bits >> (IntScalar::BITS - 1)) & IntScalar::MAX)
+            }
+
+            /// Scalar reference for the pairwise order of the horizontal sum and product.
+            fn reduce_tree<const LANES: usize>(mut x: [Scalar; LANES], f: impl Fn(Scalar, Scalar) -> Scalar) -> Scalar {
+                let mut width = LANES;
+                while width > 1 {
+                    width /= 2;
+                    for i in 0..width {
+                        x[i] = f(x[i], x[i + width]);
+                    }
+                }
+                x[0]
+            }
 
             impl_unary_op_test!(Vector<LANES>, Scalar, Neg::neg);
             impl_binary_op_test!(Vector<LANES>, Scalar, Add::add, AddAssign::add_assign);
@@ -361,7 +1050,37 @@ macro_rules! impl_float_tests {
             impl_binary_op_test!(Vector<LANES>, Scalar, Div::div, DivAssign::div_assign);
             impl_binary_op_test!(Vector<LANES>, Scalar, Rem::rem, RemAssign::rem_assign);
 
+            #[cfg(feature = "std")]
+            test_helpers::test_lanes! {
+                fn div_euclid<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::div_euclid,
+                        &Scalar::div_euclid,
+                        &|_, _| true,
+                    );
+                }
+
+                fn rem_euclid<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::rem_euclid,
+                        &Scalar::rem_euclid,
+                        &|_, _| true,
+                    );
+                }
+            }
+
             test_helpers::test_lanes! {
+                fn lanes_and_align<const LANES: usize>() {
+                    let v = Vector::<LANES>::default();
+                    assert_eq!(Vector::<LANES>::LANES, LANES);
+                    assert_eq!(v.lanes(), LANES);
+                    let align = Vector::<LANES>::ALIGN;
+                    assert!(align.is_power_of_two());
+                    assert!(align >= core::mem::align_of::<Scalar>());
+                    assert_eq!(core::mem::align_of_val(&v), align);
+                    assert_eq!(core::mem::size_of_val(&v), LANES * core::mem::size_of::<Scalar>());
+                }
+
                 fn is_sign_positive<const LANES: usize>() {
                     test_helpers::test_unary_mask_elementwise(
                         &Vector::<LANES>::is_sign_positive,
@@ -402,6 +1121,95 @@ macro_rules! impl_float_tests {
                     );
                 }
 
+                fn is_zero<const LANES: usize>() {
+                    test_helpers::test_unary_mask_elementwise(
+                        &Vector::<LANES>::is_zero,
+                        &|x: Scalar| x == 0.0,
+                        &|_| true,
+                    );
+                }
+
+                fn classify<const LANES: usize>() {
+                    test_helpers::test_1(&|x: [Scalar; LANES]| {
+                        let categories = Vector::<LANES>::from_array(x).classify();
+                        for i in 0..LANES {
+                            proptest::prop_assert_eq!(categories[i], x[i].classify());
+                        }
+                        Ok(())
+                    });
+                    let special = [0.0, -0.0, Scalar::INFINITY, Scalar::NEG_INFINITY, Scalar::NAN, Scalar::MIN_POSITIVE, Scalar::MIN_POSITIVE / 2.0];
+                    for x in special {
+                        assert_eq!(Vector::<LANES>::splat(x).classify()[0], x.classify());
+                    }
+                }
+
+                fn next_up<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::next_up,
+                        &Scalar::next_up,
+                        &|_| true,
+                    );
+                    for x in [0.0, -0.0, Scalar::INFINITY, Scalar::NEG_INFINITY, Scalar::MAX, -Scalar::from_bits(1)] {
+                        assert_eq!(Vector::<LANES>::splat(x).next_up()[0].to_bits(), x.next_up().to_bits());
+                    }
+                }
+
+                fn next_down<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::next_down,
+                        &Scalar::next_down,
+                        &|_| true,
+                    );
+                    for x in [0.0, -0.0, Scalar::INFINITY, Scalar::NEG_INFINITY, Scalar::MIN, Scalar::from_bits(1)] {
+                        assert_eq!(Vector::<LANES>::splat(x).next_down()[0].to_bits(), x.next_down().to_bits());
+                    }
+                }
+
+                fn bitselect<const LANES: usize>() {
+                    // Random floats are used as the source of random bits.
+                    test_helpers::test_3(&|x: [Scalar; LANES], y: [Scalar; LANES], bits: [Scalar; LANES]| {
+                        let bits = Vector::<LANES>::from_array(bits).to_bits();
+                        let selected = Vector::<LANES>::from_array(x).bitselect(Vector::<LANES>::from_array(y), bits);
+                        for i in 0..LANES {
+                            let expected = (x[i].to_bits() & bits[i]) | (y[i].to_bits() & !bits[i]);
+                            proptest::prop_assert_eq!(selected[i].to_bits(), expected);
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn lanes_approx_eq<const LANES: usize>() {
+                    test_helpers::test_3(&|x: [Scalar; LANES], y: [Scalar; LANES], max_ulps: u8| {
+                        // Make some lanes close together.
+                        let y = core::array::from_fn::<Scalar, LANES, _>(|i| {
+                            if y[i].is_sign_negative() { x[i].next_up().next_up() } else { y[i] }
+                        });
+                        let (a, b) = (Vector::<LANES>::from_array(x), Vector::<LANES>::from_array(y));
+                        let max_ulps = max_ulps % 4;
+                        let (ulps, abs, rel) = (
+                            a.lanes_approx_eq(b, max_ulps as _).to_array(),
+                            a.lanes_approx_eq_abs(b, 0.5).to_array(),
+                            a.lanes_approx_eq_rel(b, 0.25).to_array(),
+                        );
+                        for i in 0..LANES {
+                            // Count the steps between the values, up to one more than allowed.
+                            let mut steps = 0;
+                            let (mut lo, hi) = if x[i] < y[i] { (x[i], y[i]) } else { (y[i], x[i]) };
+                            while lo < hi && steps <= max_ulps {
+                                lo = lo.next_up();
+                                steps += 1;
+                            }
+                            let expected = x[i] == y[i] || (lo == hi && steps <= max_ulps && !(x[i].is_nan() || y[i].is_nan()));
+                            proptest::prop_assert_eq!(ulps[i], expected, "{:?} {:?}", x[i], y[i]);
+
+                            let diff = (x[i] - y[i]).abs();
+                            proptest::prop_assert_eq!(abs[i], x[i] == y[i] || diff <= 0.5);
+                            proptest::prop_assert_eq!(rel[i], x[i] == y[i] || diff <= x[i].abs().max(y[i].abs()) * 0.25);
+                        }
+                        Ok(())
+                    });
+                }
+
                 fn is_normal<const LANES: usize>() {
                     test_helpers::test_unary_mask_elementwise(
                         &Vector::<LANES>::is_normal,
@@ -426,21 +1234,133 @@ macro_rules! impl_float_tests {
                     )
                 }
 
+                fn to_degrees<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::to_degrees,
+                        &Scalar::to_degrees,
+                        &|_| true,
+                    )
+                }
+
+                fn to_radians<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::to_radians,
+                        &Scalar::to_radians,
+                        &|_| true,
+                    )
+                }
+
+                fn lanes_total_lt<const LANES: usize>() {
+                    test_helpers::test_binary_mask_elementwise(
+                        &Vector::<LANES>::lanes_total_lt,
+                        &|x, y| total_order_key(x) < total_order_key(y),
+                        &|_, _| true,
+                    )
+                }
+
+                fn lanes_total_le<const LANES: usize>() {
+                    test_helpers::test_binary_mask_elementwise(
+                        &Vector::<LANES>::lanes_total_le,
+                        &|x, y| total_order_key(x) <= total_order_key(y),
+                        &|_, _| true,
+                    )
+                }
+
+                fn lanes_total_gt<const LANES: usize>() {
+                    test_helpers::test_binary_mask_elementwise(
+                        &Vector::<LANES>::lanes_total_gt,
+                        &|x, y| total_order_key(x) > total_order_key(y),
+                        &|_, _| true,
+                    )
+                }
+
+                fn lanes_total_ge<const LANES: usize>() {
+                    test_helpers::test_binary_mask_elementwise(
+                        &Vector::<LANES>::lanes_total_ge,
+                        &|x, y| total_order_key(x) >= total_order_key(y),
+                        &|_, _| true,
+                    )
+                }
+
+                fn const_ops<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [Scalar; LANES], y: [Scalar; LANES]| {
+                        let (a, b) = (Vector::<LANES>::from_array(x), Vector::<LANES>::from_array(y));
+                        test_helpers::prop_assert_biteq!(a.const_add(b).to_array(), (a + b).to_array());
+                        test_helpers::prop_assert_biteq!(a.const_sub(b).to_array(), (a - b).to_array());
+                        test_helpers::prop_assert_biteq!(a.const_mul(b).to_array(), (a * b).to_array());
+                        test_helpers::prop_assert_biteq!(a.const_div(b).to_array(), (a / b).to_array());
+                        Ok(())
+                    });
+                }
+
                 fn horizontal_sum<const LANES: usize>() {
                     test_helpers::test_1(&|x| {
                         test_helpers::prop_assert_biteq! (
                             Vector::<LANES>::from_array(x).horizontal_sum(),
-                            x.iter().sum(),
+                            reduce_tree(x, |a, b| a + b),
                         );
                         Ok(())
                     });
                 }
 
+                fn dot<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [Scalar; LANES], y: [Scalar; LANES]| {
+                        let mut products = [0 as Scalar; LANES];
+                        for i in 0..LANES {
+                            products[i] = x[i] * y[i];
+                        }
+                        let expected = reduce_tree(products, |a, b| a + b);
+                        let result = Vector::<LANES>::from_array(x).dot(Vector::<LANES>::from_array(y));
+                        test_helpers::prop_assert_biteq!(result, expected);
+                        Ok(())
+                    });
+                }
+
+                fn dot_overflow<const LANES: usize>() {
+                    // Products that overflow must sum to an infinity, not `NaN`.
+                    let big = Vector::<LANES>::splat(Scalar::MAX);
+                    let two = Vector::<LANES>::splat(2.);
+                    assert_eq!(big.dot(two), Scalar::INFINITY);
+                    assert_eq!((-big).dot(two), Scalar::NEG_INFINITY);
+                }
+
                 fn horizontal_product<const LANES: usize>() {
                     test_helpers::test_1(&|x| {
                         test_helpers::prop_assert_biteq! (
                             Vector::<LANES>::from_array(x).horizontal_product(),
-                            x.iter().product(),
+                            reduce_tree(x, |a, b| a * b),
+                        );
+                        Ok(())
+                    });
+                }
+
+                fn horizontal_sum_exact<const LANES: usize>() {
+                    // Sums of small integers are exact in any order, so the tree must agree with
+                    // a sequential sum.
+                    test_helpers::test_1(&|x: [i16; LANES]| {
+                        let mut lanes = [0 as Scalar; LANES];
+                        for i in 0..LANES {
+                            lanes[i] = x[i] as Scalar;
+                        }
+                        proptest::prop_assert_eq!(
+                            Vector::<LANES>::from_array(lanes).horizontal_sum(),
+                            lanes.iter().fold(0 as Scalar, |acc, x| acc + x)
+                        );
+                        Ok(())
+                    });
+                }
+
+                fn horizontal_product_exact<const LANES: usize>() {
+                    // Products of 1, 2, -1 and -2 are exact in any order, and only overflow if
+                    // the final product does.
+                    test_helpers::test_1(&|x: [u8; LANES]| {
+                        let mut lanes = [0 as Scalar; LANES];
+                        for i in 0..LANES {
+                            lanes[i] = [1., 2., -1., -2.][x[i] as usize % 4];
+                        }
+                        proptest::prop_assert_eq!(
+                            Vector::<LANES>::from_array(lanes).horizontal_product(),
+                            lanes.iter().fold(1 as Scalar, |acc, x| acc * x)
                         );
                         Ok(())
                     });
@@ -469,6 +1389,64 @@ macro_rules! impl_float_tests {
                         Ok(())
                     });
                 }
+
+                fn horizontal_minmax<const LANES: usize>() {
+                    test_helpers::test_1(&|x: [Scalar; LANES]| {
+                        let (vmin, vmax) = Vector::<LANES>::from_array(x).horizontal_minmax();
+                        let smin = x.iter().copied().fold(Scalar::NAN, Scalar::min);
+                        let smax = x.iter().copied().fold(Scalar::NAN, Scalar::max);
+                        // 0 and -0 are treated the same
+                        if !(x.contains(&0.) && x.contains(&-0.)) {
+                            test_helpers::prop_assert_biteq!(vmin, smin);
+                            test_helpers::prop_assert_biteq!(vmax, smax);
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn horizontal_argmax<const LANES: usize>() {
+                    test_helpers::test_1(&|x: [Scalar; LANES]| {
+                        let max = x.iter().copied().fold(Scalar::NAN, Scalar::max);
+                        let expected = x.iter().position(|&v| v == max).unwrap_or(0);
+                        proptest::prop_assert_eq!(Vector::<LANES>::from_array(x).horizontal_argmax(), expected);
+                        Ok(())
+                    });
+                }
+
+                fn horizontal_argmin<const LANES: usize>() {
+                    test_helpers::test_1(&|x: [Scalar; LANES]| {
+                        let min = x.iter().copied().fold(Scalar::NAN, Scalar::min);
+                        let expected = x.iter().position(|&v| v == min).unwrap_or(0);
+                        proptest::prop_assert_eq!(Vector::<LANES>::from_array(x).horizontal_argmin(), expected);
+                        Ok(())
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Implement tests for fixed-point arithmetic on signed integer vectors.
+#[macro_export]
+macro_rules! impl_fixed_point_tests {
+    { $vector:ident, $scalar:ident } => {
+        mod fixed_point {
+            use super::*;
+
+            type Vector<const LANES: usize> = core_simd::$vector<LANES>;
+
+            test_helpers::test_lanes! {
+                fn saturating_rounding_doubling_mul_high<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::saturating_rounding_doubling_mul_high,
+                        &|x: $scalar, y: $scalar| {
+                            let bits = core::mem::size_of::<$scalar>() as u32 * 8;
+                            let product = (2 * x as i128 * y as i128 + (1 << (bits - 1))) >> bits;
+                            product.min($scalar::MAX as i128) as $scalar
+                        },
+                        &|_, _| true,
+                    );
+                }
             }
         }
     }
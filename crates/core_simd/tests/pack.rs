@@ -0,0 +1,39 @@
+use core_simd::Simd;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! test_pack {
+    { $($name:ident: $wide:ty => $signed:ty, $unsigned:ty, $lanes:literal => $packed:literal;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            test_helpers::test_2(&|x: [$wide; $lanes], y: [$wide; $lanes]| {
+                let (a, b) = (Simd::from_array(x), Simd::from_array(y));
+                let (signed, unsigned) = (a.saturating_pack(b), a.saturating_pack_unsigned(b));
+                for (i, lane) in x.iter().chain(y.iter()).copied().enumerate() {
+                    let expected_signed = lane.max(<$signed>::MIN as $wide).min(<$signed>::MAX as $wide) as $signed;
+                    let expected_unsigned = lane.max(0).min(<$unsigned>::MAX as $wide) as $unsigned;
+                    proptest::prop_assert_eq!(signed[i], expected_signed);
+                    proptest::prop_assert_eq!(unsigned[i], expected_unsigned);
+                }
+                Ok(())
+            });
+        }
+        )*
+    }
+}
+
+test_pack! {
+    pack_i16_1: i16 => i8, u8, 1 => 2;
+    pack_i16_8: i16 => i8, u8, 8 => 16;
+    pack_i16_64: i16 => i8, u8, 64 => 128;
+    pack_i32_4: i32 => i16, u16, 4 => 8;
+    pack_i32_16: i32 => i16, u16, 16 => 32;
+    pack_i64_2: i64 => i32, u32, 2 => 4;
+    pack_i64_8: i64 => i32, u32, 8 => 16;
+}
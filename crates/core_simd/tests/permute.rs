@@ -33,3 +33,176 @@ fn interleave() {
     assert_eq!(even, a);
     assert_eq!(odd, b);
 }
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reverse_128_lanes() {
+    let mut array = [0u8; 128];
+    for (i, x) in array.iter_mut().enumerate() {
+        *x = i as u8;
+    }
+    let reversed = core_simd::u8x128::from_array(array).reverse().to_array();
+    for (i, x) in reversed.iter().enumerate() {
+        assert_eq!(*x as usize, 127 - i);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn horizontal_pairwise_add() {
+    let a = SimdU32::from_array([0, 1, 2, 3, 4, 5, 6, u32::MAX]);
+    let b = SimdU32::from_array([8, 9, 10, 11, 12, 13, 14, 15]);
+    assert_eq!(
+        a.horizontal_pairwise_add(b).to_array(),
+        [1, 5, 9, 5, 17, 21, 25, 29]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn complex_mul() {
+    let a = core_simd::SimdF32::from_array([1., 2., 3., -4., 0., 1., 5., 0.]);
+    let b = core_simd::SimdF32::from_array([3., 4., 1., 1., 0., 1., 0., 5.]);
+    assert_eq!(
+        a.complex_mul(b).to_array(),
+        [-5., 10., 7., -1., -1., 0., 0., 25.]
+    );
+    let a = core_simd::SimdF64::from_array([0.5, -1.5]);
+    let b = core_simd::SimdF64::from_array([2., 4.]);
+    assert_eq!(a.complex_mul(b).to_array(), [7., -1.]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn prefix_sum_128_lanes() {
+    let mut array = [0u16; 128];
+    for (i, x) in array.iter_mut().enumerate() {
+        *x = i as u16;
+    }
+    let a = core_simd::SimdU16::from_array(array);
+    let (inclusive, exclusive) = (
+        a.prefix_sum().to_array(),
+        a.exclusive_prefix_sum().to_array(),
+    );
+    for i in 0..128 {
+        assert_eq!(inclusive[i] as usize, i * (i + 1) / 2);
+        assert_eq!(exclusive[i] as usize, i * (i + 1) / 2 - i);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn prefix_sum_2_lanes() {
+    let a = core_simd::SimdF32::from_array([1.5, 2.5]);
+    assert_eq!(a.prefix_sum().to_array(), [1.5, 4.]);
+    assert_eq!(a.exclusive_prefix_sum().to_array(), [0., 1.5]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sorted_128_lanes() {
+    let mut array = [0u8; 128];
+    for (i, x) in array.iter_mut().enumerate() {
+        *x = ((i * 37 + 11) % 128) as u8;
+    }
+    let a = core_simd::u8x128::from_array(array);
+    let (ascending, descending) = (a.sorted().to_array(), a.sorted_descending().to_array());
+    for i in 0..128 {
+        assert_eq!(ascending[i] as usize, i);
+        assert_eq!(descending[i] as usize, 127 - i);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sorted_with_duplicates() {
+    let array = [5i16, -3, 5, 0, 9, -3, 2, 2, 7, 0, -8, 5, 1, 1, 6, -3];
+    let mut expected = array;
+    expected.sort_unstable();
+    assert_eq!(
+        core_simd::SimdI16::from_array(array).sorted().to_array(),
+        expected
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sorted_keeps_nan() {
+    let a = core_simd::SimdF32::from_array([3., f32::NAN, -1., 2., f32::NAN, 0., 8., -4.]);
+    let mut sorted = a.sorted().to_array();
+    assert_eq!(sorted.iter().filter(|x| x.is_nan()).count(), 2);
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    assert_eq!(sorted[..6], [-4., -1., 0., 2., 3., 8.]);
+}
+
+macro_rules! test_transpose {
+    { $($name:ident: $vector:ident, $scalar:ty, $n:literal;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            let mut rows = [core_simd::$vector::<$n>::splat(0 as $scalar); $n];
+            for (i, row) in rows.iter_mut().enumerate() {
+                for j in 0..$n {
+                    row[j] = (i * $n + j) as $scalar;
+                }
+            }
+            let columns = core_simd::$vector::<$n>::transpose(rows);
+            for i in 0..$n {
+                for j in 0..$n {
+                    assert_eq!(columns[i][j], rows[j][i]);
+                }
+            }
+            assert_eq!(core_simd::$vector::<$n>::transpose(columns), rows);
+        }
+        )*
+    }
+}
+
+test_transpose! {
+    transpose_f32_4x4: SimdF32, f32, 4;
+    transpose_i16_8x8: SimdI16, i16, 8;
+    transpose_u8_16x16: SimdU8, u8, 16;
+    transpose_u64_2x2: SimdU64, u64, 2;
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn swizzle_patterns() {
+    use core_simd::*;
+    let a = SimdU32::from_array([0, 1, 2, 3, 4, 5, 6, 7]);
+    let b = SimdU32::from_array([8, 9, 10, 11, 12, 13, 14, 15]);
+    assert_eq!(a.swizzle::<Reverse>(), a.reverse());
+    assert_eq!(
+        a.swizzle::<RotateLanesLeft<3>>().to_array(),
+        [3, 4, 5, 6, 7, 0, 1, 2]
+    );
+    assert_eq!(
+        a.swizzle::<RotateLanesRight<3>>().to_array(),
+        [5, 6, 7, 0, 1, 2, 3, 4]
+    );
+    assert_eq!(
+        a.swizzle::<RotateLanesLeft<9>>(),
+        a.swizzle::<RotateLanesLeft<1>>()
+    );
+    assert_eq!(a.swizzle::<RotateLanesRight<8>>(), a);
+    assert_eq!(
+        (a.swizzle2::<InterleaveLo>(b), a.swizzle2::<InterleaveHi>(b)),
+        a.interleave(b)
+    );
+    assert_eq!(
+        a.swizzle::<DuplicateEven>().to_array(),
+        [0, 0, 2, 2, 4, 4, 6, 6]
+    );
+    assert_eq!(
+        a.swizzle::<DuplicateOdd>().to_array(),
+        [1, 1, 3, 3, 5, 5, 7, 7]
+    );
+    assert_eq!(a.swizzle::<BroadcastLane<5>>(), SimdU32::splat(5));
+    assert_eq!(
+        SimdU32::<2>::from_array([1, 2])
+            .swizzle::<RotateLanesRight<1>>()
+            .to_array(),
+        [2, 1]
+    );
+}
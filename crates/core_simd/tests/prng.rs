@@ -0,0 +1,112 @@
+use core_simd::{Simd, SimdRng};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// The reference scalar SplitMix64 generator.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// The reference scalar xoshiro256++ generator.
+struct Xoshiro256PlusPlus([u64; 4]);
+
+impl Xoshiro256PlusPlus {
+    fn from_splitmix(splitmix: &mut SplitMix64) -> Self {
+        Self([
+            splitmix.next(),
+            splitmix.next(),
+            splitmix.next(),
+            splitmix.next(),
+        ])
+    }
+
+    fn next(&mut self) -> u64 {
+        let s = &mut self.0;
+        let result = s[0].wrapping_add(s[3]).rotate_left(23).wrapping_add(s[0]);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reference_output() {
+    // Check the scalar references against published outputs first.
+    assert_eq!(Xoshiro256PlusPlus([1, 2, 3, 4]).next(), 41943041);
+    let mut rng = SimdRng::<1>::from_seeds(Simd::splat(0));
+    let mut expected = Xoshiro256PlusPlus::from_splitmix(&mut SplitMix64(0));
+    assert_eq!(expected.0[0], 0xe220a8397b1dcdaf);
+    for _ in 0..4 {
+        assert_eq!(rng.next_u64()[0], expected.next());
+    }
+}
+
+test_helpers::test_lanes! {
+    fn seed_from_u64<const LANES: usize>() {
+        test_helpers::test_1(&|seed: u64| {
+            let mut rng = SimdRng::<LANES>::seed_from_u64(seed);
+            let mut splitmix = SplitMix64(seed);
+            let mut lanes: Vec<_> = (0..LANES)
+                .map(|_| Xoshiro256PlusPlus::from_splitmix(&mut splitmix))
+                .collect();
+            for _ in 0..8 {
+                let v = rng.next_u64();
+                for (i, lane) in lanes.iter_mut().enumerate() {
+                    proptest::prop_assert_eq!(v[i], lane.next());
+                }
+            }
+            Ok(())
+        });
+    }
+
+    fn from_seeds<const LANES: usize>() {
+        test_helpers::test_1(&|seeds: [u64; LANES]| {
+            let mut rng = SimdRng::from_seeds(Simd::<u64, LANES>::from_array(seeds));
+            for (i, seed) in seeds.iter().copied().enumerate() {
+                let mut lane = rng.clone();
+                let mut expected = Xoshiro256PlusPlus::from_splitmix(&mut SplitMix64(seed));
+                for _ in 0..8 {
+                    proptest::prop_assert_eq!(lane.next_u64()[i], expected.next());
+                }
+            }
+            let bits = rng.clone().next_u64();
+            let high = rng.next_u32();
+            for i in 0..LANES {
+                proptest::prop_assert_eq!(high[i], (bits[i] >> 32) as u32);
+            }
+            Ok(())
+        });
+    }
+
+    fn floats_in_unit_interval<const LANES: usize>() {
+        test_helpers::test_1(&|seed: u64| {
+            let mut rng = SimdRng::<LANES>::seed_from_u64(seed);
+            for _ in 0..8 {
+                let (x, y) = (rng.next_f32(), rng.next_f64());
+                for i in 0..LANES {
+                    proptest::prop_assert!((0. ..1.).contains(&x[i]));
+                    proptest::prop_assert!((0. ..1.).contains(&y[i]));
+                }
+            }
+            Ok(())
+        });
+    }
+}
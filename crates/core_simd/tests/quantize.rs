@@ -0,0 +1,107 @@
+use core_simd::{f32x8, Simd};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn round_ties_even(x: f32) -> f32 {
+    if (x - x.trunc()).abs() == 0.5 {
+        2.0 * (x / 2.0).round()
+    } else {
+        x.round()
+    }
+}
+
+fn quantize_scalar(x: f32, scale: f32, zero_point: f32, min: f32, max: f32) -> f32 {
+    let scaled = x * scale + zero_point;
+    if scaled.is_nan() {
+        zero_point
+    } else {
+        round_ties_even(scaled).max(min).min(max)
+    }
+}
+
+macro_rules! test_quantize {
+    { $($name:ident, $ties_name:ident: $fn:ident => $int:ty;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            test_helpers::test_3(&|x: [f32; 8], scale: f32, zero_point: $int| {
+                let q = f32x8::from_array(x).$fn(scale, zero_point);
+                for (i, lane) in x.iter().copied().enumerate() {
+                    let expected = quantize_scalar(lane, scale, zero_point as f32, <$int>::MIN as f32, <$int>::MAX as f32);
+                    proptest::prop_assert_eq!(q[i], expected as $int);
+                }
+                Ok(())
+            });
+        }
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $ties_name() {
+            // Eighths multiplied by four hit every tie between two integers.
+            test_helpers::test_2(&|eighths: [i16; 8], zero_point: $int| {
+                let mut x = [0.0; 8];
+                for (x, eighths) in x.iter_mut().zip(eighths.iter()) {
+                    *x = *eighths as f32 / 8.0;
+                }
+                let q = f32x8::from_array(x).$fn(4.0, zero_point);
+                for (i, lane) in x.iter().copied().enumerate() {
+                    let expected = quantize_scalar(lane, 4.0, zero_point as f32, <$int>::MIN as f32, <$int>::MAX as f32);
+                    proptest::prop_assert_eq!(q[i], expected as $int);
+                }
+                Ok(())
+            });
+        }
+        )*
+    }
+}
+
+test_quantize! {
+    quantize_i8, quantize_i8_ties: quantize_i8 => i8;
+    quantize_u8, quantize_u8_ties: quantize_u8 => u8;
+}
+
+macro_rules! test_dequantize {
+    { $($name:ident, $roundtrip_name:ident: $quantize:ident => $int:ty;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            test_helpers::test_3(&|q: [$int; 8], scale: f32, zero_point: $int| {
+                let x = Simd::from_array(q).dequantize(scale, zero_point);
+                for (i, lane) in q.iter().copied().enumerate() {
+                    let expected = (lane as i32 - zero_point as i32) as f32 * scale;
+                    if expected.is_nan() {
+                        proptest::prop_assert!(x[i].is_nan());
+                    } else {
+                        proptest::prop_assert_eq!(x[i].to_bits(), expected.to_bits());
+                    }
+                }
+                Ok(())
+            });
+        }
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $roundtrip_name() {
+            test_helpers::test_3(&|q: [$int; 8], scale: f32, zero_point: $int| {
+                // Scales this far from the limits keep every dequantized value, and the
+                // reciprocal scale, normal.
+                proptest::prop_assume!(scale.abs() > 1e-30 && scale.abs() < 1e30);
+                let q = Simd::from_array(q);
+                proptest::prop_assert_eq!(q.dequantize(scale, zero_point).$quantize(1.0 / scale, zero_point), q);
+                Ok(())
+            });
+        }
+        )*
+    }
+}
+
+test_dequantize! {
+    dequantize_i8, dequantize_i8_roundtrip: quantize_i8 => i8;
+    dequantize_u8, dequantize_u8_roundtrip: quantize_u8 => u8;
+}
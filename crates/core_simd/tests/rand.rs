@@ -0,0 +1,64 @@
+#![cfg(feature = "rand")]
+
+use core_simd::{Mask, SimdF32, SimdI16, SimdU64};
+use rand::distributions::{Distribution, Uniform};
+use rand::rngs::mock::StepRng;
+use rand::Rng;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn rng() -> StepRng {
+    StepRng::new(0x0123_4567_89ab_cdef, 0x9e37_79b9_7f4a_7c15)
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn standard_matches_lanes() {
+    let v: SimdU64<4> = rng().gen();
+    let mut scalar = rng();
+    for lane in v.to_array().iter() {
+        assert_eq!(*lane, scalar.gen::<u64>());
+    }
+
+    let m: Mask<i8, 8> = rng().gen();
+    let mut scalar = rng();
+    for lane in m.to_array().iter() {
+        assert_eq!(*lane, scalar.gen::<bool>());
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn uniform_within_bounds() {
+    let low = SimdI16::from_array([-10, 0, 100, i16::MIN]);
+    let high = SimdI16::from_array([10, 1, 200, i16::MAX]);
+    let exclusive = Uniform::new(low, high);
+    let inclusive = Uniform::new_inclusive(low, high);
+    let mut rng = rng();
+    for _ in 0..100 {
+        let x = exclusive.sample(&mut rng);
+        assert!((x.lanes_ge(low) & x.lanes_lt(high)).all());
+        let x = inclusive.sample(&mut rng);
+        assert!((x.lanes_ge(low) & x.lanes_le(high)).all());
+    }
+    // The only value in 0..1 is 0.
+    assert_eq!(exclusive.sample(&mut rng)[1], 0);
+
+    let low = SimdF32::from_array([0.0, -1.0]);
+    let high = SimdF32::from_array([1.0, -0.5]);
+    for x in Uniform::new(low, high).sample_iter(rng).take(100) {
+        assert!((x.lanes_ge(low) & x.lanes_lt(high)).all());
+    }
+}
+
+#[test]
+#[should_panic]
+fn uniform_empty_range() {
+    let low = SimdU64::from_array([0, 5]);
+    let high = SimdU64::from_array([1, 5]);
+    let _ = Uniform::new(low, high);
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "rayon")]
+
+use core_simd::{ParSimdSlice, SimdF64, SimdU32};
+use rayon::prelude::*;
+
+#[test]
+fn par_simd_chunks() {
+    let values: Vec<u32> = (0..1003).collect();
+    let chunks = values.par_simd_chunks::<8>();
+    assert_eq!(chunks.len(), 125);
+    assert_eq!(chunks.remainder(), &[1000, 1001, 1002]);
+    let vectors: Vec<SimdU32<8>> = chunks.collect();
+    for (i, vector) in vectors.iter().enumerate() {
+        assert_eq!(vector.as_slice(), &values[8 * i..8 * i + 8]);
+    }
+}
+
+#[test]
+fn par_simd_chunks_sum() {
+    let values: Vec<f64> = (0..4096).map(|x| x as f64).collect();
+    let sum = values
+        .par_simd_chunks::<4>()
+        .reduce(|| SimdF64::splat(0.), |a, b| a + b)
+        .horizontal_sum();
+    assert_eq!(sum, values.iter().sum::<f64>());
+}
+
+#[test]
+fn par_simd_chunks_mut() {
+    let mut values: Vec<u32> = (0..70).collect();
+    let mut chunks = values.par_simd_chunks_mut::<16>();
+    assert_eq!(chunks.len(), 4);
+    assert_eq!(chunks.take_remainder(), &[64, 65, 66, 67, 68, 69]);
+    chunks
+        .enumerate()
+        .for_each(|(i, chunk)| *chunk = (SimdU32::from_array(*chunk) + i as u32).to_array());
+    for (i, x) in values.iter().enumerate() {
+        let offset = if i < 64 { i as u32 / 16 } else { 0 };
+        assert_eq!(*x, i as u32 + offset);
+    }
+}
+
+#[test]
+fn empty_slice() {
+    let values: [u32; 0] = [];
+    assert_eq!(values.par_simd_chunks::<4>().count(), 0);
+    assert!(values.par_simd_chunks::<4>().remainder().is_empty());
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "serde")]
+
+use core_simd::{Mask, SimdF32, SimdI32, SimdU8};
+use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn vector_tokens() {
+    assert_tokens(
+        &SimdI32::from_array([1, -2, 3, i32::MIN]),
+        &[
+            Token::Tuple { len: 4 },
+            Token::I32(1),
+            Token::I32(-2),
+            Token::I32(3),
+            Token::I32(i32::MIN),
+            Token::TupleEnd,
+        ],
+    );
+    assert_tokens(
+        &SimdF32::from_array([0.5, -1.0]),
+        &[
+            Token::Tuple { len: 2 },
+            Token::F32(0.5),
+            Token::F32(-1.0),
+            Token::TupleEnd,
+        ],
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn mask_tokens() {
+    assert_tokens(
+        &Mask::<i16, 4>::from_array([true, false, false, true]),
+        &[
+            Token::Tuple { len: 4 },
+            Token::Bool(true),
+            Token::Bool(false),
+            Token::Bool(false),
+            Token::Bool(true),
+            Token::TupleEnd,
+        ],
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn wrong_length() {
+    assert_de_tokens_error::<SimdU8<4>>(
+        &[
+            Token::Tuple { len: 4 },
+            Token::U8(1),
+            Token::U8(2),
+            Token::TupleEnd,
+        ],
+        "invalid length 2, expected an array of length 4",
+    );
+}
+
+#[cfg(feature = "f16")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn f16_tokens() {
+    use core_simd::{SimdF16, SimdU16};
+    assert_tokens(
+        &SimdF16::from_bits(SimdU16::from_array([0x3c00, 0xc000])),
+        &[
+            Token::Tuple { len: 2 },
+            Token::U16(0x3c00),
+            Token::U16(0xc000),
+            Token::TupleEnd,
+        ],
+    );
+}
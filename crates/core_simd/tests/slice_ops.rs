@@ -0,0 +1,118 @@
+use core_simd::slice_ops;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+// Every length up to a few vectors of accumulators, at every offset into an aligned buffer, so
+// that each combination of vectors and remaining elements is covered at every alignment.
+fn for_each_subslice<T: Copy>(values: &[T], f: impl Fn(&[T])) {
+    for start in 0..16.min(values.len()) {
+        for end in start..values.len() {
+            f(&values[start..end]);
+        }
+    }
+}
+
+macro_rules! test_int {
+    { $($name:ident: $type:ty,)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            let values: Vec<$type> = (0..300u32)
+                .map(|i| (i.wrapping_mul(0x9e37_79b9) >> 7) as $type)
+                .collect();
+            for_each_subslice(&values, |slice| {
+                assert_eq!(slice_ops::sum(slice), slice.iter().fold(0, |acc: $type, x| acc.wrapping_add(*x)));
+                assert_eq!(slice_ops::min(slice), slice.iter().copied().min());
+                assert_eq!(slice_ops::max(slice), slice.iter().copied().max());
+                let other = &values[values.len() - slice.len()..];
+                assert_eq!(
+                    slice_ops::dot(slice, other),
+                    slice.iter().zip(other).fold(0, |acc: $type, (x, y)| acc.wrapping_add(x.wrapping_mul(*y))),
+                );
+            });
+        }
+        )*
+    }
+}
+
+test_int! {
+    int_u8: u8,
+    int_i16: i16,
+    int_u32: u32,
+    int_i64: i64,
+    int_u128: u128,
+    int_isize: isize,
+}
+
+macro_rules! test_float {
+    { $($name:ident: $type:ty,)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            // Small integers, so that the sums are exact regardless of their order.
+            let values: Vec<$type> = (0..300u32).map(|i| ((i * 37) % 101) as $type - 50.).collect();
+            for_each_subslice(&values, |slice| {
+                assert_eq!(slice_ops::sum(slice), slice.iter().sum::<$type>());
+                assert_eq!(slice_ops::min(slice), slice.iter().copied().reduce(<$type>::min));
+                assert_eq!(slice_ops::max(slice), slice.iter().copied().reduce(<$type>::max));
+                let other = &values[values.len() - slice.len()..];
+                assert_eq!(slice_ops::dot(slice, other), slice.iter().zip(other).map(|(x, y)| x * y).sum::<$type>());
+            });
+        }
+        )*
+    }
+}
+
+test_float! {
+    float_f32: f32,
+    float_f64: f64,
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn float_sum_independent_of_alignment() {
+    // Values of very different magnitudes, so that the sum depends on the order of the additions.
+    let values: Vec<f32> = (0..200u32)
+        .map(|i| (i.wrapping_mul(0x9e37_79b9) >> 8) as f32 * [1e-3, 1., 1e3, 1e6][i as usize % 4])
+        .collect();
+    let expected = slice_ops::sum(&values).to_bits();
+    let mut buffer = vec![0.; values.len() + 16];
+    for offset in 0..16 {
+        buffer[offset..offset + values.len()].copy_from_slice(&values);
+        let sum = slice_ops::sum(&buffer[offset..offset + values.len()]);
+        assert_eq!(sum.to_bits(), expected);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn float_min_max_ignore_nan() {
+    let mut values = vec![f32::NAN; 100];
+    assert!(slice_ops::min(&values).unwrap().is_nan());
+    assert!(slice_ops::max(&values).unwrap().is_nan());
+    values[3] = 2.;
+    values[77] = -1.;
+    assert_eq!(slice_ops::min(&values), Some(-1.));
+    assert_eq!(slice_ops::max(&values), Some(2.));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn empty() {
+    assert_eq!(slice_ops::sum::<f64>(&[]), 0.);
+    assert_eq!(slice_ops::min::<u8>(&[]), None);
+    assert_eq!(slice_ops::max::<i32>(&[]), None);
+    assert_eq!(slice_ops::dot::<f32>(&[], &[]), 0.);
+}
+
+#[test]
+#[should_panic]
+fn dot_length_mismatch() {
+    slice_ops::dot(&[1u32, 2], &[1]);
+}
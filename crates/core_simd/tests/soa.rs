@@ -0,0 +1,84 @@
+use core_simd::Simd;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+core_simd::soa! {
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    struct Record {
+        /// A field with a doc comment.
+        a: f32,
+        b: u8,
+        c: i64,
+    }
+
+    struct RecordXN;
+}
+
+fn records(a: [f32; 8], b: [u8; 8], c: [i64; 8]) -> [Record; 8] {
+    let mut records = [Record { a: 0., b: 0, c: 0 }; 8];
+    for (i, record) in records.iter_mut().enumerate() {
+        *record = Record {
+            a: a[i],
+            b: b[i],
+            c: c[i],
+        };
+    }
+    records
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn load_and_store() {
+    test_helpers::test_3(&|a: [f32; 8], b: [u8; 8], c: [i64; 8]| {
+        let records = records(a, b, c);
+        let vectors = RecordXN::<8>::from_slice(&records);
+        proptest::prop_assert_eq!(vectors.a.to_bits(), Simd::from_array(a).to_bits());
+        proptest::prop_assert_eq!(vectors.b, Simd::from_array(b));
+        proptest::prop_assert_eq!(vectors.c, Simd::from_array(c));
+
+        let mut stored = [Record { a: 0., b: 0, c: 0 }; 9];
+        vectors.write_to_slice(&mut stored);
+        for i in 0..8 {
+            proptest::prop_assert_eq!(stored[i].a.to_bits(), a[i].to_bits());
+            proptest::prop_assert_eq!(stored[i].b, b[i]);
+            proptest::prop_assert_eq!(stored[i].c, c[i]);
+        }
+        proptest::prop_assert_eq!(stored[8], Record { a: 0., b: 0, c: 0 });
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn splat_and_get() {
+    let record = Record {
+        a: 1.5,
+        b: 2,
+        c: -3,
+    };
+    let vectors = RecordXN::<4>::splat(record);
+    assert_eq!(vectors.b, Simd::splat(2));
+    for lane in 0..4 {
+        assert_eq!(vectors.get(lane), record);
+    }
+    assert_eq!(
+        RecordXN::<2>::default().get(1),
+        Record { a: 0., b: 0, c: 0 }
+    );
+}
+
+#[test]
+#[should_panic]
+fn from_slice_too_short() {
+    RecordXN::<4>::from_slice(&[Record { a: 0., b: 0, c: 0 }; 3]);
+}
+
+#[test]
+#[should_panic]
+fn write_to_slice_too_short() {
+    RecordXN::<4>::default().write_to_slice(&mut [Record { a: 0., b: 0, c: 0 }; 3]);
+}
@@ -0,0 +1,110 @@
+use core_simd::simd_sort;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// A simple linear congruential generator, so that the tests are reproducible.
+fn random(len: usize, seed: u32) -> Vec<u32> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            state
+        })
+        .collect()
+}
+
+fn lengths() -> impl Iterator<Item = usize> {
+    (0..100).chain([127, 128, 129, 1000, 4096, 10_000].iter().copied())
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sort_u32() {
+    for len in lengths() {
+        let mut v = random(len, len as u32);
+        let mut expected = v.clone();
+        expected.sort_unstable();
+        simd_sort(&mut v);
+        assert_eq!(v, expected);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sort_i32_with_duplicates() {
+    for len in lengths() {
+        // Only a few distinct values, including the extremes.
+        let mut v: Vec<i32> = random(len, 7)
+            .into_iter()
+            .map(|x| [i32::MIN, -1, 0, 1, i32::MAX][x as usize % 5])
+            .collect();
+        let mut expected = v.clone();
+        expected.sort_unstable();
+        simd_sort(&mut v);
+        assert_eq!(v, expected);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sort_f32_total_order() {
+    let special = [
+        f32::NAN,
+        -f32::NAN,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        0.,
+        -0.,
+        f32::MIN_POSITIVE,
+        -1.5,
+    ];
+    for len in lengths() {
+        let mut v: Vec<f32> = random(len, 3)
+            .into_iter()
+            .map(|x| {
+                if x % 4 == 0 {
+                    special[(x >> 8) as usize % special.len()]
+                } else {
+                    (x as i32 >> 8) as f32 / 1000.
+                }
+            })
+            .collect();
+        let mut expected = v.clone();
+        expected.sort_unstable_by(f32::total_cmp);
+        simd_sort(&mut v);
+        let bits = |v: &[f32]| v.iter().map(|x| x.to_bits()).collect::<Vec<_>>();
+        assert_eq!(bits(&v), bits(&expected));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sort_sorted_and_reversed() {
+    let mut ascending: Vec<u32> = (0..5000).collect();
+    let mut descending: Vec<u32> = (0..5000).rev().collect();
+    simd_sort(&mut ascending);
+    simd_sort(&mut descending);
+    assert_eq!(ascending, descending);
+    assert!(ascending.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sort_equal_keys() {
+    for len in lengths() {
+        let mut v = vec![7u32; len];
+        simd_sort(&mut v);
+        assert_eq!(v, vec![7; len]);
+
+        // Runs of equal keys on both sides of the pivot.
+        let mut v: Vec<u32> = (0..len as u32).map(|x| x % 2 * 10).rev().collect();
+        let mut expected = v.clone();
+        expected.sort_unstable();
+        simd_sort(&mut v);
+        assert_eq!(v, expected);
+    }
+}
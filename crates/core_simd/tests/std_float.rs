@@ -0,0 +1,137 @@
+#![cfg(feature = "std")]
+
+macro_rules! std_float_test {
+    { $vector:ident, $scalar:tt } => {
+        mod $scalar {
+            use core_simd::StdFloat;
+
+            type Vector<const LANES: usize> = core_simd::$vector<LANES>;
+            type Scalar = $scalar;
+
+            test_helpers::test_lanes! {
+                fn mul_add<const LANES: usize>() {
+                    test_helpers::test_ternary_elementwise(
+                        &<Vector<LANES> as StdFloat>::mul_add,
+                        &Scalar::mul_add,
+                        &|_, _, _| true,
+                    )
+                }
+
+                fn mul_sub<const LANES: usize>() {
+                    test_helpers::test_ternary_elementwise(
+                        &<Vector<LANES> as StdFloat>::mul_sub,
+                        &|x: Scalar, a: Scalar, b: Scalar| x.mul_add(a, -b),
+                        &|_, _, _| true,
+                    )
+                }
+
+                fn neg_mul_add<const LANES: usize>() {
+                    test_helpers::test_ternary_elementwise(
+                        &<Vector<LANES> as StdFloat>::neg_mul_add,
+                        &|x: Scalar, a: Scalar, b: Scalar| (-x).mul_add(a, b),
+                        &|_, _, _| true,
+                    )
+                }
+
+                fn neg_mul_sub<const LANES: usize>() {
+                    test_helpers::test_ternary_elementwise(
+                        &<Vector<LANES> as StdFloat>::neg_mul_sub,
+                        &|x: Scalar, a: Scalar, b: Scalar| (-x).mul_add(a, -b),
+                        &|_, _, _| true,
+                    )
+                }
+
+                fn sqrt<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &<Vector<LANES> as StdFloat>::sqrt,
+                        &Scalar::sqrt,
+                        &|_| true,
+                    )
+                }
+
+                fn sin<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &<Vector<LANES> as StdFloat>::sin,
+                        &Scalar::sin,
+                        &|_| true,
+                    )
+                }
+
+                fn cos<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &<Vector<LANES> as StdFloat>::cos,
+                        &Scalar::cos,
+                        &|_| true,
+                    )
+                }
+
+                fn tan<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &<Vector<LANES> as StdFloat>::tan,
+                        &Scalar::tan,
+                        &|_| true,
+                    )
+                }
+
+                fn powf<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &<Vector<LANES> as StdFloat>::powf,
+                        &Scalar::powf,
+                        &|_, _| true,
+                    )
+                }
+
+                fn exp<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &<Vector<LANES> as StdFloat>::exp,
+                        &Scalar::exp,
+                        &|_| true,
+                    )
+                }
+
+                fn exp2<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &<Vector<LANES> as StdFloat>::exp2,
+                        &Scalar::exp2,
+                        &|_| true,
+                    )
+                }
+
+                fn ln<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &<Vector<LANES> as StdFloat>::ln,
+                        &Scalar::ln,
+                        &|_| true,
+                    )
+                }
+
+                fn log<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &<Vector<LANES> as StdFloat>::log,
+                        &Scalar::log,
+                        &|_, _| true,
+                    )
+                }
+
+                fn log2<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &<Vector<LANES> as StdFloat>::log2,
+                        &Scalar::log2,
+                        &|_| true,
+                    )
+                }
+
+                fn log10<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &<Vector<LANES> as StdFloat>::log10,
+                        &Scalar::log10,
+                        &|_| true,
+                    )
+                }
+            }
+        }
+    }
+}
+
+std_float_test! { SimdF32, f32 }
+std_float_test! { SimdF64, f64 }
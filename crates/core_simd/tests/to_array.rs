@@ -0,0 +1,41 @@
+//! Tests for `to_array`, in `const` contexts and at every lane count.
+//!
+//! `to_array` used to copy each lane separately on wasm32 to work around rust-lang/rust#80108.
+//! That copy gave the same results, so these tests pass with or without it and don't detect
+//! whether it has come back.  On wasm32 they do check that returning the vector's array directly
+//! compiles and runs.
+
+use core_simd::{Simd, SimdF64, SimdU8};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+const ARRAY: [u8; 128] = SimdU8::<128>::splat(7).to_array();
+const FLOATS: [f64; 2] = SimdF64::<2>::from_array([1.5, -0.0]).to_array();
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn const_to_array() {
+    assert_eq!(ARRAY, [7; 128]);
+    assert_eq!(FLOATS[0], 1.5);
+    assert!(FLOATS[1].is_sign_negative());
+}
+
+test_helpers::test_lanes! {
+    fn round_trip<const LANES: usize>() {
+        test_helpers::test_1(&|x: [u8; LANES]| {
+            proptest::prop_assert_eq!(Simd::from_array(x).to_array(), x);
+            Ok(())
+        });
+        test_helpers::test_1(&|x: [f64; LANES]| {
+            let y = Simd::from_array(x).to_array();
+            for i in 0..LANES {
+                proptest::prop_assert_eq!(y[i].to_bits(), x[i].to_bits());
+            }
+            Ok(())
+        });
+    }
+}
@@ -0,0 +1,172 @@
+//! Round trips through the `core::arch` vector types of each target.
+#![cfg_attr(
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "powerpc",
+        target_arch = "powerpc64"
+    ),
+    feature(stdsimd)
+)]
+#![allow(unused_macros)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Converts random vectors to each target type and back, checking that the lanes are unchanged.
+macro_rules! test_round_trip {
+    { $($name:ident: $scalar:ty, $lanes:literal => $arch:ty;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            test_helpers::test_1(&|x: [$scalar; $lanes]| {
+                let arch: $arch = core_simd::Simd::<$scalar, $lanes>::from_array(x).into();
+                let back: core_simd::Simd<$scalar, $lanes> = arch.into();
+                test_helpers::prop_assert_biteq!(back.to_array(), x);
+                Ok(())
+            });
+        }
+        )*
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64 {
+    use core::arch::aarch64::*;
+
+    test_round_trip! {
+        f32x2: f32, 2 => float32x2_t;
+        f32x4: f32, 4 => float32x4_t;
+        f64x1: f64, 1 => float64x1_t;
+        f64x2: f64, 2 => float64x2_t;
+        i8x8: i8, 8 => int8x8_t;
+        i8x16: i8, 16 => int8x16_t;
+        i16x4: i16, 4 => int16x4_t;
+        i16x8: i16, 8 => int16x8_t;
+        i32x2: i32, 2 => int32x2_t;
+        i32x4: i32, 4 => int32x4_t;
+        i64x1: i64, 1 => int64x1_t;
+        i64x2: i64, 2 => int64x2_t;
+        isizex2: isize, 2 => int64x2_t;
+        u8x8: u8, 8 => uint8x8_t;
+        u8x16: u8, 16 => uint8x16_t;
+        u16x4: u16, 4 => uint16x4_t;
+        u16x8: u16, 8 => uint16x8_t;
+        u32x2: u32, 2 => uint32x2_t;
+        u32x4: u32, 4 => uint32x4_t;
+        u64x1: u64, 1 => uint64x1_t;
+        u64x2: u64, 2 => uint64x2_t;
+        usizex2: usize, 2 => uint64x2_t;
+    }
+}
+
+#[cfg(all(target_arch = "arm", target_feature = "v7"))]
+mod arm {
+    use core::arch::arm::*;
+
+    test_round_trip! {
+        f32x2: f32, 2 => float32x2_t;
+        f32x4: f32, 4 => float32x4_t;
+        i8x8: i8, 8 => int8x8_t;
+        i8x16: i8, 16 => int8x16_t;
+        i16x4: i16, 4 => int16x4_t;
+        i16x8: i16, 8 => int16x8_t;
+        i32x2: i32, 2 => int32x2_t;
+        i32x4: i32, 4 => int32x4_t;
+        i64x1: i64, 1 => int64x1_t;
+        i64x2: i64, 2 => int64x2_t;
+        isizex2: isize, 2 => int32x2_t;
+        isizex4: isize, 4 => int32x4_t;
+        u8x8: u8, 8 => uint8x8_t;
+        u8x16: u8, 16 => uint8x16_t;
+        u16x4: u16, 4 => uint16x4_t;
+        u16x8: u16, 8 => uint16x8_t;
+        u32x2: u32, 2 => uint32x2_t;
+        u32x4: u32, 4 => uint32x4_t;
+        u64x1: u64, 1 => uint64x1_t;
+        u64x2: u64, 2 => uint64x2_t;
+        usizex2: usize, 2 => uint32x2_t;
+        usizex4: usize, 4 => uint32x4_t;
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod wasm32 {
+    use super::*;
+    use core::arch::wasm32::v128;
+
+    test_round_trip! {
+        f32x4: f32, 4 => v128;
+        f64x2: f64, 2 => v128;
+        i8x16: i8, 16 => v128;
+        i16x8: i16, 8 => v128;
+        i32x4: i32, 4 => v128;
+        i64x2: i64, 2 => v128;
+        isizex4: isize, 4 => v128;
+        u8x16: u8, 16 => v128;
+        u16x8: u16, 8 => v128;
+        u32x4: u32, 4 => v128;
+        u64x2: u64, 2 => v128;
+        usizex4: usize, 4 => v128;
+    }
+}
+
+#[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+mod powerpc {
+    #[cfg(target_arch = "powerpc")]
+    use core::arch::powerpc::*;
+    #[cfg(target_arch = "powerpc64")]
+    use core::arch::powerpc64::*;
+
+    test_round_trip! {
+        f32x4: f32, 4 => vector_float;
+        f64x2: f64, 2 => vector_double;
+        i8x16: i8, 16 => vector_signed_char;
+        i16x8: i16, 8 => vector_signed_short;
+        i32x4: i32, 4 => vector_signed_int;
+        i64x2: i64, 2 => vector_signed_long;
+        u8x16: u8, 16 => vector_unsigned_char;
+        u16x8: u16, 8 => vector_unsigned_short;
+        u32x4: u32, 4 => vector_unsigned_int;
+        u64x2: u64, 2 => vector_unsigned_long;
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod avx512 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    test_round_trip! {
+        f32x16: f32, 16 => __m512;
+        f64x8: f64, 8 => __m512d;
+        i8x64: i8, 64 => __m512i;
+        i16x32: i16, 32 => __m512i;
+        i32x16: i32, 16 => __m512i;
+        i64x8: i64, 8 => __m512i;
+        u8x64: u8, 64 => __m512i;
+        u16x32: u16, 32 => __m512i;
+        u32x16: u32, 16 => __m512i;
+        u64x8: u64, 8 => __m512i;
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    test_round_trip! {
+        isizex16: isize, 16 => __m512i;
+        usizex16: usize, 16 => __m512i;
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    test_round_trip! {
+        isizex8: isize, 8 => __m512i;
+        usizex8: usize, 8 => __m512i;
+    }
+}
@@ -0,0 +1,3 @@
+#[macro_use]
+mod ops_macros;
+impl_unsigned_tests! { SimdU128, u128 }
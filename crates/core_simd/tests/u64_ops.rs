@@ -1,3 +1,23 @@
 #[macro_use]
 mod ops_macros;
 impl_unsigned_tests! { SimdU64, u64 }
+
+test_helpers::test_lanes! {
+    fn widening_carryless_mul<const LANES: usize>() {
+        test_helpers::test_2(&|x: [u64; LANES], y: [u64; LANES]| {
+            let (lo, hi) = core_simd::SimdU64::<LANES>::from_array(x)
+                .widening_carryless_mul(core_simd::SimdU64::from_array(y));
+            for i in 0..LANES {
+                let mut expected = 0u128;
+                for bit in 0..64 {
+                    if y[i] >> bit & 1 == 1 {
+                        expected ^= (x[i] as u128) << bit;
+                    }
+                }
+                proptest::prop_assert_eq!(lo[i], expected as u64);
+                proptest::prop_assert_eq!(hi[i], (expected >> 64) as u64);
+            }
+            Ok(())
+        });
+    }
+}
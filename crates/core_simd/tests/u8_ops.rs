@@ -1,3 +1,95 @@
 #[macro_use]
 mod ops_macros;
 impl_unsigned_tests! { SimdU8, u8 }
+
+macro_rules! test_abs_diff_sum {
+    { $($name:ident: $lanes:literal => $blocks:literal),* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+        fn $name() {
+            test_helpers::test_2(&|x: [u8; $lanes], y: [u8; $lanes]| {
+                let sums = core_simd::SimdU8::<$lanes>::from_array(x)
+                    .abs_diff_sum(core_simd::SimdU8::from_array(y));
+                for block in 0..$blocks {
+                    let mut expected = 0u64;
+                    for i in 8 * block..8 * (block + 1) {
+                        expected += (x[i].max(y[i]) - x[i].min(y[i])) as u64;
+                    }
+                    proptest::prop_assert_eq!(sums[block], expected);
+                }
+                Ok(())
+            });
+        }
+        )*
+    }
+}
+
+test_abs_diff_sum! {
+    abs_diff_sum_8: 8 => 1,
+    abs_diff_sum_16: 16 => 2,
+    abs_diff_sum_32: 32 => 4,
+    abs_diff_sum_64: 64 => 8
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn ascii() {
+    // Every byte value, in four vectors.
+    for block in 0..4u8 {
+        let mut bytes = [0u8; 64];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = block * 64 + i as u8;
+        }
+        let v = core_simd::u8x64::from_array(bytes);
+        let (is_ascii, upper, lower) = (
+            v.is_ascii().to_array(),
+            v.to_ascii_uppercase().to_array(),
+            v.to_ascii_lowercase().to_array(),
+        );
+        let eq_upper = v
+            .eq_ignore_ascii_case(core_simd::u8x64::from_array(upper))
+            .to_array();
+        let eq_next = v
+            .eq_ignore_ascii_case(v.wrapping_add(core_simd::u8x64::splat(1)))
+            .to_array();
+        for i in 0..64 {
+            let byte = bytes[i];
+            assert_eq!(is_ascii[i], byte.is_ascii());
+            assert_eq!(upper[i], byte.to_ascii_uppercase());
+            assert_eq!(lower[i], byte.to_ascii_lowercase());
+            assert!(eq_upper[i]);
+            assert_eq!(eq_next[i], byte.eq_ignore_ascii_case(&byte.wrapping_add(1)));
+        }
+    }
+}
+
+macro_rules! test_lookup16 {
+    { $($name:ident: $lanes:literal),* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+        fn $name() {
+            test_helpers::test_2(&|indices: [u8; $lanes], table: [u8; 16]| {
+                // Keep most indices near the table, with some far out of range.
+                let indices = indices.map(|i| if i >= 0x80 { i } else { i % 20 });
+                let result = core_simd::SimdU8::<$lanes>::from_array(indices)
+                    .lookup16(core_simd::u8x16::from_array(table))
+                    .to_array();
+                for i in 0..$lanes {
+                    let expected = table.get(indices[i] as usize).copied().unwrap_or(0);
+                    proptest::prop_assert_eq!(result[i], expected);
+                }
+                Ok(())
+            });
+        }
+        )*
+    }
+}
+
+test_lookup16! {
+    lookup16_1: 1,
+    lookup16_8: 8,
+    lookup16_16: 16,
+    lookup16_64: 64
+}
@@ -0,0 +1,37 @@
+use core_simd::Simd;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! test_widening_pairwise_add {
+    { $($name:ident: $narrow:ty => $wide:ty, $lanes:literal => $half:literal;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            test_helpers::test_1(&|x: [$narrow; $lanes]| {
+                let sums: Simd<$wide, $half> = Simd::from_array(x).widening_pairwise_add();
+                for i in 0..$half {
+                    proptest::prop_assert_eq!(sums[i], x[2 * i] as $wide + x[2 * i + 1] as $wide);
+                }
+                Ok(())
+            });
+        }
+        )*
+    }
+}
+
+test_widening_pairwise_add! {
+    u8_2: u8 => u16, 2 => 1;
+    u8_16: u8 => u16, 16 => 8;
+    u8_128: u8 => u16, 128 => 64;
+    u16_8: u16 => u32, 8 => 4;
+    u32_4: u32 => u64, 4 => 2;
+    i8_16: i8 => i16, 16 => 8;
+    i8_64: i8 => i16, 64 => 32;
+    i16_8: i16 => i32, 8 => 4;
+    i32_4: i32 => i64, 4 => 2;
+}
@@ -9,6 +9,7 @@ use proptest::{
     test_runner::TestRunner,
 };
 
+/// A strategy for arrays of any length, with each element generated by the same strategy.
 #[must_use = "strategies do nothing unless used"]
 #[derive(Clone, Copy, Debug)]
 pub struct UniformArrayStrategy<S, T> {
@@ -17,6 +18,7 @@ pub struct UniformArrayStrategy<S, T> {
 }
 
 impl<S, T> UniformArrayStrategy<S, T> {
+    /// Creates a strategy that generates each element with `strategy`.
     pub const fn new(strategy: S) -> Self {
         Self {
             strategy,
@@ -25,6 +27,7 @@ impl<S, T> UniformArrayStrategy<S, T> {
     }
 }
 
+/// The value tree of a [`UniformArrayStrategy`], which shrinks one element at a time.
 pub struct ArrayValueTree<T> {
     tree: T,
     shrinker: usize,
@@ -1,7 +1,11 @@
 //! Compare numeric types by exact bit value.
 
+/// Equality by bit pattern, used to compare test results.
 pub trait BitEq {
+    /// Returns true if `self` and `other` have the same bits.  Any two `NaN`s are equal.
     fn biteq(&self, other: &Self) -> bool;
+
+    /// Formats the value along with its bits.
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result;
 }
 
@@ -93,6 +97,7 @@ impl<T: BitEq> core::fmt::Debug for BitEqWrapper<'_, T> {
     }
 }
 
+/// Asserts that two values are equal by [`BitEq`], failing the current proptest case otherwise.
 #[macro_export]
 macro_rules! prop_assert_biteq {
     { $a:expr, $b:expr $(,)? } => {
@@ -100,7 +105,7 @@ macro_rules! prop_assert_biteq {
             use $crate::biteq::BitEqWrapper;
             let a = $a;
             let b = $b;
-            proptest::prop_assert_eq!(BitEqWrapper(&a), BitEqWrapper(&b));
+            $crate::proptest::prop_assert_eq!(BitEqWrapper(&a), BitEqWrapper(&b));
         }
     }
 }
@@ -1,3 +1,38 @@
+//! Property-testing helpers for SIMD code, used by `core_simd`'s own tests.
+//!
+//! The helpers generate arbitrary inputs with [proptest], run a vector function on them, and
+//! compare each lane against a scalar reference function.  Results are compared by exact bit
+//! pattern (see [`biteq`]), so `-0.0` is distinct from `0.0` and any `NaN` matches any other.
+//!
+//! # Testing your own kernels
+//! Add `test_helpers` and `core_simd` as dev-dependencies, then compare a kernel against its
+//! scalar definition:
+//!
+//! ```
+//! use core_simd::{LaneCount, SimdU32, SupportedLaneCount};
+//!
+//! // The kernel under test: clears the bits of `a` that are set in `b`.
+//! fn and_not<const LANES: usize>(a: SimdU32<LANES>, b: SimdU32<LANES>) -> SimdU32<LANES>
+//! where
+//!     LaneCount<LANES>: SupportedLaneCount,
+//! {
+//!     a & !b
+//! }
+//!
+//! test_helpers::test_binary_elementwise(
+//!     &and_not::<4>,
+//!     &|a: u32, b: u32| a & !b,
+//!     &|_: [u32; 4], _: [u32; 4]| true,
+//! );
+//! ```
+//!
+//! The [`test_lanes!`] macro expands a test that is generic over `LANES` into one `#[test]` per
+//! supported lane count.  It names `core_simd` directly, so the crate must be available under
+//! that name, and on `wasm32` it also requires `wasm-bindgen-test`.
+
+// Re-exported so that the exported macros can name it through `$crate`.
+pub use proptest;
+
 pub mod array;
 
 #[cfg(target_arch = "wasm32")]
@@ -6,11 +41,18 @@ pub mod wasm;
 #[macro_use]
 pub mod biteq;
 
+pub mod ulps;
+
+pub mod wide;
+
 /// Specifies the default strategy for testing a type.
 ///
 /// This strategy should be what "makes sense" to test.
 pub trait DefaultStrategy {
+    /// The strategy that generates values of this type.
     type Strategy: proptest::strategy::Strategy<Value = Self>;
+
+    /// Returns the strategy.
     fn default_strategy() -> Self::Strategy;
 }
 
@@ -97,6 +139,27 @@ pub fn test_2<A: core::fmt::Debug + DefaultStrategy, B: core::fmt::Debug + Defau
         .unwrap();
 }
 
+/// Test a function that takes three values.
+pub fn test_3<
+    A: core::fmt::Debug + DefaultStrategy,
+    B: core::fmt::Debug + DefaultStrategy,
+    C: core::fmt::Debug + DefaultStrategy,
+>(
+    f: &dyn Fn(A, B, C) -> proptest::test_runner::TestCaseResult,
+) {
+    let mut runner = proptest::test_runner::TestRunner::default();
+    runner
+        .run(
+            &(
+                A::default_strategy(),
+                B::default_strategy(),
+                C::default_strategy(),
+            ),
+            |(a, b, c)| f(a, b, c),
+        )
+        .unwrap();
+}
+
 /// Test a unary vector function against a unary scalar function, applied elementwise.
 #[inline(never)]
 pub fn test_unary_elementwise<Scalar, ScalarResult, Vector, VectorResult, const LANES: usize>(
@@ -124,6 +187,35 @@ pub fn test_unary_elementwise<Scalar, ScalarResult, Vector, VectorResult, const
     });
 }
 
+/// Test a unary vector function against a unary scalar function, applied elementwise, allowing
+/// the results to differ by up to `max_ulps`.
+#[inline(never)]
+pub fn test_unary_elementwise_ulps<Scalar, Vector, const LANES: usize>(
+    fv: &dyn Fn(Vector) -> Vector,
+    fs: &dyn Fn(Scalar) -> Scalar,
+    check: &dyn Fn([Scalar; LANES]) -> bool,
+    max_ulps: u64,
+) where
+    Scalar: Copy + Default + core::fmt::Debug + DefaultStrategy + ulps::Ulps,
+    Vector: Into<[Scalar; LANES]> + From<[Scalar; LANES]> + Copy,
+{
+    test_1(&|x: [Scalar; LANES]| {
+        proptest::prop_assume!(check(x));
+        let result: [Scalar; LANES] = fv(x.into()).into();
+        for (i, r) in x.iter().zip(result.iter()) {
+            let expected = fs(*i);
+            proptest::prop_assert!(
+                r.ulps(expected) <= max_ulps,
+                "input: {:?}, result: {:?}, expected: {:?}",
+                i,
+                r,
+                expected
+            );
+        }
+        Ok(())
+    });
+}
+
 /// Test a unary vector function against a unary scalar function, applied elementwise.
 #[inline(never)]
 pub fn test_unary_mask_elementwise<Scalar, Vector, Mask, const LANES: usize>(
@@ -187,6 +279,109 @@ pub fn test_binary_elementwise<
     });
 }
 
+/// Test a binary vector function against a binary scalar function, applied elementwise, allowing
+/// the results to differ by up to `max_ulps`.
+#[inline(never)]
+pub fn test_binary_elementwise_ulps<Scalar, Vector, const LANES: usize>(
+    fv: &dyn Fn(Vector, Vector) -> Vector,
+    fs: &dyn Fn(Scalar, Scalar) -> Scalar,
+    check: &dyn Fn([Scalar; LANES], [Scalar; LANES]) -> bool,
+    max_ulps: u64,
+) where
+    Scalar: Copy + Default + core::fmt::Debug + DefaultStrategy + ulps::Ulps,
+    Vector: Into<[Scalar; LANES]> + From<[Scalar; LANES]> + Copy,
+{
+    test_2(&|x: [Scalar; LANES], y: [Scalar; LANES]| {
+        proptest::prop_assume!(check(x, y));
+        let result: [Scalar; LANES] = fv(x.into(), y.into()).into();
+        for ((i1, i2), r) in x.iter().zip(y.iter()).zip(result.iter()) {
+            let expected = fs(*i1, *i2);
+            proptest::prop_assert!(
+                r.ulps(expected) <= max_ulps,
+                "input: {:?}, {:?}, result: {:?}, expected: {:?}",
+                i1,
+                i2,
+                r,
+                expected
+            );
+        }
+        Ok(())
+    });
+}
+
+/// Test a ternary vector function against a ternary scalar function, applied elementwise.
+#[inline(never)]
+pub fn test_ternary_elementwise<
+    Scalar1,
+    Scalar2,
+    Scalar3,
+    ScalarResult,
+    Vector1,
+    Vector2,
+    Vector3,
+    VectorResult,
+    const LANES: usize,
+>(
+    fv: &dyn Fn(Vector1, Vector2, Vector3) -> VectorResult,
+    fs: &dyn Fn(Scalar1, Scalar2, Scalar3) -> ScalarResult,
+    check: &dyn Fn([Scalar1; LANES], [Scalar2; LANES], [Scalar3; LANES]) -> bool,
+) where
+    Scalar1: Copy + Default + core::fmt::Debug + DefaultStrategy,
+    Scalar2: Copy + Default + core::fmt::Debug + DefaultStrategy,
+    Scalar3: Copy + Default + core::fmt::Debug + DefaultStrategy,
+    ScalarResult: Copy + Default + biteq::BitEq + core::fmt::Debug + DefaultStrategy,
+    Vector1: Into<[Scalar1; LANES]> + From<[Scalar1; LANES]> + Copy,
+    Vector2: Into<[Scalar2; LANES]> + From<[Scalar2; LANES]> + Copy,
+    Vector3: Into<[Scalar3; LANES]> + From<[Scalar3; LANES]> + Copy,
+    VectorResult: Into<[ScalarResult; LANES]> + From<[ScalarResult; LANES]> + Copy,
+{
+    test_3(
+        &|x: [Scalar1; LANES], y: [Scalar2; LANES], z: [Scalar3; LANES]| {
+            proptest::prop_assume!(check(x, y, z));
+            let result_1: [ScalarResult; LANES] = fv(x.into(), y.into(), z.into()).into();
+            let result_2: [ScalarResult; LANES] = {
+                let mut result = [ScalarResult::default(); LANES];
+                for ((i1, (i2, i3)), o) in
+                    x.iter().zip(y.iter().zip(z.iter())).zip(result.iter_mut())
+                {
+                    *o = fs(*i1, *i2, *i3);
+                }
+                result
+            };
+            crate::prop_assert_biteq!(result_1, result_2);
+            Ok(())
+        },
+    );
+}
+
+/// Test a binary vector function against a binary scalar function, applied elementwise.
+#[inline(never)]
+pub fn test_binary_mask_elementwise<Scalar1, Scalar2, Vector1, Vector2, Mask, const LANES: usize>(
+    fv: &dyn Fn(Vector1, Vector2) -> Mask,
+    fs: &dyn Fn(Scalar1, Scalar2) -> bool,
+    check: &dyn Fn([Scalar1; LANES], [Scalar2; LANES]) -> bool,
+) where
+    Scalar1: Copy + Default + core::fmt::Debug + DefaultStrategy,
+    Scalar2: Copy + Default + core::fmt::Debug + DefaultStrategy,
+    Vector1: Into<[Scalar1; LANES]> + From<[Scalar1; LANES]> + Copy,
+    Vector2: Into<[Scalar2; LANES]> + From<[Scalar2; LANES]> + Copy,
+    Mask: Into<[bool; LANES]> + From<[bool; LANES]> + Copy,
+{
+    test_2(&|x: [Scalar1; LANES], y: [Scalar2; LANES]| {
+        proptest::prop_assume!(check(x, y));
+        let result_1: [bool; LANES] = fv(x.into(), y.into()).into();
+        let result_2: [bool; LANES] = {
+            let mut result = [false; LANES];
+            for ((i1, i2), o) in x.iter().zip(y.iter()).zip(result.iter_mut()) {
+                *o = fs(*i1, *i2);
+            }
+            result
+        };
+        crate::prop_assert_biteq!(result_1, result_2);
+        Ok(())
+    });
+}
+
 /// Test a binary vector-scalar function against a binary scalar function, applied elementwise.
 #[inline(never)]
 pub fn test_binary_scalar_rhs_elementwise<
@@ -269,23 +464,7 @@ macro_rules! test_lanes {
 
                 fn implementation<const $lanes: usize>()
                 where
-                    core_simd::SimdU8<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdU16<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdU32<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdU64<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdUsize<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdI8<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdI16<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdI32<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdI64<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdIsize<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdF32<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdF64<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::Mask8<$lanes>: core_simd::Mask,
-                    core_simd::Mask16<$lanes>: core_simd::Mask,
-                    core_simd::Mask32<$lanes>: core_simd::Mask,
-                    core_simd::Mask64<$lanes>: core_simd::Mask,
-                    core_simd::MaskSize<$lanes>: core_simd::Mask,
+                    core_simd::LaneCount<$lanes>: core_simd::SupportedLaneCount,
                 $body
 
                 #[cfg(target_arch = "wasm32")]
@@ -326,6 +505,18 @@ macro_rules! test_lanes {
                 fn lanes_32() {
                     implementation::<32>();
                 }
+
+                #[test]
+                #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+                fn lanes_64() {
+                    implementation::<64>();
+                }
+
+                #[test]
+                #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+                fn lanes_128() {
+                    implementation::<128>();
+                }
             }
         )*
     }
@@ -343,23 +534,7 @@ macro_rules! test_lanes_panic {
 
                 fn implementation<const $lanes: usize>()
                 where
-                    core_simd::SimdU8<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdU16<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdU32<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdU64<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdUsize<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdI8<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdI16<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdI32<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdI64<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdIsize<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdF32<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::SimdF64<$lanes>: core_simd::LanesAtMost32,
-                    core_simd::Mask8<$lanes>: core_simd::Mask,
-                    core_simd::Mask16<$lanes>: core_simd::Mask,
-                    core_simd::Mask32<$lanes>: core_simd::Mask,
-                    core_simd::Mask64<$lanes>: core_simd::Mask,
-                    core_simd::MaskSize<$lanes>: core_simd::Mask,
+                    core_simd::LaneCount<$lanes>: core_simd::SupportedLaneCount,
                 $body
 
                 #[test]
@@ -397,6 +572,18 @@ macro_rules! test_lanes_panic {
                 fn lanes_32() {
                     implementation::<32>();
                 }
+
+                #[test]
+                #[should_panic]
+                fn lanes_64() {
+                    implementation::<64>();
+                }
+
+                #[test]
+                #[should_panic]
+                fn lanes_128() {
+                    implementation::<128>();
+                }
             }
         )*
     }
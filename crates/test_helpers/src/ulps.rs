@@ -0,0 +1,36 @@
+//! Compare floats by the number of representable values between them.
+
+/// Distance between floats, used to test approximate results.
+pub trait Ulps: Copy {
+    /// Returns the distance between `self` and `other` in units in the last place.
+    ///
+    /// `NaN`s are equal to each other and infinitely far from every other value.
+    fn ulps(self, other: Self) -> u64;
+}
+
+macro_rules! impl_float_ulps {
+    { $($type:ty),* } => {
+        $(
+        impl Ulps for $type {
+            fn ulps(self, other: Self) -> u64 {
+                if self.is_nan() || other.is_nan() {
+                    return if self.is_nan() && other.is_nan() { 0 } else { u64::MAX };
+                }
+
+                // Map the bits to integers that are ordered the same way as the floats.
+                fn key(x: $type) -> i128 {
+                    let magnitude = (x.abs().to_bits()) as i128;
+                    if x.is_sign_negative() {
+                        -magnitude
+                    } else {
+                        magnitude
+                    }
+                }
+                (key(self) - key(other)).unsigned_abs() as u64
+            }
+        }
+        )*
+    };
+}
+
+impl_float_ulps! { f32, f64 }
@@ -0,0 +1,49 @@
+//! Scalar references for double-width integer arithmetic.
+
+/// Full-width multiplication, used as the reference for vector `widening_mul`.
+pub trait WideningMul: Copy {
+    /// Returns the low and high halves of the full product of `self` and `other`.
+    fn widening_mul(self, other: Self) -> (Self, Self);
+}
+
+macro_rules! impl_widening_mul {
+    { $($type:ty => $unsigned:ty),* } => {
+        $(
+        impl WideningMul for $type {
+            fn widening_mul(self, other: Self) -> (Self, Self) {
+                const BITS: u32 = <$unsigned>::BITS;
+                let (x, y) = (self as $unsigned, other as $unsigned);
+
+                // Long multiplication, one bit of `y` at a time.
+                let (mut lo, mut hi): ($unsigned, $unsigned) = (0, 0);
+                for i in 0..BITS {
+                    if (y >> i) & 1 == 1 {
+                        let (sum, carry) = lo.overflowing_add(x << i);
+                        lo = sum;
+                        hi = hi
+                            .wrapping_add(x.checked_shr(BITS - i).unwrap_or(0))
+                            .wrapping_add(carry as $unsigned);
+                    }
+                }
+
+                // A negative operand is its unsigned value minus 2^BITS, which subtracts the
+                // other operand from the high half.
+                if <$type>::MIN != 0 {
+                    if x >> (BITS - 1) == 1 {
+                        hi = hi.wrapping_sub(y);
+                    }
+                    if y >> (BITS - 1) == 1 {
+                        hi = hi.wrapping_sub(x);
+                    }
+                }
+                (lo as $type, hi as $type)
+            }
+        }
+        )*
+    };
+}
+
+impl_widening_mul! {
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+}